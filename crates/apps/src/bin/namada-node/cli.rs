@@ -51,13 +51,20 @@ pub fn main() -> Result<()> {
             }
             cmds::Ledger::DumpDb(cmds::LedgerDumpDb(args)) => {
                 let chain_ctx = ctx.take_chain_or_exit();
-                node::dump_db(chain_ctx.config.ledger, args);
+                node::dump_db(chain_ctx.config.ledger, args)
+                    .wrap_err("Failed to dump the DB")?;
             }
             cmds::Ledger::RollBack(_) => {
                 let chain_ctx = ctx.take_chain_or_exit();
                 node::rollback(chain_ctx.config.ledger)
                     .wrap_err("Failed to rollback the Namada node")?;
             }
+            cmds::Ledger::VerifyDb(_) => {
+                let chain_ctx = ctx.take_chain_or_exit();
+                if !node::verify_db(chain_ctx.config.ledger) {
+                    cli::safe_exit(1)
+                }
+            }
             cmds::Ledger::UpdateDB(cmds::LedgerUpdateDB(args)) => {
                 #[cfg(not(feature = "migrations"))]
                 {
@@ -77,7 +84,10 @@ pub fn main() -> Result<()> {
                     let wasm_dir = chain_ctx.wasm_dir();
                     chain_ctx.config.ledger.shell.action_at_height =
                         Some(ActionAtHeight {
-                            height: args.last_height.checked_add(2).unwrap(),
+                            height: Some(
+                                args.last_height.checked_add(2).unwrap(),
+                            ),
+                            time: None,
                             action: Action::Halt,
                         });
                     std::env::set_var(
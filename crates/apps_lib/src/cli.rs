@@ -13,12 +13,13 @@ pub mod relayer;
 mod utils;
 pub mod wallet;
 
-use clap::{ArgGroup, ArgMatches, ColorChoice};
+use clap::{ArgAction, ArgGroup, ArgMatches, ColorChoice};
 use color_eyre::eyre::Result;
 use namada_sdk::io::StdIo;
 use utils::*;
 pub use utils::{safe_exit, Cmd};
 
+use self::context::BaseDirAccess;
 pub use self::context::Context;
 use crate::cli::api::CliIo;
 
@@ -228,8 +229,8 @@ pub mod cmds {
     #[allow(clippy::large_enum_variant)]
     pub enum NamadaClient {
         /// The [`super::Context`] provides access to the wallet and the
-        /// config. It will generate a new wallet and config, if they
-        /// don't exist.
+        /// config. It will generate a new config, if it doesn't exist,
+        /// except for queries, which fail instead.
         WithContext(NamadaClientWithContext),
         /// Utils don't have [`super::Context`], only the global arguments.
         WithoutContext(ClientUtils),
@@ -279,6 +280,7 @@ pub mod cmds {
                 .subcommand(QueryMaspRewardTokens::def().display_order(5))
                 .subcommand(QueryBlock::def().display_order(5))
                 .subcommand(QueryBalance::def().display_order(5))
+                .subcommand(QueryTotalSupply::def().display_order(5))
                 .subcommand(QueryBonds::def().display_order(5))
                 .subcommand(QueryBondedStake::def().display_order(5))
                 .subcommand(QuerySlashes::def().display_order(5))
@@ -291,12 +293,14 @@ pub mod cmds {
                 .subcommand(QueryProposalResult::def().display_order(5))
                 .subcommand(QueryProtocolParameters::def().display_order(5))
                 .subcommand(QueryPgf::def().display_order(5))
+                .subcommand(QueryEthBridgePoolTransfers::def().display_order(5))
                 .subcommand(QueryValidatorState::def().display_order(5))
                 .subcommand(QueryCommissionRate::def().display_order(5))
                 .subcommand(QueryRewards::def().display_order(5))
                 .subcommand(QueryMetaData::def().display_order(5))
                 // Actions
                 .subcommand(SignTx::def().display_order(6))
+                .subcommand(BroadcastTx::def().display_order(6))
                 .subcommand(ShieldedSync::def().display_order(6))
                 .subcommand(GenIbcShieldingTransfer::def().display_order(6))
                 // Utils
@@ -359,6 +363,8 @@ pub mod cmds {
                 Self::parse_with_ctx(matches, QueryMaspRewardTokens);
             let query_block = Self::parse_with_ctx(matches, QueryBlock);
             let query_balance = Self::parse_with_ctx(matches, QueryBalance);
+            let query_total_supply =
+                Self::parse_with_ctx(matches, QueryTotalSupply);
             let query_bonds = Self::parse_with_ctx(matches, QueryBonds);
             let query_bonded_stake =
                 Self::parse_with_ctx(matches, QueryBondedStake);
@@ -378,6 +384,8 @@ pub mod cmds {
             let query_protocol_parameters =
                 Self::parse_with_ctx(matches, QueryProtocolParameters);
             let query_pgf = Self::parse_with_ctx(matches, QueryPgf);
+            let query_eth_bridge_pool =
+                Self::parse_with_ctx(matches, QueryEthBridgePool);
             let query_validator_state =
                 Self::parse_with_ctx(matches, QueryValidatorState);
             let query_commission =
@@ -386,6 +394,7 @@ pub mod cmds {
             let add_to_eth_bridge_pool =
                 Self::parse_with_ctx(matches, AddToEthBridgePool);
             let sign_tx = Self::parse_with_ctx(matches, SignTx);
+            let broadcast_tx = Self::parse_with_ctx(matches, BroadcastTx);
             let shielded_sync = Self::parse_with_ctx(matches, ShieldedSync);
             let gen_ibc_shielding =
                 Self::parse_with_ctx(matches, GenIbcShieldingTransfer);
@@ -424,6 +433,7 @@ pub mod cmds {
                 .or(query_masp_reward_tokens)
                 .or(query_block)
                 .or(query_balance)
+                .or(query_total_supply)
                 .or(query_bonds)
                 .or(query_bonded_stake)
                 .or(query_slashes)
@@ -437,11 +447,13 @@ pub mod cmds {
                 .or(query_proposal_result)
                 .or(query_protocol_parameters)
                 .or(query_pgf)
+                .or(query_eth_bridge_pool)
                 .or(query_validator_state)
                 .or(query_commission)
                 .or(query_metadata)
                 .or(query_account)
                 .or(sign_tx)
+                .or(broadcast_tx)
                 .or(shielded_sync)
                 .or(gen_ibc_shielding)
                 .or(utils)
@@ -517,6 +529,7 @@ pub mod cmds {
         QueryMaspRewardTokens(QueryMaspRewardTokens),
         QueryBlock(QueryBlock),
         QueryBalance(QueryBalance),
+        QueryTotalSupply(QueryTotalSupply),
         QueryBonds(QueryBonds),
         QueryBondedStake(QueryBondedStake),
         QueryCommissionRate(QueryCommissionRate),
@@ -530,13 +543,50 @@ pub mod cmds {
         QueryProposalResult(QueryProposalResult),
         QueryProtocolParameters(QueryProtocolParameters),
         QueryPgf(QueryPgf),
+        QueryEthBridgePool(QueryEthBridgePoolTransfers),
         QueryValidatorState(QueryValidatorState),
         QueryRewards(QueryRewards),
         SignTx(SignTx),
+        BroadcastTx(BroadcastTx),
         ShieldedSync(ShieldedSync),
         GenIbcShieldingTransfer(GenIbcShieldingTransfer),
     }
 
+    impl NamadaClientWithContext {
+        /// Whether this is a read-only query command
+        pub fn is_query(&self) -> bool {
+            matches!(
+                self,
+                Self::QueryResult(_)
+                    | Self::QueryEpoch(_)
+                    | Self::QueryNextEpochInfo(_)
+                    | Self::QueryStatus(_)
+                    | Self::QueryAccount(_)
+                    | Self::QueryConversions(_)
+                    | Self::QueryMaspRewardTokens(_)
+                    | Self::QueryBlock(_)
+                    | Self::QueryBalance(_)
+                    | Self::QueryTotalSupply(_)
+                    | Self::QueryBonds(_)
+                    | Self::QueryBondedStake(_)
+                    | Self::QueryCommissionRate(_)
+                    | Self::QueryMetaData(_)
+                    | Self::QuerySlashes(_)
+                    | Self::QueryDelegations(_)
+                    | Self::QueryFindValidator(_)
+                    | Self::QueryRawBytes(_)
+                    | Self::QueryProposal(_)
+                    | Self::QueryProposalVotes(_)
+                    | Self::QueryProposalResult(_)
+                    | Self::QueryProtocolParameters(_)
+                    | Self::QueryPgf(_)
+                    | Self::QueryEthBridgePool(_)
+                    | Self::QueryValidatorState(_)
+                    | Self::QueryRewards(_)
+            )
+        }
+    }
+
     #[allow(clippy::large_enum_variant)]
     #[derive(Clone, Debug)]
     pub enum NamadaWallet {
@@ -544,6 +594,8 @@ pub mod cmds {
         KeyGen(WalletGen),
         /// Key derivation
         KeyDerive(WalletDerive),
+        /// Mnemonic code check
+        KeyMnemonicCheck(WalletMnemonicCheck),
         /// Payment address generation
         PayAddrGen(WalletGenPaymentAddress),
         /// Key / address list
@@ -560,12 +612,17 @@ pub mod cmds {
         KeyAddrAdd(WalletAddKeyAddress),
         /// Key / address remove
         KeyAddrRemove(WalletRemoveKeyAddress),
+        /// Key / address rename
+        KeyAddrRename(WalletRenameKeyAddress),
+        /// Store integrity check
+        Check(WalletCheck),
     }
 
     impl Cmd for NamadaWallet {
         fn add_sub(app: App) -> App {
             app.subcommand(WalletGen::def())
                 .subcommand(WalletDerive::def())
+                .subcommand(WalletMnemonicCheck::def())
                 .subcommand(WalletGenPaymentAddress::def())
                 .subcommand(WalletListKeysAddresses::def())
                 .subcommand(WalletFindKeysAddresses::def())
@@ -574,11 +631,15 @@ pub mod cmds {
                 .subcommand(WalletImportKey::def())
                 .subcommand(WalletAddKeyAddress::def())
                 .subcommand(WalletRemoveKeyAddress::def())
+                .subcommand(WalletRenameKeyAddress::def())
+                .subcommand(WalletCheck::def())
         }
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             let gen = SubCmd::parse(matches).map(Self::KeyGen);
             let derive = SubCmd::parse(matches).map(Self::KeyDerive);
+            let mnemonic_check =
+                SubCmd::parse(matches).map(Self::KeyMnemonicCheck);
             let pay_addr_gen = SubCmd::parse(matches).map(Self::PayAddrGen);
             let key_addr_list = SubCmd::parse(matches).map(Self::KeyAddrList);
             let key_addr_find = SubCmd::parse(matches).map(Self::KeyAddrFind);
@@ -588,7 +649,11 @@ pub mod cmds {
             let key_addr_add = SubCmd::parse(matches).map(Self::KeyAddrAdd);
             let key_addr_remove =
                 SubCmd::parse(matches).map(Self::KeyAddrRemove);
+            let key_addr_rename =
+                SubCmd::parse(matches).map(Self::KeyAddrRename);
+            let check = SubCmd::parse(matches).map(Self::Check);
             gen.or(derive)
+                .or(mnemonic_check)
                 .or(pay_addr_gen)
                 .or(key_addr_list)
                 .or(key_addr_find)
@@ -597,6 +662,8 @@ pub mod cmds {
                 .or(import)
                 .or(key_addr_add)
                 .or(key_addr_remove)
+                .or(key_addr_rename)
+                .or(check)
         }
     }
 
@@ -686,6 +753,38 @@ pub mod cmds {
         }
     }
 
+    /// Derive a keypair and implicit address from the mnemonic code without
+    /// storing them in the wallet.
+    #[derive(Clone, Debug)]
+    pub struct WalletMnemonicCheck(pub args::KeyMnemonicCheck);
+
+    impl SubCmd for WalletMnemonicCheck {
+        const CMD: &'static str = "mnemonic-check";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::KeyMnemonicCheck::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Check which implicit address a mnemonic code derives, \
+                     without storing anything in the wallet."
+                ))
+                .long_about(wrap!(
+                    "Derives a transparent keypair from the given mnemonic \
+                     code, optional BIP39 passphrase and HD derivation path, \
+                     and prints the implicit address derived from its public \
+                     key. Nothing is persisted in the wallet, so this can be \
+                     used to confirm a recovery phrase before restoring it \
+                     with the `derive` command."
+                ))
+                .add_args::<args::KeyMnemonicCheck>()
+        }
+    }
+
     /// List known keys and addresses
     #[derive(Clone, Debug)]
     pub struct WalletListKeysAddresses(pub args::KeyAddressList);
@@ -851,6 +950,48 @@ pub mod cmds {
         }
     }
 
+    /// Rename key / address
+    #[derive(Clone, Debug)]
+    pub struct WalletRenameKeyAddress(pub args::KeyAddressRename);
+
+    impl SubCmd for WalletRenameKeyAddress {
+        const CMD: &'static str = "rename";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::KeyAddressRename::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Move all the keys / addresses associated with the given \
+                     alias to a new alias."
+                ))
+                .add_args::<args::KeyAddressRename>()
+        }
+    }
+
+    /// Check the integrity of the wallet store
+    #[derive(Clone, Debug)]
+    pub struct WalletCheck;
+
+    impl SubCmd for WalletCheck {
+        const CMD: &'static str = "check";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|_matches| Self)
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD).about(wrap!(
+                "Report dangling references between the keys and addresses of \
+                 the wallet. The wallet is not modified."
+            ))
+        }
+    }
+
     /// Generate a payment address from a viewing key or payment address
     #[derive(Clone, Debug)]
     pub struct WalletGenPaymentAddress(pub args::PayAddressGen<args::CliTypes>);
@@ -882,6 +1023,7 @@ pub mod cmds {
         UpdateDB(LedgerUpdateDB),
         QueryDB(LedgerQueryDB),
         RollBack(LedgerRollBack),
+        VerifyDb(LedgerVerifyDb),
     }
 
     impl SubCmd for Ledger {
@@ -895,12 +1037,14 @@ pub mod cmds {
                 let update_db = SubCmd::parse(matches).map(Self::UpdateDB);
                 let query_db = SubCmd::parse(matches).map(Self::QueryDB);
                 let rollback = SubCmd::parse(matches).map(Self::RollBack);
+                let verify_db = SubCmd::parse(matches).map(Self::VerifyDb);
                 let run_until = SubCmd::parse(matches).map(Self::RunUntil);
                 run.or(reset)
                     .or(dump_db)
                     .or(update_db)
                     .or(query_db)
                     .or(rollback)
+                    .or(verify_db)
                     .or(run_until)
                     // The `run` command is the default if no sub-command given
                     .or(Some(Self::Run(LedgerRun(args::LedgerRun {
@@ -925,6 +1069,7 @@ pub mod cmds {
                 .subcommand(LedgerUpdateDB::def())
                 .subcommand(LedgerQueryDB::def())
                 .subcommand(LedgerRollBack::def())
+                .subcommand(LedgerVerifyDb::def())
         }
     }
 
@@ -962,8 +1107,8 @@ pub mod cmds {
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Run Namada ledger node until a given height. Then halt \
-                     or suspend."
+                    "Run Namada ledger node until a given height or block \
+                     time. Then halt or suspend."
                 ))
                 .add_args::<args::LedgerRunUntil>()
         }
@@ -1073,6 +1218,26 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct LedgerVerifyDb;
+
+    impl SubCmd for LedgerVerifyDb {
+        const CMD: &'static str = "verify-db";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|_matches| Self)
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD).about(wrap!(
+                "Check the consistency of the Namada state at the last \
+                 committed height. The Merkle tree is restored from the DB \
+                 and checked against its stored roots and against the values \
+                 in storage. The DB is not modified."
+            ))
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub enum Config {
         Gen(ConfigGen),
@@ -1295,6 +1460,35 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryEthBridgePoolTransfers(
+        pub args::QueryEthBridgePool<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryEthBridgePoolTransfers {
+        const CMD: &'static str = "query-eth-bridge-pool";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryEthBridgePoolTransfers(args::QueryEthBridgePool::parse(
+                    matches,
+                ))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "List the transfers currently pending in the Ethereum \
+                     bridge pool."
+                ))
+                .add_args::<args::QueryEthBridgePool<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct TxCustom(pub args::TxCustom<args::CliTypes>);
 
@@ -1706,7 +1900,7 @@ pub mod cmds {
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryEpoch(pub args::Query<args::CliTypes>);
+    pub struct QueryEpoch(pub args::QueryEpoch<args::CliTypes>);
 
     impl SubCmd for QueryEpoch {
         const CMD: &'static str = "epoch";
@@ -1714,13 +1908,13 @@ pub mod cmds {
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| QueryEpoch(args::Query::parse(matches)))
+                .map(|matches| QueryEpoch(args::QueryEpoch::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!("Query the epoch of the last committed block."))
-                .add_args::<args::Query<args::CliTypes>>()
+                .add_args::<args::QueryEpoch<args::CliTypes>>()
         }
     }
 
@@ -1866,6 +2060,25 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryTotalSupply(pub args::QueryTotalSupply<args::CliTypes>);
+
+    impl SubCmd for QueryTotalSupply {
+        const CMD: &'static str = "query-total-supply";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryTotalSupply(args::QueryTotalSupply::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Query the total supply of a token."))
+                .add_args::<args::QueryTotalSupply<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryBonds(pub args::QueryBonds<args::CliTypes>);
 
@@ -1923,6 +2136,25 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct BroadcastTx(pub args::BroadcastTx<args::CliTypes>);
+
+    impl SubCmd for BroadcastTx {
+        const CMD: &'static str = "broadcast-tx";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| BroadcastTx(args::BroadcastTx::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Broadcast a signed transaction."))
+                .add_args::<args::BroadcastTx<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryValidatorState(
         pub args::QueryValidatorState<args::CliTypes>,
@@ -1939,6 +2171,7 @@ pub mod cmds {
 
         fn def() -> App {
             App::new(Self::CMD)
+                .visible_alias("query-validator-state")
                 .about(wrap!("Query the state of a PoS validator."))
                 .add_args::<args::QueryValidatorState<args::CliTypes>>()
         }
@@ -2020,6 +2253,7 @@ pub mod cmds {
 
         fn def() -> App {
             App::new(Self::CMD)
+                .visible_alias("query-rewards")
                 .about(wrap!(
                     "Query the latest rewards available to claim for a given \
                      delegation (or self-bond)."
@@ -2264,7 +2498,7 @@ pub mod cmds {
     }
 
     #[derive(Clone, Debug)]
-    pub struct TxRevealPk(pub args::RevealPk<args::CliTypes>);
+    pub struct TxRevealPk(pub args::RevealPks<args::CliTypes>);
 
     impl SubCmd for TxRevealPk {
         const CMD: &'static str = "reveal-pk";
@@ -2275,7 +2509,7 @@ pub mod cmds {
         {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| TxRevealPk(args::RevealPk::parse(matches)))
+                .map(|matches| TxRevealPk(args::RevealPks::parse(matches)))
         }
 
         fn def() -> App {
@@ -2289,7 +2523,7 @@ pub mod cmds {
                      signature verification on transactions authorized by \
                      this account."
                 ))
-                .add_args::<args::RevealPk<args::CliTypes>>()
+                .add_args::<args::RevealPks<args::CliTypes>>()
         }
     }
 
@@ -2387,11 +2621,14 @@ pub mod cmds {
     pub enum ClientUtils {
         JoinNetwork(JoinNetwork),
         ValidateWasm(ValidateWasm),
+        VerifyWasm(VerifyWasm),
+        ListWasms(ListWasms),
         InitNetwork(InitNetwork),
         DeriveGenesisAddresses(DeriveGenesisAddresses),
         GenesisBond(GenesisBond),
         InitGenesisEstablishedAccount(InitGenesisEstablishedAccount),
         InitGenesisValidator(InitGenesisValidator),
+        ExportPreGenesisValidator(ExportPreGenesisValidator),
         PkToTmAddress(PkToTmAddress),
         DefaultBaseDir(DefaultBaseDir),
         EpochSleep(EpochSleep),
@@ -2400,6 +2637,29 @@ pub mod cmds {
         ParseMigrationJson(MigrationJson),
     }
 
+    impl ClientUtils {
+        /// Whether this command creates or writes files in the base dir
+        pub fn writes_base_dir(&self) -> bool {
+            matches!(
+                self,
+                Self::JoinNetwork(_)
+                    | Self::ListWasms(_)
+                    | Self::InitGenesisEstablishedAccount(_)
+                    | Self::InitGenesisValidator(_)
+                    | Self::ExportPreGenesisValidator(_)
+                    | Self::EpochSleep(_)
+            )
+        }
+
+        /// Whether this command reads files from the base dir
+        pub fn reads_base_dir(&self) -> bool {
+            matches!(
+                self,
+                Self::SignGenesisTxs(_) | Self::DeriveGenesisAddresses(_)
+            )
+        }
+    }
+
     impl SubCmd for ClientUtils {
         const CMD: &'static str = "utils";
 
@@ -2409,6 +2669,8 @@ pub mod cmds {
                     SubCmd::parse(matches).map(Self::JoinNetwork);
                 let validate_wasm =
                     SubCmd::parse(matches).map(Self::ValidateWasm);
+                let verify_wasm = SubCmd::parse(matches).map(Self::VerifyWasm);
+                let list_wasms = SubCmd::parse(matches).map(Self::ListWasms);
                 let init_network =
                     SubCmd::parse(matches).map(Self::InitNetwork);
                 let derive_addresses =
@@ -2419,6 +2681,8 @@ pub mod cmds {
                     .map(Self::InitGenesisEstablishedAccount);
                 let init_genesis =
                     SubCmd::parse(matches).map(Self::InitGenesisValidator);
+                let export_pre_genesis_validator =
+                    SubCmd::parse(matches).map(Self::ExportPreGenesisValidator);
                 let pk_to_tm_address =
                     SubCmd::parse(matches).map(Self::PkToTmAddress);
                 let default_base_dir =
@@ -2432,11 +2696,14 @@ pub mod cmds {
                     SubCmd::parse(matches).map(Self::ParseMigrationJson);
                 join_network
                     .or(validate_wasm)
+                    .or(verify_wasm)
+                    .or(list_wasms)
                     .or(init_network)
                     .or(derive_addresses)
                     .or(genesis_bond)
                     .or(init_established)
                     .or(init_genesis)
+                    .or(export_pre_genesis_validator)
                     .or(pk_to_tm_address)
                     .or(default_base_dir)
                     .or(epoch_sleep)
@@ -2451,11 +2718,14 @@ pub mod cmds {
                 .about(wrap!("Utilities."))
                 .subcommand(JoinNetwork::def())
                 .subcommand(ValidateWasm::def())
+                .subcommand(VerifyWasm::def())
+                .subcommand(ListWasms::def())
                 .subcommand(InitNetwork::def())
                 .subcommand(DeriveGenesisAddresses::def())
                 .subcommand(GenesisBond::def())
                 .subcommand(InitGenesisEstablishedAccount::def())
                 .subcommand(InitGenesisValidator::def())
+                .subcommand(ExportPreGenesisValidator::def())
                 .subcommand(PkToTmAddress::def())
                 .subcommand(DefaultBaseDir::def())
                 .subcommand(EpochSleep::def())
@@ -2508,6 +2778,50 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct VerifyWasm(pub args::VerifyWasm);
+
+    impl SubCmd for VerifyWasm {
+        const CMD: &'static str = "verify-wasm";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::VerifyWasm::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Check the wasm files in the wasm directory against their \
+                     checksums, without downloading anything."
+                ))
+                .add_args::<args::VerifyWasm>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ListWasms(pub args::ListWasms);
+
+    impl SubCmd for ListWasms {
+        const CMD: &'static str = "list-wasms";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::ListWasms::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "List the wasm files available in the wasm directory of \
+                     the chain, with their SHA256 hashes."
+                ))
+                .add_args::<args::ListWasms>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct InitNetwork(pub args::InitNetwork);
 
@@ -2614,6 +2928,29 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct ExportPreGenesisValidator(pub args::ExportPreGenesisValidator);
+
+    impl SubCmd for ExportPreGenesisValidator {
+        const CMD: &'static str = "export-pre-genesis-validator";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::ExportPreGenesisValidator::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Export the keys of a genesis validator from the wallet \
+                     of a chain into a pre-genesis validator wallet, e.g. for \
+                     auditing or migrating them."
+                ))
+                .add_args::<args::ExportPreGenesisValidator>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct ValidateGenesisTemplates(pub args::ValidateGenesisTemplates);
 
@@ -3151,6 +3488,7 @@ pub mod cmds {
 pub mod args {
     use std::env;
     use std::net::SocketAddr;
+    use std::num::NonZeroUsize;
     use std::path::PathBuf;
     use std::str::FromStr;
 
@@ -3165,7 +3503,7 @@ pub mod args {
     use namada_sdk::ibc::core::host::types::identifiers::{ChannelId, PortId};
     use namada_sdk::keccak::KeccakHash;
     use namada_sdk::key::*;
-    use namada_sdk::masp::{MaspEpoch, PaymentAddress};
+    use namada_sdk::masp::{ExtendedViewingKey, MaspEpoch, PaymentAddress};
     use namada_sdk::storage::{self, BlockHeight, Epoch};
     use namada_sdk::time::DateTimeUtc;
     use namada_sdk::token::NATIVE_MAX_DECIMAL_PLACES;
@@ -3185,7 +3523,7 @@ pub mod args {
 
     use super::context::*;
     use super::utils::*;
-    use super::{ArgGroup, ArgMatches};
+    use super::{ArgAction, ArgGroup, ArgMatches};
     use crate::client::utils::PRE_GENESIS_DIR;
     use crate::config::genesis::GenesisAddress;
     use crate::config::{self, Action, ActionAtHeight};
@@ -3201,12 +3539,14 @@ pub mod args {
     pub const ALIAS_MANY: ArgMulti<String, GlobPlus> = arg_multi("aliases");
     pub const ALLOW_DUPLICATE_IP: ArgFlag = flag("allow-duplicate-ip");
     pub const AMOUNT: Arg<token::DenominatedAmount> = arg("amount");
+    pub const AMOUNT_MANY: ArgMulti<token::DenominatedAmount, GlobPlus> =
+        AMOUNT.multi_glob_plus();
     pub const ARCHIVE_DIR: ArgOpt<PathBuf> = arg_opt("archive-dir");
     pub const AVATAR_OPT: ArgOpt<String> = arg_opt("avatar");
     pub const BALANCE_OWNER: Arg<WalletBalanceOwner> = arg("owner");
     pub const BASE_DIR: ArgDefault<PathBuf> = arg_default(
         "base-dir",
-        DefaultFn(|| match env::var("NAMADA_BASE_DIR") {
+        DefaultFn(|| match env::var(ENV_VAR_BASE_DIR) {
             Ok(dir) => PathBuf::from(dir),
             Err(_) => config::get_default_namada_folder(),
         }),
@@ -3260,18 +3600,25 @@ pub mod args {
         "db-column-family",
         DefaultFn(|| storage::SUBSPACE_CF.to_string()),
     );
+    pub const DECODE_AS: ArgOpt<RawValueType> = arg_opt("decode-as");
     pub const DECRYPT: ArgFlag = flag("decrypt");
+    pub const DESC: ArgFlag = flag("desc");
     pub const DESCRIPTION_OPT: ArgOpt<String> = arg_opt("description");
     pub const DISPOSABLE_SIGNING_KEY: ArgFlag = flag("disposable-gas-payer");
     pub const DESTINATION_VALIDATOR: Arg<WalletAddress> =
         arg("destination-validator");
+    pub const DETERMINISTIC: ArgFlag = flag("deterministic");
     pub const DISCORD_OPT: ArgOpt<String> = arg_opt("discord-handle");
+    pub const DIVERSIFIER_INDEX: ArgOpt<u64> = arg_opt("index");
     pub const DO_IT: ArgFlag = flag("do-it");
     pub const DONT_PREFETCH_WASM: ArgFlag = flag("dont-prefetch-wasm");
     pub const DRY_RUN_TX: ArgFlag = flag("dry-run");
     pub const DRY_RUN_WRAPPER_TX: ArgFlag = flag("dry-run-wrapper");
     pub const DUMP_TX: ArgFlag = flag("dump-tx");
+    pub const DUMP_TX_PATH: ArgOpt<PathBuf> = arg_opt("dump-tx-path");
     pub const EPOCH: ArgOpt<Epoch> = arg_opt("epoch");
+    pub const EPOCH_FROM_OPT: ArgOpt<Epoch> = arg_opt("from-epoch");
+    pub const EPOCH_TO_OPT: ArgOpt<Epoch> = arg_opt("to-epoch");
     pub const ERC20: Arg<EthAddress> = arg("erc20");
     pub const ETH_CONFIRMATIONS: Arg<u64> = arg("confirmations");
     pub const ETH_GAS: ArgOpt<u64> = arg_opt("eth-gas");
@@ -3283,7 +3630,7 @@ pub mod args {
         DefaultFn(|| "http://localhost:8545".into()),
     );
     pub const ETH_SYNC: ArgFlag = flag("sync");
-    pub const EXPIRATION_OPT: ArgOpt<DateTimeUtc> = arg_opt("expiration");
+    pub const EXPIRATION_OPT: ArgOpt<TxExpiration> = arg_opt("expiration");
     pub const EMAIL: Arg<String> = arg("email");
     pub const EMAIL_OPT: ArgOpt<String> = EMAIL.opt();
     pub const FEE_AMOUNT_OPT: ArgOpt<token::DenominatedAmount> =
@@ -3291,6 +3638,8 @@ pub mod args {
     pub const FEE_PAYER_OPT: ArgOpt<WalletPublicKey> = arg_opt("gas-payer");
     pub const FILE_PATH: Arg<String> = arg("file");
     pub const FORCE: ArgFlag = flag("force");
+    pub const FROM_ARCHIVE: ArgOpt<PathBuf> = arg_opt("from-archive");
+    pub const FROM_STORED_MNEMONIC: ArgFlag = flag("from-stored-mnemonic");
     pub const GAS_LIMIT: ArgDefault<GasLimit> = arg_default(
         "gas-limit",
         DefaultFn(|| GasLimit::from(DEFAULT_GAS_LIMIT)),
@@ -3330,6 +3679,7 @@ pub mod args {
         arg_opt("ibc-shielding-data");
     pub const IBC_MEMO: ArgOpt<String> = arg_opt("ibc-memo");
     pub const INPUT_OPT: ArgOpt<PathBuf> = arg_opt("input");
+    pub const JSON: ArgFlag = flag("json");
     pub const LEDGER_ADDRESS_ABOUT: &str = textwrap_macros::fill!(
         "Address of a ledger node as \"{scheme}://{host}:{port}\". If the \
          scheme is not supplied, it is assumed to be TCP.",
@@ -3352,9 +3702,14 @@ pub mod args {
     pub const MAX_ETH_GAS: ArgOpt<u64> = arg_opt("max_eth-gas");
     pub const MEMO_OPT: ArgOpt<String> = arg_opt("memo");
     pub const MIGRATION_PATH: ArgOpt<PathBuf> = arg_opt("migration-path");
+    pub const MNEMONIC_WORDS: ArgDefault<usize> = arg_default(
+        "mnemonic-words",
+        DefaultFn(|| namada_sdk::wallet::DEFAULT_MNEMONIC_WORDS),
+    );
     pub const MODE: ArgOpt<String> = arg_opt("mode");
     pub const NET_ADDRESS: Arg<SocketAddr> = arg("net-address");
     pub const NAMADA_START_TIME: ArgOpt<DateTimeUtc> = arg_opt("time");
+    pub const NEW_ALIAS: Arg<String> = arg("new-alias");
     pub const NO_CONVERSIONS: ArgFlag = flag("no-conversions");
     pub const NO_EXPIRATION: ArgFlag = flag("no-expiration");
     pub const NUT: ArgFlag = flag("nut");
@@ -3362,6 +3717,8 @@ pub mod args {
     pub const OUTPUT: ArgOpt<PathBuf> = arg_opt("output");
     pub const OUTPUT_FOLDER_PATH: ArgOpt<PathBuf> =
         arg_opt("output-folder-path");
+    pub const OUTPUT_FORMAT: ArgDefault<OutputFormat> =
+        arg_default("output-format", DefaultFn(|| OutputFormat::Text));
     pub const OWNER: Arg<WalletAddress> = arg("owner");
     pub const OWNER_OPT: ArgOpt<WalletAddress> = OWNER.opt();
     pub const PATH: Arg<PathBuf> = arg("path");
@@ -3375,9 +3732,12 @@ pub mod args {
     pub const PROPOSAL_ETH: ArgFlag = flag("eth");
     pub const PROPOSAL_PGF_STEWARD: ArgFlag = flag("pgf-stewards");
     pub const PROPOSAL_PGF_FUNDING: ArgFlag = flag("pgf-funding");
+    pub const PROPOSAL_VOTES: ArgFlag = flag("votes");
     pub const PROTOCOL_KEY: ArgOpt<WalletPublicKey> = arg_opt("protocol-key");
     pub const PRE_GENESIS_PATH: ArgOpt<PathBuf> = arg_opt("pre-genesis-path");
     pub const PUBLIC_KEY: Arg<WalletPublicKey> = arg("public-key");
+    pub const PUBLIC_KEY_MANY: ArgMulti<WalletPublicKey, GlobPlus> =
+        PUBLIC_KEY.multi_glob_plus();
     pub const PUBLIC_KEYS: ArgMulti<WalletPublicKey, GlobStar> =
         arg_multi("public-keys");
     pub const PROPOSAL_ID: Arg<u64> = arg("proposal-id");
@@ -3403,9 +3763,13 @@ pub mod args {
         arg_opt("refund-target");
     pub const RELAYER: Arg<Address> = arg("relayer");
     pub const SAFE_MODE: ArgFlag = flag("safe-mode");
+    pub const SCAN_JOBS: ArgDefault<NonZeroUsize> =
+        arg_default("scan-jobs", DefaultFn(|| NonZeroUsize::MIN));
     pub const SCHEME: ArgDefault<SchemeType> =
         arg_default("scheme", DefaultFn(|| SchemeType::Ed25519));
+    pub const SCHEME_OPT: ArgOpt<SchemeType> = arg_opt("scheme");
     pub const SHELL: Arg<Shell> = arg("shell");
+    pub const SEED: ArgOpt<String> = arg_opt("seed");
     pub const SELF_BOND_AMOUNT: Arg<token::DenominatedAmount> =
         arg("self-bond-amount");
     pub const SENDER: Arg<String> = arg("sender");
@@ -3415,6 +3779,7 @@ pub mod args {
     pub const SIGNING_KEYS: ArgMulti<WalletPublicKey, GlobStar> =
         arg_multi("signing-keys");
     pub const SIGNATURES: ArgMulti<PathBuf, GlobStar> = arg_multi("signatures");
+    pub const SORT_VALIDATORS: ArgOpt<ValidatorSortBy> = arg_opt("sort");
     pub const SOURCE: Arg<WalletAddress> = arg("source");
     pub const SOURCE_OPT: ArgOpt<WalletAddress> = SOURCE.opt();
     pub const SOURCE_VALIDATOR: Arg<WalletAddress> = arg("source-validator");
@@ -3423,8 +3788,12 @@ pub mod args {
         arg_multi("spending-keys");
     pub const STEWARD: Arg<WalletAddress> = arg("steward");
     pub const STORAGE_KEY: Arg<storage::Key> = arg("storage-key");
+    pub const STORAGE_KEY_PREFIX: ArgFlag = flag("prefix");
+    pub const STORE_MNEMONIC: ArgFlag = flag("store-mnemonic");
     pub const SUSPEND_ACTION: ArgFlag = flag("suspend");
     pub const TARGET: Arg<WalletAddress> = arg("target");
+    pub const TARGET_MANY: ArgMulti<WalletAddress, GlobPlus> =
+        TARGET.multi_glob_plus();
     pub const TEMPLATES_PATH: Arg<PathBuf> = arg("templates-path");
     pub const TIMEOUT_HEIGHT: ArgOpt<u64> = arg_opt("timeout-height");
     pub const TIMEOUT_SEC_OFFSET: ArgOpt<u64> = arg_opt("timeout-sec-offset");
@@ -3432,6 +3801,8 @@ pub mod args {
     pub const TOKEN_OPT: ArgOpt<WalletAddress> = TOKEN.opt();
     pub const TOKEN_STR_OPT: ArgOpt<String> = TOKEN_STR.opt();
     pub const TOKEN: Arg<WalletAddress> = arg("token");
+    pub const TOKEN_MANY: ArgMulti<WalletAddress, GlobStar> =
+        TOKEN.multi_glob_star();
     pub const TOKEN_STR: Arg<String> = arg("token");
     pub const TRANSFER_SOURCE: Arg<WalletTransferSource> = arg("source");
     pub const TRANSFER_TARGET: Arg<WalletTransferTarget> = arg("target");
@@ -3440,6 +3811,8 @@ pub mod args {
     pub const THRESHOLD: ArgOpt<u8> = arg_opt("threshold");
     pub const UNSAFE_DONT_ENCRYPT: ArgFlag = flag("unsafe-dont-encrypt");
     pub const UNSAFE_SHOW_SECRET: ArgFlag = flag("unsafe-show-secret");
+    pub const UNTIL_HEIGHT: ArgOpt<BlockHeight> = BLOCK_HEIGHT.opt();
+    pub const UNTIL_TIME: ArgOpt<DateTimeUtc> = arg_opt("until-time");
     pub const USE_DEVICE: ArgFlag = flag("use-device");
     pub const VALIDATOR: Arg<WalletAddress> = arg("validator");
     pub const VALIDATOR_OPT: ArgOpt<WalletAddress> = VALIDATOR.opt();
@@ -3459,12 +3832,14 @@ pub mod args {
     pub const VALUE: Arg<String> = arg("value");
     pub const VOTER_OPT: ArgOpt<WalletAddress> = arg_opt("voter");
     pub const VIEWING_KEY: Arg<WalletViewingKey> = arg("key");
+    pub const VIEWING_KEY_FILE: ArgOpt<PathBuf> = arg_opt("viewing-key-file");
     pub const VIEWING_KEYS: ArgMulti<WalletViewingKey, GlobStar> =
         arg_multi("viewing-keys");
     pub const VP: ArgOpt<String> = arg_opt("vp");
     pub const WALLET_ALIAS_FORCE: ArgFlag = flag("wallet-alias-force");
     pub const WASM_CHECKSUMS_PATH: Arg<PathBuf> = arg("wasm-checksums-path");
     pub const WASM_DIR: ArgOpt<PathBuf> = arg_opt("wasm-dir");
+    pub const WATCH: ArgFlag = flag("watch");
     pub const WEBSITE_OPT: ArgOpt<String> = arg_opt("website");
     pub const WITH_INDEXER: ArgOpt<String> = arg_opt("with-indexer");
     pub const TX_PATH: Arg<PathBuf> = arg("tx-path");
@@ -3600,7 +3975,8 @@ pub mod args {
             Self {
                 time: NAMADA_START_TIME.parse(matches),
                 action_at_height: ActionAtHeight {
-                    height: BLOCK_HEIGHT.parse(matches),
+                    height: UNTIL_HEIGHT.parse(matches),
+                    time: UNTIL_TIME.parse(matches),
                     action: if HALT_ACTION.parse(matches) {
                         Action::Halt
                     } else {
@@ -3617,25 +3993,34 @@ pub mod args {
                     .help(wrap!("The start time of the ledger.")),
             )
             .arg(
-                BLOCK_HEIGHT
+                UNTIL_HEIGHT
                     .def()
                     .help(wrap!("The block height to run until.")),
             )
-            .arg(
-                HALT_ACTION
-                    .def()
-                    .help(wrap!("Halt at the given block height")),
-            )
-            .arg(
-                SUSPEND_ACTION
-                    .def()
-                    .help(wrap!("Suspend consensus at the given block height")),
-            )
+            .arg(UNTIL_TIME.def().help(wrap!(
+                "The block time to run until. The action is taken at the \
+                 first block with a time at or after this one. Accepts a \
+                 strict subset of RFC3339."
+            )))
+            .arg(HALT_ACTION.def().help(wrap!(
+                "Halt at the given block height or block time, whichever is \
+                 reached first."
+            )))
+            .arg(SUSPEND_ACTION.def().help(wrap!(
+                "Suspend consensus at the given block height or block time, \
+                 whichever is reached first."
+            )))
             .group(
                 ArgGroup::new("find_flags")
                     .args([HALT_ACTION.name, SUSPEND_ACTION.name])
                     .required(true),
             )
+            .group(
+                ArgGroup::new("run_until_target")
+                    .args([UNTIL_HEIGHT.name, UNTIL_TIME.name])
+                    .multiple(true)
+                    .required(true),
+            )
         }
     }
 
@@ -3663,8 +4048,7 @@ pub mod args {
 
         fn def(app: App) -> App {
             app.arg(BLOCK_HEIGHT_OPT.def().help(wrap!(
-                "The block height to dump. Defaults to latest committed
-                block."
+                "The block height to dump. Defaults to latest committed block."
             )))
             .arg(OUT_FILE_PATH_OPT.def().help(wrap!(
                 "Path for the output file (omitting file extension). Defaults \
@@ -4458,16 +4842,23 @@ pub mod args {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
             let source = SOURCE.parse(matches);
-            let target = TARGET.parse(matches);
+            let targets = TARGET_MANY.parse(matches);
             let token = TOKEN.parse(matches);
-            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let amounts = AMOUNT_MANY.parse(matches);
             let tx_code_path = PathBuf::from(TX_TRANSFER_WASM);
-            let data = vec![TxTransparentTransferData {
-                source,
-                target,
-                token,
-                amount,
-            }];
+            let data = pair_targets_with_amounts(targets, amounts)
+                .unwrap_or_else(|err| {
+                    eprintln!("{err}");
+                    safe_exit(1)
+                })
+                .into_iter()
+                .map(|(target, amount)| TxTransparentTransferData {
+                    source: source.clone(),
+                    target,
+                    token: token.clone(),
+                    amount: InputAmount::Unvalidated(amount),
+                })
+                .collect();
 
             Self {
                 tx,
@@ -4482,16 +4873,51 @@ pub mod args {
                     "The source account address. The source's key may be used \
                      to produce the signature."
                 )))
-                .arg(TARGET.def().help(wrap!("The target account address.")))
+                .arg(
+                    TARGET_MANY
+                        .def()
+                        .required(true)
+                        .action(ArgAction::Append)
+                        .help(wrap!(
+                            "The target account address. Repeat it, or give a \
+                             comma separated list, to transfer to several \
+                             targets in a single transaction."
+                        )),
+                )
                 .arg(TOKEN.def().help(wrap!("The token address.")))
                 .arg(
-                    AMOUNT
+                    AMOUNT_MANY
                         .def()
-                        .help(wrap!("The amount to transfer in decimal.")),
+                        .required(true)
+                        .action(ArgAction::Append)
+                        .help(wrap!(
+                            "The amount to transfer in decimal. There must be \
+                             one amount for each target, given in the same \
+                             order."
+                        )),
                 )
         }
     }
 
+    /// Pair up the targets of a transfer with their amounts, in the order
+    /// they were given
+    pub(crate) fn pair_targets_with_amounts(
+        targets: Vec<WalletAddress>,
+        amounts: Vec<token::DenominatedAmount>,
+    ) -> Result<Vec<(WalletAddress, token::DenominatedAmount)>, String> {
+        if targets.len() != amounts.len() {
+            return Err(format!(
+                "Got {} target(s) but {} amount(s), each `--{}` must have a \
+                 matching `--{}`.",
+                targets.len(),
+                amounts.len(),
+                TARGET_MANY.name,
+                AMOUNT_MANY.name,
+            ));
+        }
+        Ok(targets.into_iter().zip(amounts).collect())
+    }
+
     impl CliToSdk<TxShieldedTransfer<SdkTypes>> for TxShieldedTransfer<CliTypes> {
         type Error = std::io::Error;
 
@@ -5692,34 +6118,47 @@ pub mod args {
         }
     }
 
-    impl CliToSdk<RevealPk<SdkTypes>> for RevealPk<CliTypes> {
+    impl CliToSdk<RevealPks<SdkTypes>> for RevealPks<CliTypes> {
         type Error = std::io::Error;
 
         fn to_sdk(
             self,
             ctx: &mut Context,
-        ) -> Result<RevealPk<SdkTypes>, Self::Error> {
+        ) -> Result<RevealPks<SdkTypes>, Self::Error> {
             let tx = self.tx.to_sdk(ctx)?;
             let chain_ctx = ctx.borrow_mut_chain_or_exit();
 
-            Ok(RevealPk::<SdkTypes> {
+            Ok(RevealPks::<SdkTypes> {
                 tx,
-                public_key: chain_ctx.get(&self.public_key),
+                public_keys: self
+                    .public_keys
+                    .iter()
+                    .map(|public_key| chain_ctx.get(public_key))
+                    .collect(),
             })
         }
     }
 
-    impl Args for RevealPk<CliTypes> {
+    impl Args for RevealPks<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
-            let public_key = PUBLIC_KEY.parse(matches);
+            let public_keys = PUBLIC_KEY_MANY.parse(matches);
 
-            Self { tx, public_key }
+            Self { tx, public_keys }
         }
 
         fn def(app: App) -> App {
-            app.add_args::<Tx<CliTypes>>()
-                .arg(PUBLIC_KEY.def().help(wrap!("A public key to reveal.")))
+            app.add_args::<Tx<CliTypes>>().arg(
+                PUBLIC_KEY_MANY
+                    .def()
+                    .required(true)
+                    .action(ArgAction::Append)
+                    .help(wrap!(
+                        "The public keys to reveal, comma-separated or given \
+                         by repeating the argument. A tx is submitted for \
+                         each key."
+                    )),
+            )
         }
     }
 
@@ -5756,6 +6195,7 @@ pub mod args {
             Ok(QueryProposal::<SdkTypes> {
                 query: self.query.to_sdk(ctx)?,
                 proposal_id: self.proposal_id,
+                votes: self.votes,
             })
         }
     }
@@ -5764,16 +6204,28 @@ pub mod args {
         fn parse(matches: &ArgMatches) -> Self {
             let query = Query::parse(matches);
             let proposal_id = PROPOSAL_ID_OPT.parse(matches);
+            let votes = PROPOSAL_VOTES.parse(matches);
 
-            Self { query, proposal_id }
+            Self {
+                query,
+                proposal_id,
+                votes,
+            }
         }
 
         fn def(app: App) -> App {
-            app.add_args::<Query<CliTypes>>().arg(
-                PROPOSAL_ID_OPT
-                    .def()
-                    .help(wrap!("The proposal identifier.")),
-            )
+            app.add_args::<Query<CliTypes>>()
+                .arg(
+                    PROPOSAL_ID_OPT
+                        .def()
+                        .help(wrap!("The proposal identifier.")),
+                )
+                .arg(PROPOSAL_VOTES.def().requires(PROPOSAL_ID_OPT.name).help(
+                    wrap!(
+                        "Also print every vote cast on the proposal, with the \
+                         voter, its validator and its voting power."
+                    ),
+                ))
         }
     }
 
@@ -5908,6 +6360,31 @@ pub mod args {
         }
     }
 
+    impl Args for QueryEthBridgePool<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+
+            Self { query }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+        }
+    }
+
+    impl CliToSdk<QueryEthBridgePool<SdkTypes>> for QueryEthBridgePool<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryEthBridgePool<SdkTypes>, Self::Error> {
+            Ok(QueryEthBridgePool::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+            })
+        }
+    }
+
     impl CliToSdk<Withdraw<SdkTypes>> for Withdraw<CliTypes> {
         type Error = std::io::Error;
 
@@ -6006,6 +6483,7 @@ pub mod args {
                 query: self.query.to_sdk(ctx)?,
                 token: self.token.map(|x| ctx.borrow_chain_or_exit().get(&x)),
                 epoch: self.epoch,
+                output_format: self.output_format,
             })
         }
     }
@@ -6015,10 +6493,12 @@ pub mod args {
             let query = Query::parse(matches);
             let token = TOKEN_OPT.parse(matches);
             let epoch = MASP_EPOCH.parse(matches);
+            let output_format = OUTPUT_FORMAT.parse(matches);
             Self {
                 query,
                 epoch,
                 token,
+                output_format,
             }
         }
 
@@ -6030,6 +6510,10 @@ pub mod args {
                 .arg(TOKEN_OPT.def().help(wrap!(
                     "The token address for which to query conversions."
                 )))
+                .arg(OUTPUT_FORMAT.def().help(wrap!(
+                    "The format of the output, either `text` or `json`. \
+                     Defaults to `text`."
+                )))
         }
     }
 
@@ -6077,8 +6561,14 @@ pub mod args {
             Ok(QueryBalance::<SdkTypes> {
                 query,
                 owner: chain_ctx.get_cached(&self.owner),
-                token: chain_ctx.get(&self.token),
+                tokens: self
+                    .tokens
+                    .iter()
+                    .map(|token| chain_ctx.get(token))
+                    .collect(),
                 no_conversions: self.no_conversions,
+                epoch: self.epoch,
+                output_format: self.output_format,
             })
         }
     }
@@ -6086,32 +6576,115 @@ pub mod args {
     impl Args for QueryBalance<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let query = Query::parse(matches);
-            let owner = BALANCE_OWNER.parse(matches);
-            let token = TOKEN.parse(matches);
+            let owner = match VIEWING_KEY_FILE.parse(matches) {
+                Some(path) => {
+                    let data =
+                        std::fs::read_to_string(&path).unwrap_or_else(|err| {
+                            eprintln!(
+                                "Failed to open the viewing key file {}: {err}",
+                                path.to_string_lossy()
+                            );
+                            safe_exit(1)
+                        });
+                    let viewing_key = ExtendedViewingKey::from_str(data.trim())
+                        .unwrap_or_else(|err| {
+                            eprintln!(
+                                "Failed to decode the viewing key from {}: \
+                                 {err}",
+                                path.to_string_lossy()
+                            );
+                            safe_exit(1)
+                        });
+                    WalletBalanceOwner::new(viewing_key.to_string())
+                }
+                None => BALANCE_OWNER.parse(matches),
+            };
+            let tokens = TOKEN_MANY.parse(matches);
             let no_conversions = NO_CONVERSIONS.parse(matches);
+            let epoch = EPOCH.parse(matches);
+            let output_format = OUTPUT_FORMAT.parse(matches);
             Self {
                 query,
                 owner,
-                token,
+                tokens,
                 no_conversions,
+                epoch,
+                output_format,
             }
         }
 
         fn def(app: App) -> App {
             app.add_args::<Query<CliTypes>>()
                 .arg(
-                    BALANCE_OWNER.def().help(wrap!(
-                        "The account address whose balance to query."
-                    )),
+                    BALANCE_OWNER
+                        .def()
+                        .required(false)
+                        .required_unless_present(VIEWING_KEY_FILE.name)
+                        .help(wrap!(
+                            "The account address whose balance to query."
+                        )),
                 )
                 .arg(
-                    TOKEN.def().help(wrap!(
-                        "The token's address whose balance to query."
-                    )),
+                    VIEWING_KEY_FILE
+                        .def()
+                        .conflicts_with(BALANCE_OWNER.name)
+                        .help(wrap!(
+                            "Path to a file containing the viewing key whose \
+                             shielded balance to query. The viewing key is \
+                             not added to the wallet."
+                        )),
                 )
+                .arg(TOKEN_MANY.def().action(ArgAction::Append).help(wrap!(
+                    "The address of a token whose balance to query. May be \
+                     given multiple times to query several tokens. If \
+                     omitted, the balances of all the tokens known to the \
+                     wallet are queried."
+                )))
                 .arg(NO_CONVERSIONS.def().help(wrap!(
                     "Whether not to automatically perform conversions."
                 )))
+                .arg(EPOCH.def().help(wrap!(
+                    "The epoch at the end of which to query the balance of a \
+                     transparent account. Defaults to the latest committed \
+                     block."
+                )))
+                .arg(OUTPUT_FORMAT.def().help(wrap!(
+                    "The format of the output, either `text` or `json`. \
+                     Defaults to `text`."
+                )))
+        }
+    }
+
+    impl CliToSdk<QueryTotalSupply<SdkTypes>> for QueryTotalSupply<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryTotalSupply<SdkTypes>, Self::Error> {
+            let query = self.query.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(QueryTotalSupply::<SdkTypes> {
+                query,
+                token: chain_ctx.get(&self.token),
+            })
+        }
+    }
+
+    impl Args for QueryTotalSupply<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let token = TOKEN.parse(matches);
+            Self { query, token }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>().arg(
+                TOKEN
+                    .def()
+                    .help(wrap!("The token's address whose supply to query.")),
+            )
         }
     }
 
@@ -6129,6 +6702,9 @@ pub mod args {
                 query,
                 owner: self.owner.map(|x| chain_ctx.get(&x)),
                 validator: self.validator.map(|x| chain_ctx.get(&x)),
+                from_epoch: self.from_epoch,
+                to_epoch: self.to_epoch,
+                output_format: self.output_format,
             })
         }
     }
@@ -6138,10 +6714,16 @@ pub mod args {
             let query = Query::parse(matches);
             let owner = OWNER_OPT.parse(matches);
             let validator = VALIDATOR_OPT.parse(matches);
+            let from_epoch = EPOCH_FROM_OPT.parse(matches);
+            let to_epoch = EPOCH_TO_OPT.parse(matches);
+            let output_format = OUTPUT_FORMAT.parse(matches);
             Self {
                 query,
                 owner,
                 validator,
+                from_epoch,
+                to_epoch,
+                output_format,
             }
         }
 
@@ -6153,6 +6735,18 @@ pub mod args {
                 .arg(VALIDATOR_OPT.def().help(wrap!(
                     "The validator's address whose bonds to query."
                 )))
+                .arg(EPOCH_FROM_OPT.def().help(wrap!(
+                    "Only show the bonds that started and the unbonds that \
+                     become withdrawable at or after this epoch."
+                )))
+                .arg(EPOCH_TO_OPT.def().help(wrap!(
+                    "Only show the bonds that started and the unbonds that \
+                     become withdrawable at or before this epoch."
+                )))
+                .arg(OUTPUT_FORMAT.def().help(wrap!(
+                    "The format of the output, either `text` or `json`. \
+                     Defaults to `text`."
+                )))
         }
     }
 
@@ -6169,6 +6763,8 @@ pub mod args {
                     .validator
                     .map(|x| ctx.borrow_chain_or_exit().get(&x)),
                 epoch: self.epoch,
+                sort: self.sort,
+                desc: self.desc,
             })
         }
     }
@@ -6178,10 +6774,14 @@ pub mod args {
             let query = Query::parse(matches);
             let validator = VALIDATOR_OPT.parse(matches);
             let epoch = EPOCH.parse(matches);
+            let sort = SORT_VALIDATORS.parse(matches);
+            let desc = DESC.parse(matches);
             Self {
                 query,
                 validator,
                 epoch,
+                sort,
+                desc,
             }
         }
 
@@ -6194,6 +6794,24 @@ pub mod args {
                     "The epoch at which to query (corresponding to the last \
                      committed block, if not specified)."
                 )))
+                .arg(
+                    SORT_VALIDATORS
+                        .def()
+                        .help(wrap!(
+                            "Sort the listed validators by \"stake\" or \
+                             \"address\", in ascending order unless \
+                             `--desc` is given. When not specified, the \
+                             validators are listed by descending stake."
+                        ))
+                        .conflicts_with(VALIDATOR_OPT.name),
+                )
+                .arg(
+                    DESC.def()
+                        .help(wrap!(
+                            "Sort the listed validators in descending order."
+                        ))
+                        .requires(SORT_VALIDATORS.name),
+                )
         }
     }
 
@@ -6552,11 +7170,19 @@ pub mod args {
         ) -> Result<SignTx<SdkTypes>, Self::Error> {
             let tx = self.tx.to_sdk(ctx)?;
             let tx_data = std::fs::read(self.tx_data)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
 
             Ok(SignTx::<SdkTypes> {
                 tx,
                 tx_data,
-                owner: ctx.borrow_chain_or_exit().get(&self.owner),
+                owner: chain_ctx.get(&self.owner),
+                account_public_keys: self
+                    .account_public_keys
+                    .iter()
+                    .map(|key| chain_ctx.get(key))
+                    .collect(),
+                threshold: self.threshold,
+                output: self.output,
             })
         }
     }
@@ -6566,21 +7192,95 @@ pub mod args {
             let tx = Tx::parse(matches);
             let tx_path = TX_PATH.parse(matches);
             let owner = OWNER.parse(matches);
+            let account_public_keys = PUBLIC_KEYS.parse(matches);
+            let threshold = THRESHOLD.parse(matches);
+            let output = OUTPUT.parse(matches);
             Self {
                 tx,
                 tx_data: tx_path,
                 owner,
+                account_public_keys,
+                threshold,
+                output,
             }
         }
 
         fn def(app: App) -> App {
             app.add_args::<Tx<CliTypes>>()
-                .arg(TX_PATH.def().help(wrap!(
-                    "The path to the tx file with the serialized tx."
-                )))
+                .arg(
+                    TX_PATH
+                        .def()
+                        .help(wrap!(
+                            "The path to the tx file with the serialized tx."
+                        ))
+                        .alias("input"),
+                )
                 .arg(
                     OWNER.def().help(wrap!("The address of the account owner")),
                 )
+                .arg(PUBLIC_KEYS.def().help(wrap!(
+                    "The public keys of the owner's account, in the order \
+                     they were registered. Required to sign without a node \
+                     connection for an established account. They are \
+                     otherwise queried from the node."
+                )))
+                .arg(
+                    THRESHOLD
+                        .def()
+                        .help(wrap!(
+                            "The threshold of the owner's account. Required \
+                             with --public-keys for an established account."
+                        ))
+                        .requires(PUBLIC_KEYS.name),
+                )
+                .arg(OUTPUT.def().help(wrap!(
+                    "Add the owner's signatures from the wallet to those the \
+                     tx already carries and write it to this file. Once they \
+                     meet the owner's threshold, the fee payer's key signs \
+                     the wrapper and the file is ready for `broadcast-tx`. \
+                     Until then, the other signers can each run `sign-tx` \
+                     on the file. By default, the owner's signatures are \
+                     written to separate files."
+                )))
+        }
+    }
+
+    impl CliToSdk<BroadcastTx<SdkTypes>> for BroadcastTx<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<BroadcastTx<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let tx_data = std::fs::read(self.tx_data)?;
+
+            Ok(BroadcastTx::<SdkTypes> { tx, tx_data })
+        }
+    }
+
+    impl Args for BroadcastTx<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let tx_path = TX_PATH.parse(matches);
+            Self {
+                tx,
+                tx_data: tx_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>().arg(
+                TX_PATH
+                    .def()
+                    .help(wrap!(
+                        "The path to the tx file with the signed serialized \
+                         tx. Its wrapper signature and the thresholds of the \
+                         accounts that signed it are checked before it's \
+                         broadcast."
+                    ))
+                    .alias("input"),
+            )
         }
     }
 
@@ -6592,6 +7292,7 @@ pub mod args {
             let spending_keys = SPENDING_KEYS.parse(matches);
             let viewing_keys = VIEWING_KEYS.parse(matches);
             let with_indexer = WITH_INDEXER.parse(matches);
+            let scan_jobs = SCAN_JOBS.parse(matches);
             Self {
                 ledger_address,
                 start_query_height,
@@ -6599,6 +7300,7 @@ pub mod args {
                 spending_keys,
                 viewing_keys,
                 with_indexer,
+                scan_jobs,
             }
         }
 
@@ -6625,6 +7327,10 @@ pub mod args {
                      present, the shielded sync will be performed using data \
                      retrieved from the given indexer."
                 )))
+                .arg(SCAN_JOBS.def().help(wrap!(
+                    "Number of threads used to trial-decrypt the fetched notes \
+                     with the viewing keys. Defaults to 1."
+                )))
         }
     }
 
@@ -6652,6 +7358,7 @@ pub mod args {
                     .map(|vk| chain_ctx.get_cached(vk))
                     .collect(),
                 with_indexer: self.with_indexer.map(|_| ()),
+                scan_jobs: self.scan_jobs,
             })
         }
     }
@@ -6732,6 +7439,8 @@ pub mod args {
                 query: self.query.to_sdk(ctx)?,
                 validator: ctx.borrow_chain_or_exit().get(&self.validator),
                 epoch: self.epoch,
+                from_epoch: self.from_epoch,
+                to_epoch: self.to_epoch,
             })
         }
     }
@@ -6741,10 +7450,14 @@ pub mod args {
             let query = Query::parse(matches);
             let validator = VALIDATOR.parse(matches);
             let epoch = EPOCH.parse(matches);
+            let from_epoch = EPOCH_FROM_OPT.parse(matches);
+            let to_epoch = EPOCH_TO_OPT.parse(matches);
             Self {
                 query,
                 validator,
                 epoch,
+                from_epoch,
+                to_epoch,
             }
         }
 
@@ -6757,6 +7470,26 @@ pub mod args {
                     "The epoch at which to query (corresponding to the last \
                      committed block, if not specified)."
                 )))
+                .arg(
+                    EPOCH_FROM_OPT
+                        .def()
+                        .help(wrap!(
+                            "Show the history of the commission rate in every \
+                             epoch starting from this one. The history can \
+                             span at most 100 epochs."
+                        ))
+                        .conflicts_with(EPOCH.name),
+                )
+                .arg(
+                    EPOCH_TO_OPT
+                        .def()
+                        .help(wrap!(
+                            "The last epoch of the commission rate history \
+                             (the epoch of the last committed block, if not \
+                             specified)."
+                        ))
+                        .requires(EPOCH_FROM_OPT.name),
+                )
         }
     }
 
@@ -6801,6 +7534,9 @@ pub mod args {
                 validator: self
                     .validator
                     .map(|x| ctx.borrow_chain_or_exit().get(&x)),
+                owner: self
+                    .owner
+                    .map(|x| ctx.borrow_chain_or_exit().get(&x)),
             })
         }
     }
@@ -6809,15 +7545,24 @@ pub mod args {
         fn parse(matches: &ArgMatches) -> Self {
             let query = Query::parse(matches);
             let validator = VALIDATOR_OPT.parse(matches);
-            Self { query, validator }
+            let owner = OWNER_OPT.parse(matches);
+            Self {
+                query,
+                validator,
+                owner,
+            }
         }
 
         fn def(app: App) -> App {
-            app.add_args::<Query<CliTypes>>().arg(
-                VALIDATOR_OPT.def().help(wrap!(
+            app.add_args::<Query<CliTypes>>()
+                .arg(VALIDATOR_OPT.def().help(wrap!(
                     "The validator's address whose slashes to query."
-                )),
-            )
+                )))
+                .arg(OWNER_OPT.def().help(wrap!(
+                    "Show the amount of tokens that the bonds and unbonds of \
+                     this owner lost to slashes. Only the bonds to the given \
+                     validator are considered, if any."
+                )))
         }
     }
 
@@ -6935,6 +7680,35 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<QueryEpoch<SdkTypes>> for QueryEpoch<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryEpoch<SdkTypes>, Self::Error> {
+            Ok(QueryEpoch::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                watch: self.watch,
+            })
+        }
+    }
+
+    impl Args for QueryEpoch<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let watch = WATCH.parse(matches);
+            Self { query, watch }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>().arg(WATCH.def().help(wrap!(
+                "After printing the current epoch, keep polling and print the \
+                 epoch each time it advances, until interrupted."
+            )))
+        }
+    }
+
     impl CliToSdk<QueryRawBytes<SdkTypes>> for QueryRawBytes<CliTypes> {
         type Error = std::convert::Infallible;
 
@@ -6945,6 +7719,8 @@ pub mod args {
             Ok(QueryRawBytes::<SdkTypes> {
                 query: self.query.to_sdk(ctx)?,
                 storage_key: self.storage_key,
+                prefix: self.prefix,
+                decode_as: self.decode_as,
             })
         }
     }
@@ -6952,13 +7728,29 @@ pub mod args {
     impl Args for QueryRawBytes<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let storage_key = STORAGE_KEY.parse(matches);
+            let prefix = STORAGE_KEY_PREFIX.parse(matches);
+            let decode_as = DECODE_AS.parse(matches);
             let query = Query::parse(matches);
-            Self { storage_key, query }
+            Self {
+                storage_key,
+                prefix,
+                decode_as,
+                query,
+            }
         }
 
         fn def(app: App) -> App {
             app.add_args::<Query<CliTypes>>()
                 .arg(STORAGE_KEY.def().help(wrap!("Storage key")))
+                .arg(STORAGE_KEY_PREFIX.def().help(wrap!(
+                    "Query the raw bytes of all the keys that have the given \
+                     storage key as a prefix."
+                )))
+                .arg(DECODE_AS.def().help(wrap!(
+                    "Decode the raw bytes as the given type, one of amount, \
+                     epoch, address, u64, bool or public-key. Values that \
+                     cannot be decoded are printed as hex."
+                )))
         }
     }
 
@@ -6999,6 +7791,7 @@ pub mod args {
                 dry_run_wrapper: self.dry_run_wrapper,
                 dump_tx: self.dump_tx,
                 output_folder: self.output_folder,
+                dump_tx_path: self.dump_tx_path,
                 force: self.force,
                 broadcast_only: self.broadcast_only,
                 ledger_address: ctx.get(&self.ledger_address),
@@ -7056,9 +7849,22 @@ pub mod args {
                     .conflicts_with(DRY_RUN_TX.name),
             )
             .arg(
-                DUMP_TX
+                DUMP_TX.def().help(wrap!(
+                    "Dump the transaction instead of submitting it. It is \
+                     written to `--dump-tx-path` if given, or else to a file \
+                     named after the tx hash in the `--output-folder-path` \
+                     folder, or printed to stdout when no folder is given."
+                )),
+            )
+            .arg(
+                DUMP_TX_PATH
                     .def()
-                    .help(wrap!("Dump transaction bytes to a file.")),
+                    .help(wrap!(
+                        "Dump the unsigned transaction to this file instead \
+                         of submitting it. Use `-` to write only the \
+                         transaction to stdout."
+                    ))
+                    .alias("export-unsigned"),
             )
             .arg(FORCE.def().help(wrap!(
                 "Submit the transaction even if it doesn't pass client checks."
@@ -7101,7 +7907,9 @@ pub mod args {
                          provided, a default will be set. All of these \
                          examples are \
                          equivalent:\n2012-12-12T12:12:12Z\n2012-12-12 \
-                         12:12:12Z\n2012-  12-12T12:  12:12Z"
+                         12:12:12Z\n2012-  12-12T12:  12:12Z\nAn expiration \
+                         relative to the current time can also be given in \
+                         seconds, minutes or hours, e.g. +30s, +5m or +1h."
                     ))
                     .conflicts_with_all([NO_EXPIRATION.name]),
             )
@@ -7168,7 +7976,8 @@ pub mod args {
         fn parse(matches: &ArgMatches) -> Self {
             let dry_run = DRY_RUN_TX.parse(matches);
             let dry_run_wrapper = DRY_RUN_WRAPPER_TX.parse(matches);
-            let dump_tx = DUMP_TX.parse(matches);
+            let dump_tx_path = DUMP_TX_PATH.parse(matches);
+            let dump_tx = DUMP_TX.parse(matches) || dump_tx_path.is_some();
             let force = FORCE.parse(matches);
             let broadcast_only = BROADCAST_ONLY.parse(matches);
             let ledger_address = CONFIG_RPC_LEDGER_ADDRESS.parse(matches);
@@ -7194,15 +8003,13 @@ pub mod args {
             let expiration = if no_expiration {
                 TxExpiration::NoExpiration
             } else {
-                match expiration {
-                    Some(exp) => TxExpiration::Custom(exp),
-                    None => TxExpiration::Default,
-                }
+                expiration.unwrap_or_default()
             };
             Self {
                 dry_run,
                 dry_run_wrapper,
                 dump_tx,
+                dump_tx_path,
                 force,
                 broadcast_only,
                 ledger_address,
@@ -7317,6 +8124,7 @@ pub mod args {
                 alias: self.alias,
                 alias_force: self.alias_force,
                 viewing_key,
+                diversifier_index: self.diversifier_index,
             })
         }
     }
@@ -7326,10 +8134,12 @@ pub mod args {
             let alias = ALIAS.parse(matches);
             let alias_force = ALIAS_FORCE.parse(matches);
             let viewing_key = VIEWING_KEY.parse(matches);
+            let diversifier_index = DIVERSIFIER_INDEX.parse(matches);
             Self {
                 alias,
                 alias_force,
                 viewing_key,
+                diversifier_index,
             }
         }
 
@@ -7341,6 +8151,13 @@ pub mod args {
                 "Override the alias without confirmation if it already exists."
             )))
             .arg(VIEWING_KEY.def().help(wrap!("The viewing key.")))
+            .arg(DIVERSIFIER_INDEX.def().help(wrap!(
+                "The diversifier index to derive the payment address from. \
+                 The first valid diversifier at or after this index is used, \
+                 such that the same index always yields the same payment \
+                 address, and its index is printed. If none is provided, a \
+                 random diversifier is used."
+            )))
         }
     }
 
@@ -7357,6 +8174,7 @@ pub mod args {
                 HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH.parse(matches);
             let prompt_bip39_passphrase =
                 HD_PROMPT_BIP39_PASSPHRASE.parse(matches);
+            let from_stored_mnemonic = FROM_STORED_MNEMONIC.parse(matches);
             Self {
                 scheme,
                 shielded,
@@ -7367,6 +8185,7 @@ pub mod args {
                 derivation_path,
                 allow_non_compliant,
                 prompt_bip39_passphrase,
+                from_stored_mnemonic,
             }
         }
 
@@ -7422,6 +8241,67 @@ pub mod args {
             .arg(HD_PROMPT_BIP39_PASSPHRASE.def().help(wrap!(
                 "Use an additional passphrase for HD-key generation."
             )))
+            .arg(
+                FROM_STORED_MNEMONIC
+                    .def()
+                    .conflicts_with_all([
+                        HD_DERIVATION_PATH.name,
+                        USE_DEVICE.name,
+                    ])
+                    .help(wrap!(
+                        "Derive the next key from the mnemonic code stored in \
+                         the wallet for the key with the given alias. The new \
+                         key is saved under the alias suffixed with its index."
+                    )),
+            )
+        }
+    }
+
+    impl Args for KeyMnemonicCheck {
+        fn parse(matches: &ArgMatches) -> Self {
+            let scheme = SCHEME.parse(matches);
+            let derivation_path = HD_DERIVATION_PATH.parse(matches);
+            let allow_non_compliant =
+                HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH.parse(matches);
+            let prompt_bip39_passphrase =
+                HD_PROMPT_BIP39_PASSPHRASE.parse(matches);
+            Self {
+                scheme,
+                derivation_path,
+                allow_non_compliant,
+                prompt_bip39_passphrase,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(SCHEME.def().help(wrap!(
+                "The type of key that should be derived. Argument must be \
+                 either ed25519 or secp256k1. If none provided, the default \
+                 key scheme is ed25519."
+            )))
+            .arg(HD_DERIVATION_PATH.def().help(wrap!(
+                "HD key derivation path. Use keyword `default` to refer to a \
+                 scheme default path:\n- m/44'/60'/0'/0/0 for the secp256k1 \
+                 scheme\n- m/44'/877'/0'/0'/0' for the ed25519 scheme\nFor \
+                 ed25519 scheme, all path indices will be promoted to \
+                 hardened indexes. If none is specified, the scheme default \
+                 path is used."
+            )))
+            .arg(HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH.def().help(wrap!(
+                "Allow non-compliant HD derivation path. The compliant \
+                 derivation path schemes include:\n- \
+                 m/44'/60'/account'/change/address_index for the secp256k1 \
+                 scheme\n- m/44'/877'/account'/change'/address_index' for the \
+                 ed25519 scheme"
+            )))
+            .group(
+                ArgGroup::new("requires_group")
+                    .args([HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH.name])
+                    .requires(HD_DERIVATION_PATH.name),
+            )
+            .arg(HD_PROMPT_BIP39_PASSPHRASE.def().help(wrap!(
+                "Use an additional passphrase for HD-key derivation."
+            )))
         }
     }
 
@@ -7438,6 +8318,15 @@ pub mod args {
                 HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH.parse(matches);
             let prompt_bip39_passphrase =
                 HD_PROMPT_BIP39_PASSPHRASE.parse(matches);
+            let mnemonic_type =
+                namada_sdk::wallet::mnemonic_type_for_word_count(
+                    MNEMONIC_WORDS.parse(matches),
+                )
+                .unwrap_or_else(|err| {
+                    eprintln!("{err}");
+                    safe_exit(1)
+                });
+            let store_mnemonic = STORE_MNEMONIC.parse(matches);
             Self {
                 scheme,
                 shielded,
@@ -7448,6 +8337,8 @@ pub mod args {
                 derivation_path,
                 allow_non_compliant,
                 prompt_bip39_passphrase,
+                mnemonic_type,
+                store_mnemonic,
             }
         }
 
@@ -7506,6 +8397,21 @@ pub mod args {
             .arg(HD_PROMPT_BIP39_PASSPHRASE.def().help(wrap!(
                 "Use an additional passphrase for HD-key generation."
             )))
+            .arg(MNEMONIC_WORDS.def().conflicts_with(RAW_KEY_GEN.name).help(
+                wrap!(
+                    "The number of words of the generated mnemonic code. Must \
+                     be one of 12, 15, 18, 21 or 24. Defaults to 24."
+                ),
+            ))
+            .arg(
+                STORE_MNEMONIC.def().conflicts_with(RAW_KEY_GEN.name).help(
+                    wrap!(
+                        "Store the generated mnemonic code in the wallet, \
+                         encrypted like the key, so that further keys can be \
+                         derived from it with `derive --from-stored-mnemonic`."
+                    ),
+                ),
+            )
         }
     }
 
@@ -7517,6 +8423,8 @@ pub mod args {
             let addresses_only = LIST_FIND_ADDRESSES_ONLY.parse(matches);
             let decrypt = DECRYPT.parse(matches);
             let unsafe_show_secret = UNSAFE_SHOW_SECRET.parse(matches);
+            let scheme = SCHEME_OPT.parse(matches);
+            let output_format = OUTPUT_FORMAT.parse(matches);
             Self {
                 transparent_only,
                 shielded_only,
@@ -7524,6 +8432,8 @@ pub mod args {
                 addresses_only,
                 decrypt,
                 unsafe_show_secret,
+                scheme,
+                output_format,
             }
         }
 
@@ -7560,6 +8470,18 @@ pub mod args {
                     .def()
                     .help(wrap!("UNSAFE: Print the secret / spending keys.")),
             )
+            .arg(SCHEME_OPT.def().conflicts_with(SHIELDED.name).help(wrap!(
+                "Only list the transparent keys of the given scheme. Argument \
+                 must be either ed25519 or secp256k1.\nNot applicable for \
+                 the shielded pool."
+            )))
+            .arg(
+                OUTPUT_FORMAT.def().help(wrap!(
+                    "The format of the output, either `text` or `json`. \
+                     Defaults to `text`. The JSON output does not list the \
+                     shielded keys."
+                )),
+            )
         }
     }
 
@@ -7697,6 +8619,32 @@ pub mod args {
         }
     }
 
+    impl Args for KeyAddressRename {
+        fn parse(matches: &ArgMatches) -> Self {
+            let alias = ALIAS.parse(matches);
+            let new_alias = NEW_ALIAS.parse(matches);
+            let alias_force = ALIAS_FORCE.parse(matches);
+            Self {
+                alias,
+                new_alias,
+                alias_force,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(ALIAS.def().help(wrap!("An alias to be renamed.")))
+                .arg(
+                    NEW_ALIAS
+                        .def()
+                        .help(wrap!("The new alias of the keys / addresses.")),
+                )
+                .arg(ALIAS_FORCE.def().help(wrap!(
+                    "Override the new alias without confirmation if it \
+                     already exists."
+                )))
+        }
+    }
+
     impl Args for KeyExport {
         fn parse(matches: &ArgMatches) -> Self {
             let alias = ALIAS.parse(matches);
@@ -7764,6 +8712,7 @@ pub mod args {
         pub dont_prefetch_wasm: bool,
         pub allow_duplicate_ip: bool,
         pub add_persistent_peers: bool,
+        pub from_archive: Option<PathBuf>,
     }
 
     impl Args for JoinNetwork {
@@ -7774,6 +8723,7 @@ pub mod args {
             let dont_prefetch_wasm = DONT_PREFETCH_WASM.parse(matches);
             let allow_duplicate_ip = ALLOW_DUPLICATE_IP.parse(matches);
             let add_persistent_peers = ADD_PERSISTENT_PEERS.parse(matches);
+            let from_archive = FROM_ARCHIVE.parse(matches);
             Self {
                 chain_id,
                 genesis_validator,
@@ -7781,6 +8731,7 @@ pub mod args {
                 dont_prefetch_wasm,
                 allow_duplicate_ip,
                 add_persistent_peers,
+                from_archive,
             }
         }
 
@@ -7800,6 +8751,11 @@ pub mod args {
                 "Whether to add persistent peers to the P2P config of CometBFT, \
                  derived from the list of genesis validators.",
             ))
+            .arg(FROM_ARCHIVE.def().help(wrap!(
+                "Join offline from a local `.tar.gz` release archive of the \
+                 chain instead of downloading it. The wasms are taken from \
+                 the archive too, if it contains them, and are not fetched."
+            )))
         }
     }
 
@@ -7823,15 +8779,84 @@ pub mod args {
     }
 
     #[derive(Clone, Debug)]
-    pub struct DefaultBaseDir {}
+    pub struct DefaultBaseDir {
+        /// Print the base dir in use and its source as JSON
+        pub json: bool,
+        /// The base dir in use
+        pub base_dir: PathBuf,
+        /// What set the base dir in use
+        pub source: BaseDirSource,
+    }
+
+    impl DefaultBaseDir {
+        /// Parse the args, looking up env vars with the given `env_var`
+        /// function.
+        pub fn parse_with_env(
+            matches: &ArgMatches,
+            env_var: impl Fn(&str) -> Option<String>,
+        ) -> Self {
+            let json = JSON.parse(matches);
+            let (base_dir, source) = resolve_base_dir(matches, env_var);
+            Self {
+                json,
+                base_dir,
+                source,
+            }
+        }
+    }
 
     impl Args for DefaultBaseDir {
-        fn parse(_matches: &ArgMatches) -> Self {
-            Self {}
+        fn parse(matches: &ArgMatches) -> Self {
+            Self::parse_with_env(matches, |name| env::var(name).ok())
         }
 
         fn def(app: App) -> App {
-            app
+            app.arg(JSON.def().help(wrap!(
+                "Print the base directory that will actually be used and \
+                 what set it (`arg`, `env-var` or `default`) as JSON."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ExportPreGenesisValidator {
+        pub alias: String,
+        pub pre_genesis_path: Option<PathBuf>,
+        pub output_folder: PathBuf,
+    }
+
+    impl Args for ExportPreGenesisValidator {
+        fn parse(matches: &ArgMatches) -> Self {
+            let alias = ALIAS.parse(matches);
+            let pre_genesis_path = PRE_GENESIS_PATH.parse(matches);
+            let output_folder = OUTPUT_FOLDER_PATH
+                .parse(matches)
+                .expect("The output folder is a required argument");
+            Self {
+                alias,
+                pre_genesis_path,
+                output_folder,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(ALIAS.def().help(wrap!("The validator address alias.")))
+                .arg(PRE_GENESIS_PATH.def().help(wrap!(
+                    "The pre-genesis directory of the validator, whose \
+                     wallet holds the Ethereum cold key. The cold key is \
+                     never added to the wallet of a chain, so it is taken \
+                     from there. Defaults to \
+                     \"{base-dir}/pre-genesis/{alias}\"."
+                )))
+                .arg(
+                    OUTPUT_FOLDER_PATH
+                        .def()
+                        .help(wrap!(
+                            "The folder to write the pre-genesis validator \
+                             wallet to."
+                        ))
+                        .required(true),
+                )
         }
     }
 
@@ -7855,6 +8880,41 @@ pub mod args {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct VerifyWasm {
+        pub wasm_checksums_path: PathBuf,
+    }
+
+    impl Args for VerifyWasm {
+        fn parse(matches: &ArgMatches) -> Self {
+            let wasm_checksums_path = WASM_CHECKSUMS_PATH.parse(matches);
+            Self {
+                wasm_checksums_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(WASM_CHECKSUMS_PATH.def().help(wrap!(
+                "Path to the WASM checksums file. The wasm files are looked \
+                 up in the directory given with `--wasm-dir`, or in the \
+                 directory of the checksums file by default."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ListWasms {}
+
+    impl Args for ListWasms {
+        fn parse(_matches: &ArgMatches) -> Self {
+            Self {}
+        }
+
+        fn def(app: App) -> App {
+            app
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct InitNetwork {
         pub templates_path: PathBuf,
@@ -7863,6 +8923,7 @@ pub mod args {
         pub genesis_time: DateTimeUtc,
         pub consensus_timeout_commit: Timeout,
         pub archive_dir: Option<PathBuf>,
+        pub deterministic: bool,
     }
 
     impl Args for InitNetwork {
@@ -7874,6 +8935,7 @@ pub mod args {
             let consensus_timeout_commit =
                 CONSENSUS_TIMEOUT_COMMIT.parse(matches);
             let archive_dir = ARCHIVE_DIR.parse(matches);
+            let deterministic = DETERMINISTIC.parse(matches);
             Self {
                 templates_path,
                 wasm_checksums_path,
@@ -7881,6 +8943,7 @@ pub mod args {
                 genesis_time,
                 consensus_timeout_commit,
                 archive_dir,
+                deterministic,
             }
         }
 
@@ -7910,6 +8973,13 @@ pub mod args {
                 "Specify a directory into which to store the archive. Default \
                  is the current working directory."
             )))
+            .arg(DETERMINISTIC.def().help(wrap!(
+                "Produce a reproducible release archive. Archive entries are \
+                 sorted and their file metadata (modification times, owners) \
+                 is normalized, such that running the command twice with the \
+                 same inputs yields byte-identical output. Intended for test \
+                 networks only, NOT for production networks."
+            )))
         }
     }
 
@@ -8038,6 +9108,7 @@ pub mod args {
         pub name: Option<String>,
         pub address: EstablishedAddress,
         pub tx_path: PathBuf,
+        pub seed: Option<String>,
     }
 
     impl Args for InitGenesisValidator {
@@ -8059,6 +9130,7 @@ pub mod args {
             let name = VALIDATOR_NAME_OPT.parse(matches);
             let address = RAW_ADDRESS_ESTABLISHED.parse(matches);
             let tx_path = PATH.parse(matches);
+            let seed = SEED.parse(matches);
             Self {
                 alias,
                 net_address,
@@ -8075,6 +9147,7 @@ pub mod args {
                 name,
                 tx_path,
                 address,
+                seed,
             }
         }
 
@@ -8135,6 +9208,17 @@ pub mod args {
                     "The validator's name, used to identify the validator in \
                      online services. This is an optional parameter."
                 )))
+                .arg(
+                    SEED.def()
+                        .help(wrap!(
+                            "UNSAFE: A hex-encoded seed to deterministically \
+                             derive the validator keys from, for reproducible \
+                             test networks. Requires \
+                             `--unsafe-dont-encrypt`. Do not use this for keys \
+                             used in a live network."
+                        ))
+                        .requires(UNSAFE_DONT_ENCRYPT.name),
+                )
         }
     }
 
@@ -8282,10 +9366,41 @@ pub fn namada_client_cli() -> Result<NamadaClient> {
     let matches = app.clone().get_matches();
     match Cmd::parse(&matches) {
         Some(cmd) => {
+            let access = match &cmd {
+                cmds::NamadaClient::WithContext(sub_cmd)
+                    if sub_cmd.is_query() =>
+                {
+                    Some(BaseDirAccess::Read)
+                }
+                cmds::NamadaClient::WithContext(_) => {
+                    Some(BaseDirAccess::Write)
+                }
+                cmds::NamadaClient::WithoutContext(sub_cmd)
+                    if sub_cmd.writes_base_dir() =>
+                {
+                    Some(BaseDirAccess::Write)
+                }
+                // These read the pre-genesis wallet from the base dir
+                cmds::NamadaClient::WithoutContext(sub_cmd)
+                    if sub_cmd.reads_base_dir() =>
+                {
+                    Some(BaseDirAccess::Read)
+                }
+                // The other utils don't depend on the base dir
+                cmds::NamadaClient::WithoutContext(_) => None,
+            };
+            if let Some(access) = access {
+                check_base_dir_or_exit(&matches, access);
+            }
             let global_args = args::Global::parse(&matches);
             match cmd {
                 cmds::NamadaClient::WithContext(sub_cmd) => {
-                    let context = Context::new::<CliIo>(global_args)?;
+                    // Queries don't need to generate any missing files
+                    let context = if sub_cmd.is_query() {
+                        Context::try_new::<CliIo>(global_args)?
+                    } else {
+                        Context::new::<CliIo>(global_args)?
+                    };
                     Ok(NamadaClient::WithContext(Box::new((sub_cmd, context))))
                 }
                 cmds::NamadaClient::WithoutContext(sub_cmd) => {
@@ -8388,3 +9503,609 @@ pub fn namada_relayer_app() -> App {
         .subcommand_required(true);
     cmds::NamadaRelayer::add_sub(args::Global::def(app))
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use assert_matches::assert_matches;
+    use namada_sdk::key::SchemeType;
+    use namada_sdk::masp::ExtendedViewingKey;
+    use namada_sdk::storage::{BlockHeight, Epoch};
+    use namada_sdk::time::DateTimeUtc;
+    use namada_sdk::token;
+
+    use super::*;
+    use crate::config::Action;
+
+    #[test]
+    fn test_parse_query_eth_bridge_pool() {
+        let matches = namada_client_app()
+            .try_get_matches_from([
+                "namadac",
+                "query-eth-bridge-pool",
+                "--node",
+                "http://127.0.0.1:26657",
+            ])
+            .expect("Arguments should be valid");
+        let cmd = <cmds::NamadaClient as Cmd>::parse(&matches)
+            .expect("Command should be recognized");
+        assert_matches!(
+            cmd,
+            cmds::NamadaClient::WithContext(
+                cmds::NamadaClientWithContext::QueryEthBridgePool(
+                    cmds::QueryEthBridgePoolTransfers(_)
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_query_balance_multiple_tokens() {
+        let matches = namada_client_app()
+            .try_get_matches_from([
+                "namadac",
+                "balance",
+                "--owner",
+                "albert",
+                "--token",
+                "nam",
+                "--token",
+                "btc",
+                "--node",
+                "http://127.0.0.1:26657",
+            ])
+            .expect("Arguments should be valid");
+        let cmd = <cmds::NamadaClient as Cmd>::parse(&matches)
+            .expect("Command should be recognized");
+        let cmds::NamadaClient::WithContext(
+            cmds::NamadaClientWithContext::QueryBalance(cmds::QueryBalance(
+                args,
+            )),
+        ) = cmd
+        else {
+            panic!("Expected a balance query, got {cmd:?}");
+        };
+        let tokens: Vec<_> =
+            args.tokens.iter().map(|token| token.raw.as_str()).collect();
+        assert_eq!(tokens, ["nam", "btc"]);
+    }
+
+    #[test]
+    fn test_parse_query_balance_viewing_key_file() {
+        let spend_key =
+            masp_primitives::zip32::ExtendedSpendingKey::master(&[0; 32]);
+        let viewing_key = ExtendedViewingKey::from(
+            masp_primitives::zip32::ExtendedFullViewingKey::from(&spend_key),
+        );
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("viewing-key");
+        std::fs::write(&path, format!("{viewing_key}\n")).unwrap();
+        let path = path.to_str().unwrap();
+
+        let matches = namada_client_app()
+            .try_get_matches_from([
+                "namadac",
+                "balance",
+                "--viewing-key-file",
+                path,
+                "--node",
+                "http://127.0.0.1:26657",
+            ])
+            .expect("Arguments should be valid");
+        let cmd = <cmds::NamadaClient as Cmd>::parse(&matches)
+            .expect("Command should be recognized");
+        let cmds::NamadaClient::WithContext(
+            cmds::NamadaClientWithContext::QueryBalance(cmds::QueryBalance(
+                args,
+            )),
+        ) = cmd
+        else {
+            panic!("Expected a balance query, got {cmd:?}");
+        };
+        assert_eq!(args.owner.raw, viewing_key.to_string());
+
+        // The owner is given either directly or through the file
+        for args in [
+            vec!["--owner", "albert", "--viewing-key-file", path],
+            vec![],
+        ] {
+            let result = namada_client_app().try_get_matches_from(
+                ["namadac", "balance", "--node", "http://127.0.0.1:26657"]
+                    .into_iter()
+                    .chain(args),
+            );
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_query_total_supply() {
+        let matches = namada_client_app()
+            .try_get_matches_from([
+                "namadac",
+                "query-total-supply",
+                "--token",
+                "nam",
+                "--node",
+                "http://127.0.0.1:26657",
+            ])
+            .expect("Arguments should be valid");
+        let cmd = <cmds::NamadaClient as Cmd>::parse(&matches)
+            .expect("Command should be recognized");
+        let cmds::NamadaClient::WithContext(
+            cmds::NamadaClientWithContext::QueryTotalSupply(
+                cmds::QueryTotalSupply(args),
+            ),
+        ) = cmd
+        else {
+            panic!("Expected a total supply query, got {cmd:?}");
+        };
+        assert_eq!(args.token.raw, "nam");
+
+        // The token is required
+        assert!(
+            namada_client_app()
+                .try_get_matches_from(["namadac", "query-total-supply"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_query_validator_state() {
+        for cmd_name in ["validator-state", "query-validator-state"] {
+            let matches = namada_client_app()
+                .try_get_matches_from([
+                    "namadac",
+                    cmd_name,
+                    "--validator",
+                    "validator-0",
+                    "--epoch",
+                    "12",
+                ])
+                .expect("Arguments should be valid");
+            let cmd = <cmds::NamadaClient as Cmd>::parse(&matches)
+                .expect("Command should be recognized");
+            let cmds::NamadaClient::WithContext(
+                cmds::NamadaClientWithContext::QueryValidatorState(
+                    cmds::QueryValidatorState(args),
+                ),
+            ) = cmd
+            else {
+                panic!("Expected a validator state query, got {cmd:?}");
+            };
+            assert_eq!(args.validator.raw, "validator-0");
+            assert_eq!(args.epoch, Some(Epoch(12)));
+        }
+    }
+
+    #[test]
+    fn test_parse_shielded_sync_scan_jobs() {
+        let parse_scan_jobs = |extra_args: &[&str]| {
+            let matches = namada_client_app()
+                .try_get_matches_from(
+                    ["namadac", "shielded-sync"]
+                        .iter()
+                        .chain(extra_args)
+                        .chain(&["--node", "http://127.0.0.1:26657"]),
+                )
+                .expect("Arguments should be valid");
+            let cmd = <cmds::NamadaClient as Cmd>::parse(&matches)
+                .expect("Command should be recognized");
+            let cmds::NamadaClient::WithContext(
+                cmds::NamadaClientWithContext::ShieldedSync(
+                    cmds::ShieldedSync(args),
+                ),
+            ) = cmd
+            else {
+                panic!("Expected a shielded sync, got {cmd:?}");
+            };
+            args.scan_jobs.get()
+        };
+        assert_eq!(parse_scan_jobs(&[]), 1);
+        assert_eq!(parse_scan_jobs(&["--scan-jobs", "4"]), 4);
+    }
+
+    #[test]
+    fn test_parse_query_rewards() {
+        for cmd_name in ["rewards", "query-rewards"] {
+            let matches = namada_client_app()
+                .try_get_matches_from([
+                    "namadac",
+                    cmd_name,
+                    "--validator",
+                    "validator-0",
+                    "--source",
+                    "albert",
+                ])
+                .expect("Arguments should be valid");
+            let cmd = <cmds::NamadaClient as Cmd>::parse(&matches)
+                .expect("Command should be recognized");
+            let cmds::NamadaClient::WithContext(
+                cmds::NamadaClientWithContext::QueryRewards(
+                    cmds::QueryRewards(args),
+                ),
+            ) = cmd
+            else {
+                panic!("Expected a rewards query, got {cmd:?}");
+            };
+            assert_eq!(args.validator.raw, "validator-0");
+            assert_eq!(
+                args.source.map(|source| source.raw).as_deref(),
+                Some("albert")
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_transparent_transfer_to_many_targets() {
+        let matches = namada_client_app()
+            .try_get_matches_from([
+                "namadac",
+                "transparent-transfer",
+                "--source",
+                "albert",
+                "--token",
+                "nam",
+                "--target",
+                "bertha",
+                "--amount",
+                "1",
+                "--target",
+                "christel,daewon",
+                "--amount",
+                "2.5,3",
+            ])
+            .expect("Arguments should be valid");
+        let cmd = <cmds::NamadaClient as Cmd>::parse(&matches)
+            .expect("Command should be recognized");
+        let cmds::NamadaClient::WithContext(
+            cmds::NamadaClientWithContext::TxTransparentTransfer(
+                cmds::TxTransparentTransfer(args),
+            ),
+        ) = cmd
+        else {
+            panic!("Expected a transparent transfer, got {cmd:?}");
+        };
+        let transfers: Vec<_> = args
+            .data
+            .iter()
+            .map(|data| {
+                assert_eq!(data.source.raw, "albert");
+                assert_eq!(data.token.raw, "nam");
+                let args::InputAmount::Unvalidated(amount) = &data.amount
+                else {
+                    panic!("Expected an unvalidated amount");
+                };
+                (data.target.raw.as_str(), amount.to_string())
+            })
+            .collect();
+        assert_eq!(
+            transfers,
+            [
+                ("bertha", "1".to_string()),
+                ("christel", "2.5".to_string()),
+                ("daewon", "3".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_reveal_many_pks() {
+        let parse_public_keys = |public_keys: &[&str]| {
+            let mut cli_args = vec!["namadac", "reveal-pk"];
+            for public_key in public_keys {
+                cli_args.extend(["--public-key", *public_key]);
+            }
+            let matches = namada_client_app()
+                .try_get_matches_from(cli_args)
+                .expect("Arguments should be valid");
+            let cmd = <cmds::NamadaClient as Cmd>::parse(&matches)
+                .expect("Command should be recognized");
+            let cmds::NamadaClient::WithContext(
+                cmds::NamadaClientWithContext::TxRevealPk(cmds::TxRevealPk(
+                    args,
+                )),
+            ) = cmd
+            else {
+                panic!("Expected a reveal-pk tx, got {cmd:?}");
+            };
+            args.public_keys
+                .into_iter()
+                .map(|public_key| public_key.raw)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(parse_public_keys(&["albert-key"]), ["albert-key"]);
+        assert_eq!(
+            parse_public_keys(&["albert-key", "bertha-key,christel-key"]),
+            ["albert-key", "bertha-key", "christel-key"]
+        );
+
+        // At least one key is required
+        assert!(
+            namada_client_app()
+                .try_get_matches_from(["namadac", "reveal-pk"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    #[allow(clippy::disallowed_methods)]
+    fn test_parse_tx_expiration() {
+        let parse_expiration = |expiration: &str| {
+            let matches = namada_client_app()
+                .try_get_matches_from([
+                    "namadac",
+                    "transparent-transfer",
+                    "--source",
+                    "albert",
+                    "--token",
+                    "nam",
+                    "--target",
+                    "bertha",
+                    "--amount",
+                    "1",
+                    "--expiration",
+                    expiration,
+                ])
+                .expect("Arguments should be valid");
+            let cmd = <cmds::NamadaClient as Cmd>::parse(&matches)
+                .expect("Command should be recognized");
+            let cmds::NamadaClient::WithContext(
+                cmds::NamadaClientWithContext::TxTransparentTransfer(
+                    cmds::TxTransparentTransfer(args),
+                ),
+            ) = cmd
+            else {
+                panic!("Expected a transparent transfer, got {cmd:?}");
+            };
+            let args::TxExpiration::Custom(expiration) = args.tx.expiration
+            else {
+                panic!("Expected a custom expiration");
+            };
+            expiration
+        };
+
+        for (relative, secs) in [("+30s", 30), ("+5m", 5 * 60)] {
+            let before = DateTimeUtc::now();
+            let expiration = parse_expiration(relative);
+            let after = DateTimeUtc::now();
+            let duration = namada_sdk::time::Duration::seconds(secs);
+            assert!(before + duration <= expiration);
+            assert!(expiration <= after + duration);
+        }
+
+        assert_eq!(
+            parse_expiration("2012-12-12T12:12:12Z"),
+            DateTimeUtc::from_str("2012-12-12T12:12:12Z").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_ibc_transfer_memos() {
+        let matches = namada_client_app()
+            .try_get_matches_from([
+                "namadac",
+                "ibc-transfer",
+                "--source",
+                "albert",
+                "--receiver",
+                "cosmos1receiver",
+                "--token",
+                "nam",
+                "--amount",
+                "10",
+                "--channel-id",
+                "channel-0",
+                "--memo",
+                "tx note",
+                "--ibc-memo",
+                "deposit-id:1234",
+            ])
+            .expect("Arguments should be valid");
+        let cmd = <cmds::NamadaClient as Cmd>::parse(&matches)
+            .expect("Command should be recognized");
+        let cmds::NamadaClient::WithContext(
+            cmds::NamadaClientWithContext::TxIbcTransfer(cmds::TxIbcTransfer(
+                args,
+            )),
+        ) = cmd
+        else {
+            panic!("Expected an IBC transfer, got {cmd:?}");
+        };
+        assert_eq!(args.tx.memo, Some(b"tx note".to_vec()));
+        assert_eq!(args.ibc_memo.as_deref(), Some("deposit-id:1234"));
+    }
+
+    #[test]
+    fn test_pair_targets_with_amounts() {
+        let targets = |raw: &[&str]| -> Vec<_> {
+            raw.iter()
+                .map(|target| context::WalletAddress::new(target.to_string()))
+                .collect()
+        };
+        let amounts = |raw: &[&str]| -> Vec<_> {
+            raw.iter()
+                .map(|amount| amount.parse::<token::DenominatedAmount>())
+                .collect::<Result<_, _>>()
+                .unwrap()
+        };
+
+        let pairs = args::pair_targets_with_amounts(
+            targets(&["bertha", "christel", "daewon"]),
+            amounts(&["1", "2", "3"]),
+        )
+        .unwrap();
+        assert_eq!(pairs.len(), 3);
+
+        let err = args::pair_targets_with_amounts(
+            targets(&["bertha", "christel"]),
+            amounts(&["1", "2", "3"]),
+        )
+        .unwrap_err();
+        assert!(err.starts_with("Got 2 target(s) but 3 amount(s)"));
+        assert!(
+            args::pair_targets_with_amounts(
+                targets(&["bertha", "christel", "daewon"]),
+                amounts(&["1"]),
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_ledger_run_until() {
+        let parse_run_until = |extra_args: &[&str]| {
+            let matches = namada_node_app()
+                .try_get_matches_from(
+                    ["namadan", "ledger", "run-until"]
+                        .iter()
+                        .chain(extra_args)
+                        .copied(),
+                )
+                .expect("Arguments should be valid");
+            let cmd = <cmds::NamadaNode as Cmd>::parse(&matches)
+                .expect("Command should be recognized");
+            let cmds::NamadaNode::Ledger(cmds::Ledger::RunUntil(
+                cmds::LedgerRunUntil(args),
+            )) = cmd
+            else {
+                panic!("Expected a run-until command, got {cmd:?}");
+            };
+            args.action_at_height
+        };
+
+        // Height only
+        let action = parse_run_until(&["--block-height", "2", "--halt"]);
+        assert_eq!(action.height, Some(BlockHeight(2)));
+        assert_eq!(action.time, None);
+        assert_matches!(action.action, Action::Halt);
+
+        // Time only
+        let action = parse_run_until(&[
+            "--until-time",
+            "2023-01-20T12:12:12Z",
+            "--suspend",
+        ]);
+        assert_eq!(action.height, None);
+        assert_eq!(action.time, Some("2023-01-20T12:12:12Z".parse().unwrap()));
+        assert_matches!(action.action, Action::Suspend);
+
+        // Both
+        let action = parse_run_until(&[
+            "--block-height",
+            "2",
+            "--until-time",
+            "2023-01-20T12:12:12Z",
+            "--halt",
+        ]);
+        assert_eq!(action.height, Some(BlockHeight(2)));
+        assert!(action.time.is_some());
+
+        // Either the height or the time is required
+        assert!(
+            namada_node_app()
+                .try_get_matches_from([
+                    "namadan",
+                    "ledger",
+                    "run-until",
+                    "--halt"
+                ])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_wallet_list_scheme() {
+        let parse_list = |args: &[&str]| {
+            let matches = namada_wallet_app()
+                .try_get_matches_from(
+                    ["namadaw", "list"].iter().chain(args).copied(),
+                )
+                .expect("Arguments should be valid");
+            let cmd = <cmds::NamadaWallet as Cmd>::parse(&matches)
+                .expect("Command should be recognized");
+            let cmds::NamadaWallet::KeyAddrList(
+                cmds::WalletListKeysAddresses(args),
+            ) = cmd
+            else {
+                panic!("Expected a wallet list command, got {cmd:?}");
+            };
+            args
+        };
+
+        assert_eq!(parse_list(&[]).scheme, None);
+        assert_eq!(
+            parse_list(&["--scheme", "secp256k1"]).scheme,
+            Some(SchemeType::Secp256k1)
+        );
+
+        // The scheme only applies to transparent keys
+        assert!(
+            namada_wallet_app()
+                .try_get_matches_from([
+                    "namadaw",
+                    "list",
+                    "--shielded",
+                    "--scheme",
+                    "ed25519"
+                ])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_default_base_dir_json() {
+        // The env var is injected rather than set, as tests run in parallel
+        let base_dir_json = |cli_args: &[&str], env_base_dir: Option<&str>| {
+            let matches = namada_client_app()
+                .try_get_matches_from(
+                    ["namadac"].iter().chain(cli_args).copied(),
+                )
+                .expect("Arguments should be valid");
+            let matches = matches
+                .subcommand_matches(cmds::ClientUtils::CMD)
+                .and_then(|matches| {
+                    matches.subcommand_matches(cmds::DefaultBaseDir::CMD)
+                })
+                .expect("Expected the default-base-dir command");
+            let args = args::DefaultBaseDir::parse_with_env(matches, |name| {
+                assert_eq!(name, context::ENV_VAR_BASE_DIR);
+                env_base_dir.map(String::from)
+            });
+            assert!(args.json);
+            crate::client::utils::base_dir_json(&args)
+        };
+        let cli_args = ["utils", "default-base-dir", "--json"];
+
+        // Set with the argument, which takes precedence over the env var
+        let json = base_dir_json(
+            &[
+                "--base-dir",
+                "/tmp/from-arg",
+                "utils",
+                "default-base-dir",
+                "--json",
+            ],
+            Some("/tmp/from-env"),
+        );
+        assert_eq!(json["path"], "/tmp/from-arg");
+        assert_eq!(json["source"], "arg");
+
+        // Set with the env var
+        let json = base_dir_json(&cli_args, Some("/tmp/from-env"));
+        assert_eq!(json["path"], "/tmp/from-env");
+        assert_eq!(json["source"], "env-var");
+
+        // Platform default
+        let json = base_dir_json(&cli_args, None);
+        assert_eq!(
+            json["path"],
+            crate::config::get_default_namada_folder()
+                .to_string_lossy()
+                .into_owned()
+        );
+        assert_eq!(json["source"], "default");
+    }
+}
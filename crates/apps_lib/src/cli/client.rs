@@ -370,6 +370,7 @@ impl CliApi {
                             args.last_query_height,
                             &sks,
                             &vks,
+                            args.scan_jobs,
                         )
                         .await?;
                     }
@@ -475,13 +476,17 @@ impl CliApi {
                     Sub::QueryEpoch(QueryEpoch(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
-                            chain_ctx.get(&args.ledger_address);
+                            chain_ctx.get(&args.query.ledger_address);
                         let client = client.unwrap_or_else(|| {
                             C::from_tendermint_address(&ledger_address)
                         });
                         client.wait_until_node_is_synced(&io).await?;
                         let namada = ctx.to_sdk(client, io);
-                        rpc::query_and_print_epoch(&namada).await;
+                        if args.watch {
+                            rpc::query_and_watch_epoch(&namada).await;
+                        } else {
+                            rpc::query_and_print_epoch(&namada).await;
+                        }
                     }
                     Sub::QueryNextEpochInfo(QueryNextEpochInfo(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
@@ -563,6 +568,18 @@ impl CliApi {
                         let namada = ctx.to_sdk(client, io);
                         rpc::query_balance(&namada, args).await;
                     }
+                    Sub::QueryTotalSupply(QueryTotalSupply(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.query.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        rpc::query_total_supply(&namada, args).await?;
+                    }
                     Sub::QueryBonds(QueryBonds(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
@@ -748,6 +765,20 @@ impl CliApi {
                         let namada = ctx.to_sdk(client, io);
                         rpc::query_pgf(&namada, args).await;
                     }
+                    Sub::QueryEthBridgePool(QueryEthBridgePoolTransfers(
+                        args,
+                    )) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.query.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        rpc::query_eth_bridge_pool(&namada, args).await;
+                    }
                     Sub::QueryAccount(QueryAccount(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
@@ -764,14 +795,27 @@ impl CliApi {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
                             chain_ctx.get(&args.tx.ledger_address);
+                        // The node is only queried for the account's public
+                        // keys when they aren't given, so don't wait for it
                         let client = client.unwrap_or_else(|| {
                             C::from_tendermint_address(&ledger_address)
                         });
-                        client.wait_until_node_is_synced(&io).await?;
                         let args = args.to_sdk(&mut ctx)?;
                         let namada = ctx.to_sdk(client, io);
                         tx::sign_tx(&namada, args).await?;
                     }
+                    Sub::BroadcastTx(BroadcastTx(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.tx.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        tx::submit_signed_tx(&namada, args).await?;
+                    }
                 }
             }
             cli::NamadaClient::WithoutContext(cmd, global_args) => match cmd {
@@ -782,6 +826,14 @@ impl CliApi {
                 ClientUtils::ValidateWasm(ValidateWasm(args)) => {
                     utils::validate_wasm(args)
                 }
+                ClientUtils::VerifyWasm(VerifyWasm(args)) => {
+                    utils::verify_wasm(global_args, args)
+                }
+                ClientUtils::ListWasms(ListWasms(args)) => {
+                    let ctx = cli::Context::new::<IO>(global_args)
+                        .expect("expected to construct a context");
+                    utils::list_wasms(&ctx, args)
+                }
                 ClientUtils::InitNetwork(InitNetwork(args)) => {
                     utils::init_network(global_args, args);
                 }
@@ -797,6 +849,13 @@ impl CliApi {
                 ClientUtils::InitGenesisValidator(InitGenesisValidator(
                     args,
                 )) => utils::init_genesis_validator(global_args, args),
+                ClientUtils::ExportPreGenesisValidator(
+                    ExportPreGenesisValidator(args),
+                ) => {
+                    let ctx = cli::Context::new::<IO>(global_args)
+                        .expect("expected to construct a context");
+                    utils::export_pre_genesis_validator(&ctx, args)
+                }
                 ClientUtils::PkToTmAddress(PkToTmAddress(args)) => {
                     utils::pk_to_tm_address(global_args, args)
                 }
@@ -1,14 +1,15 @@
 //! CLI input types can be used for command arguments
 
-use std::env;
 use std::marker::PhantomData;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::{env, fmt, fs};
 
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{eyre, Result};
 use namada_sdk::address::{Address, InternalAddress};
 use namada_sdk::chain::ChainId;
 use namada_sdk::ethereum_events::EthAddress;
+use namada_sdk::hash::Hash;
 use namada_sdk::ibc::trace::{ibc_token, is_ibc_denom, is_nft_trace};
 use namada_sdk::io::Io;
 use namada_sdk::key::*;
@@ -16,6 +17,7 @@ use namada_sdk::masp::fs::FsShieldedUtils;
 use namada_sdk::masp::{ShieldedContext, *};
 use namada_sdk::wallet::Wallet;
 use namada_sdk::{Namada, NamadaImpl};
+use serde::Serialize;
 
 use super::args;
 use crate::cli::utils;
@@ -33,6 +35,9 @@ pub const ENV_VAR_WASM_DIR: &str = "NAMADA_WASM_DIR";
 /// Env. var to read the Namada chain id from
 pub const ENV_VAR_CHAIN_ID: &str = "NAMADA_CHAIN_ID";
 
+/// Env. var to set the base directory
+pub const ENV_VAR_BASE_DIR: &str = "NAMADA_BASE_DIR";
+
 /// A raw address (bech32m encoding) or an alias of an address that may be found
 /// in the wallet
 pub type WalletAddress = FromContext<Address>;
@@ -145,19 +150,25 @@ pub fn wasm_dir_from_env_or<P: AsRef<Path>>(
         })
 }
 
+/// Find the chain ID to use from the env var, the global arguments or the
+/// default chain ID of the global config, in this order.
+fn chain_id_from_env_or(
+    global_args: &args::Global,
+    global_config: &GlobalConfig,
+) -> Option<ChainId> {
+    std::env::var(ENV_VAR_CHAIN_ID)
+        .ok()
+        .and_then(|chain_id| ChainId::from_str(&chain_id).ok())
+        .or_else(|| global_args.chain_id.clone())
+        .or_else(|| global_config.default_chain_id.clone())
+}
+
 impl Context {
     pub fn new<IO: Io>(global_args: args::Global) -> Result<Self> {
         let global_config = read_or_try_new_global_config(&global_args);
+        let chain_id = chain_id_from_env_or(&global_args, &global_config);
 
-        let env_var_chain_id = std::env::var(ENV_VAR_CHAIN_ID)
-            .ok()
-            .and_then(|chain_id| ChainId::from_str(&chain_id).ok());
-        let chain_id = env_var_chain_id
-            .as_ref()
-            .or(global_args.chain_id.as_ref())
-            .or(global_config.default_chain_id.as_ref());
-
-        let chain = match chain_id {
+        let chain = match chain_id.as_ref() {
             Some(chain_id) if !global_args.is_pre_genesis => {
                 let mut config =
                     Config::load(&global_args.base_dir, chain_id, None);
@@ -198,6 +209,62 @@ impl Context {
         })
     }
 
+    /// Like [`Context::new`], but fails instead of generating a missing chain
+    /// config file or panicking on missing genesis files or wallet. Nothing
+    /// is written to the base directory.
+    pub fn try_new<IO: Io>(global_args: args::Global) -> Result<Self> {
+        let global_config = GlobalConfig::read(&global_args.base_dir)
+            .map_err(|err| eyre!("Error reading global config: {err}"))?;
+        if global_args.is_pre_genesis {
+            return Ok(Self {
+                global_args,
+                global_config,
+                chain: None,
+            });
+        }
+        let chain_id = chain_id_from_env_or(&global_args, &global_config)
+            .ok_or_else(|| {
+                eyre!(
+                    "No chain is configured in {}.",
+                    global_args.base_dir.to_string_lossy()
+                )
+            })?;
+
+        let base_dir = &global_args.base_dir;
+        let config_path = Config::file_path(base_dir, &chain_id);
+        if !config_path.exists() {
+            return Err(eyre!(
+                "Could not find the config of chain {chain_id} at {}.",
+                config_path.to_string_lossy()
+            ));
+        }
+        let mut config = Config::read(base_dir, &chain_id, None)?;
+        config.ledger.shell.base_dir.clone_from(base_dir);
+        let chain_dir = base_dir.join(chain_id.as_str());
+        let genesis = genesis::chain::Finalized::read_toml_files(&chain_dir)
+            .map_err(|err| eyre!("Missing genesis files: {err}"))?;
+        let native_token = genesis.get_native_token().clone();
+        let wallet = wallet::load(&chain_dir).ok_or_else(|| {
+            eyre!("Could not find wallet at {}.", chain_dir.to_string_lossy())
+        })?;
+
+        // Put WASM dir path in the config
+        if let Some(wasm_dir) = wasm_dir_from_env_or_args(&global_args) {
+            config.wasm_dir = wasm_dir;
+        }
+
+        Ok(Self {
+            global_args,
+            global_config,
+            chain: Some(ChainContext {
+                wallet,
+                config,
+                shielded: FsShieldedUtils::new(chain_dir),
+                native_token,
+            }),
+        })
+    }
+
     /// Try to take the chain context, or exit the process with an error if no
     /// chain is configured.
     pub fn take_chain_or_exit(self) -> ChainContext {
@@ -303,6 +370,11 @@ impl ChainContext {
     pub fn read_wasm(&self, file_name: impl AsRef<Path>) -> Vec<u8> {
         wasm_loader::read_wasm_or_exit(self.wasm_dir(), file_name)
     }
+
+    /// List the WASM files in the WASM directory with their hashes.
+    pub fn list_wasms(&self) -> Vec<(String, Hash)> {
+        wasm_loader::list_wasms_or_exit(self.wasm_dir())
+    }
 }
 
 /// Load global config from expected path in the `base_dir` or try to generate a
@@ -316,6 +388,92 @@ pub fn read_or_try_new_global_config(
     })
 }
 
+/// What set the base directory
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BaseDirSource {
+    /// The `--base-dir` argument
+    Arg,
+    /// The [`ENV_VAR_BASE_DIR`] env var
+    EnvVar,
+    /// The platform default
+    Default,
+}
+
+impl fmt::Display for BaseDirSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Arg => write!(f, "the `--base-dir` argument"),
+            Self::EnvVar => write!(f, "the `{ENV_VAR_BASE_DIR}` env var"),
+            Self::Default => write!(f, "the default location"),
+        }
+    }
+}
+
+/// How a command is going to use the base directory
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BaseDirAccess {
+    /// The base dir must exist and be readable
+    Read,
+    /// The base dir must be readable and writable, if it exists. Otherwise
+    /// it gets created on demand.
+    Write,
+}
+
+/// Check that the base directory can be used with the given access before
+/// loading anything from it. The `source` is included in the errors.
+pub fn check_base_dir(
+    base_dir: &Path,
+    source: BaseDirSource,
+    access: BaseDirAccess,
+) -> Result<()> {
+    let path = base_dir.to_string_lossy();
+    let metadata = match fs::metadata(base_dir) {
+        Ok(metadata) => metadata,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return match access {
+                BaseDirAccess::Read => Err(eyre!(
+                    "The base directory {path} set by {source} does not \
+                     exist. Point `--base-dir` or `{ENV_VAR_BASE_DIR}` to an \
+                     existing base directory or run `namada client utils \
+                     join-network` to create it."
+                )),
+                BaseDirAccess::Write => Ok(()),
+            };
+        }
+        Err(err) => {
+            return Err(eyre!(
+                "Could not access the base directory {path} set by {source}: \
+                 {err}. Check the permissions of its parent directories."
+            ));
+        }
+    };
+    if !metadata.is_dir() {
+        return Err(eyre!(
+            "The base directory {path} set by {source} is not a directory."
+        ));
+    }
+    if let Err(err) = fs::read_dir(base_dir) {
+        return Err(eyre!(
+            "The base directory {path} set by {source} is not readable: \
+             {err}. Check its permissions, e.g. with `ls -ld {path}`."
+        ));
+    }
+    // The mode bits alone don't tell whether we can write to the dir (e.g.
+    // ACLs, read-only mounts or a privileged user), so try to create a file
+    if access == BaseDirAccess::Write {
+        if let Err(err) =
+            tempfile::NamedTempFile::new_in(base_dir).and_then(|f| f.close())
+        {
+            return Err(eyre!(
+                "The base directory {path} set by {source} is not writable: \
+                 {err}. Make it writable, e.g. with `chmod u+w {path}`."
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Argument that can be given raw or found in the [`Context`].
 #[derive(Debug, Clone)]
 pub struct FromContext<T> {
@@ -662,3 +820,92 @@ impl ArgFromMutContext for BalanceOwner {
             })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use namada_sdk::io::StdIo;
+
+    use super::*;
+
+    #[test]
+    fn test_check_nonexistent_base_dir() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base_dir = tmp.path().join("namada");
+
+        let err =
+            check_base_dir(&base_dir, BaseDirSource::Arg, BaseDirAccess::Read)
+                .unwrap_err()
+                .to_string();
+        assert!(err.contains(base_dir.to_string_lossy().as_ref()));
+        assert!(err.contains("does not exist"));
+        assert!(err.contains(ENV_VAR_BASE_DIR));
+        // The base dir gets created when it's written to
+        check_base_dir(&base_dir, BaseDirSource::Arg, BaseDirAccess::Write)
+            .unwrap();
+        // A file can't be used as a base dir
+        std::fs::write(&base_dir, "").unwrap();
+        let err =
+            check_base_dir(&base_dir, BaseDirSource::Arg, BaseDirAccess::Read)
+                .unwrap_err();
+        assert!(err.to_string().contains("not a directory"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_check_read_only_base_dir() {
+        use std::os::unix::fs::MetadataExt;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let base_dir = tmp.path();
+        let metadata = std::fs::metadata(base_dir).unwrap();
+        // The temp dir is owned by the effective user. Root can write to it
+        // regardless of its mode, so there's nothing to check.
+        if metadata.uid() == 0 {
+            eprintln!("Skipping the read-only base dir check as root");
+            return;
+        }
+        let mut permissions = metadata.permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(base_dir, permissions.clone()).unwrap();
+
+        check_base_dir(base_dir, BaseDirSource::Arg, BaseDirAccess::Read)
+            .unwrap();
+        let err =
+            check_base_dir(base_dir, BaseDirSource::Arg, BaseDirAccess::Write)
+                .unwrap_err()
+                .to_string();
+        assert!(err.contains(base_dir.to_string_lossy().as_ref()));
+        assert!(err.contains("not writable"));
+
+        // Restore the permissions to let the temp dir be cleaned up
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(false);
+        std::fs::set_permissions(base_dir, permissions).unwrap();
+    }
+
+    #[test]
+    fn test_try_new_does_not_generate_files() {
+        let base_dir = tempfile::tempdir().unwrap();
+        let chain_id = ChainId("test-chain.abcdef".to_string());
+        let global_args = args::Global {
+            is_pre_genesis: false,
+            chain_id: Some(chain_id.clone()),
+            base_dir: base_dir.path().to_path_buf(),
+            wasm_dir: None,
+        };
+        let config_path = Config::file_path(base_dir.path(), &chain_id);
+
+        // `try_new` fails on an empty base dir without writing anything
+        assert!(Context::try_new::<StdIo>(global_args.clone()).is_err());
+        assert!(!config_path.exists());
+        assert_eq!(std::fs::read_dir(base_dir.path()).unwrap().count(), 0);
+
+        // `new` generates the chain config before it gives up on the
+        // missing genesis files
+        let res = std::panic::catch_unwind(|| {
+            Context::new::<StdIo>(global_args).map(|_| ())
+        });
+        assert!(res.is_err());
+        assert!(config_path.exists());
+    }
+}
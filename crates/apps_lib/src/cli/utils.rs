@@ -2,6 +2,7 @@
 use std::fmt::Debug;
 use std::io::Write;
 use std::marker::PhantomData;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -14,7 +15,9 @@ use namada_sdk::eth_bridge::ethers::providers::{Http, Middleware, Provider};
 use namada_sdk::eth_bridge::ethers::signers::{Signer, Wallet};
 
 use super::args;
-use super::context::Context;
+use super::context::{
+    check_base_dir, BaseDirAccess, BaseDirSource, Context, ENV_VAR_BASE_DIR,
+};
 use crate::cli::api::CliIo;
 use crate::cli::context::FromContext;
 
@@ -45,6 +48,7 @@ pub trait Cmd: Sized {
         let matches = app.clone().get_matches();
         match Self::parse(&matches) {
             Some(cmd) => {
+                check_base_dir_or_exit(&matches, BaseDirAccess::Write);
                 let global_args = args::Global::parse(&matches);
                 let context = Context::new::<CliIo>(global_args)?;
                 Ok((cmd, context))
@@ -58,6 +62,40 @@ pub trait Cmd: Sized {
     }
 }
 
+/// Find what set the base directory resolved from the global arguments.
+pub fn base_dir_source(matches: &ArgMatches) -> BaseDirSource {
+    resolve_base_dir(matches, |name| std::env::var(name).ok()).1
+}
+
+/// Resolve the base directory from the global arguments and find what set
+/// it, looking up env vars with the given `env_var` function.
+pub fn resolve_base_dir(
+    matches: &ArgMatches,
+    env_var: impl Fn(&str) -> Option<String>,
+) -> (PathBuf, BaseDirSource) {
+    if matches.contains_id(args::BASE_DIR.name) {
+        (args::BASE_DIR.parse(matches), BaseDirSource::Arg)
+    } else if let Some(base_dir) = env_var(ENV_VAR_BASE_DIR) {
+        (PathBuf::from(base_dir), BaseDirSource::EnvVar)
+    } else {
+        (
+            crate::config::get_default_namada_folder(),
+            BaseDirSource::Default,
+        )
+    }
+}
+
+/// Check that the base directory resolved from the global arguments can be
+/// used with the given access, or exit the process with an error.
+pub fn check_base_dir_or_exit(matches: &ArgMatches, access: BaseDirAccess) {
+    let base_dir = args::BASE_DIR.parse(matches);
+    let source = base_dir_source(matches);
+    if let Err(err) = check_base_dir(&base_dir, source, access) {
+        eprintln!("{err}");
+        safe_exit(1)
+    }
+}
+
 pub trait SubCmd: Sized {
     const CMD: &'static str;
     fn parse(matches: &ArgMatches) -> Option<Self>;
@@ -185,7 +223,6 @@ impl<T> Arg<T> {
         }
     }
 
-    #[allow(dead_code)]
     pub const fn multi_glob_plus(self) -> ArgMulti<T, GlobPlus> {
         ArgMulti {
             name: self.name,
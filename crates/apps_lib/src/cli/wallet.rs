@@ -1,5 +1,6 @@
 //! Namada Wallet CLI.
 
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{self, Write};
 use std::str::FromStr;
@@ -11,22 +12,26 @@ use itertools::sorted;
 use ledger_namada_rs::{BIP44Path, NamadaApp};
 use ledger_transport_hid::hidapi::HidApi;
 use ledger_transport_hid::TransportNativeHID;
-use masp_primitives::zip32::ExtendedFullViewingKey;
+use masp_primitives::zip32::{DiversifierIndex, ExtendedFullViewingKey};
 use namada_sdk::address::{Address, DecodeError};
 use namada_sdk::io::Io;
 use namada_sdk::key::*;
 use namada_sdk::masp::{
-    find_valid_diversifier, ExtendedSpendingKey, MaspValue, PaymentAddress,
+    find_valid_diversifier, ExtendedSpendingKey, ExtendedViewingKey, MaspValue,
+    PaymentAddress,
 };
 use namada_sdk::wallet::{
-    DecryptionError, DerivationPath, DerivationPathError, FindKeyError, Wallet,
+    DecryptionError, DerivationPath, DerivationPathError, FindKeyError,
+    RenameResult, Wallet, WalletIo,
 };
-use namada_sdk::{display_line, edisplay_line};
+use namada_sdk::{display, display_line, edisplay_line};
 use rand_core::OsRng;
+use serde::Serialize;
+use zeroize::Zeroizing;
 
 use crate::cli;
 use crate::cli::api::CliApi;
-use crate::cli::args::CliToSdk;
+use crate::cli::args::{CliToSdk, OutputFormat};
 use crate::cli::{args, cmds, Context};
 use crate::client::utils::PRE_GENESIS_DIR;
 use crate::tendermint_node::validator_key_to_json;
@@ -47,6 +52,9 @@ impl CliApi {
             cmds::NamadaWallet::KeyDerive(cmds::WalletDerive(args)) => {
                 key_derive(ctx, io, args).await
             }
+            cmds::NamadaWallet::KeyMnemonicCheck(
+                cmds::WalletMnemonicCheck(args),
+            ) => key_mnemonic_check(io, args),
             cmds::NamadaWallet::KeyAddrList(cmds::WalletListKeysAddresses(
                 args,
             )) => key_address_list(ctx, io, args),
@@ -54,7 +62,7 @@ impl CliApi {
                 args,
             )) => key_address_find(ctx, io, args),
             cmds::NamadaWallet::KeyExport(cmds::WalletExportKey(args)) => {
-                key_export(ctx, io, args)
+                key_export(ctx, io, args).await
             }
             cmds::NamadaWallet::KeyConvert(cmds::WalletConvertKey(args)) => {
                 key_convert(ctx, io, args)
@@ -68,6 +76,12 @@ impl CliApi {
             cmds::NamadaWallet::KeyAddrRemove(
                 cmds::WalletRemoveKeyAddress(args),
             ) => key_address_remove(ctx, io, args),
+            cmds::NamadaWallet::KeyAddrRename(
+                cmds::WalletRenameKeyAddress(args),
+            ) => key_address_rename(ctx, io, args),
+            cmds::NamadaWallet::Check(cmds::WalletCheck) => {
+                wallet_check(ctx, io)
+            }
             cmds::NamadaWallet::PayAddrGen(cmds::WalletGenPaymentAddress(
                 args,
             )) => {
@@ -205,11 +219,16 @@ fn shielded_key_derive(
             cli::safe_exit(1)
         });
     println!("Using HD derivation path {}", derivation_path);
-    if !allow_non_compliant && !derivation_path.is_namada_shielded_compliant() {
-        display_line!(io, "Path {} is not compliant.", derivation_path);
+    check_derivation_path_compliance(
+        io,
+        derivation_path.validate_for_shielded(),
+        allow_non_compliant,
+    )
+    .unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", err);
         display_line!(io, "No changes are persisted. Exiting.");
         cli::safe_exit(1)
-    }
+    });
     let alias = alias.to_lowercase();
     let alias = if !use_device {
         let encryption_password =
@@ -256,6 +275,8 @@ fn shielded_key_gen(
         derivation_path,
         allow_non_compliant,
         prompt_bip39_passphrase,
+        mnemonic_type,
+        store_mnemonic,
         ..
     }: args::KeyGen,
 ) {
@@ -271,25 +292,41 @@ fn shielded_key_gen(
                 cli::safe_exit(1)
             });
         println!("Using HD derivation path {}", derivation_path);
-        if !allow_non_compliant
-            && !derivation_path.is_namada_shielded_compliant()
-        {
-            display_line!(io, "Path {} is not compliant.", derivation_path);
+        check_derivation_path_compliance(
+            io,
+            derivation_path.validate_for_shielded(),
+            allow_non_compliant,
+        )
+        .unwrap_or_else(|err| {
+            edisplay_line!(io, "{}", err);
             display_line!(io, "No changes are persisted. Exiting.");
             cli::safe_exit(1)
-        }
-        let (_mnemonic, seed) = Wallet::<CliWalletUtils>::gen_hd_seed(
-            None,
+        });
+        let passphrase =
+            read_passphrase_to_store(store_mnemonic, prompt_bip39_passphrase);
+        let (mnemonic, seed) = Wallet::<CliWalletUtils>::gen_hd_seed(
+            mnemonic_type,
+            passphrase.clone(),
             &mut OsRng,
             prompt_bip39_passphrase,
         );
-        wallet.derive_store_hd_spendind_key(
+        let added = wallet.derive_store_hd_spendind_key(
             alias,
             alias_force,
             seed,
-            derivation_path,
-            password,
-        )
+            derivation_path.clone(),
+            password.clone(),
+        );
+        if let (Some((alias, _)), Some(passphrase)) = (&added, passphrase) {
+            wallet.store_mnemonic(
+                alias,
+                &mnemonic,
+                &passphrase,
+                &derivation_path,
+                password,
+            );
+        }
+        added
     }
     .map(|x| x.0)
     .unwrap_or_else(|| {
@@ -316,17 +353,19 @@ fn payment_address_gen(
         alias,
         alias_force,
         viewing_key,
-        ..
+        diversifier_index,
     }: args::PayAddressGen,
 ) {
     let mut wallet = load_wallet(ctx);
     let alias = alias.to_lowercase();
-    let viewing_key = ExtendedFullViewingKey::from(viewing_key).fvk.vk;
-    let (div, _g_d) = find_valid_diversifier(&mut OsRng);
-    let masp_payment_addr = viewing_key
-        .to_payment_address(div)
-        .expect("a PaymentAddress");
-    let payment_addr = PaymentAddress::from(masp_payment_addr);
+    let (used_index, payment_addr) = derive_payment_address(
+        viewing_key,
+        diversifier_index,
+    )
+    .unwrap_or_else(|| {
+        edisplay_line!(io, "No valid diversifier found from the given index");
+        cli::safe_exit(1);
+    });
     let alias = wallet
         .insert_payment_addr(alias, payment_addr, alias_force)
         .unwrap_or_else(|| {
@@ -340,6 +379,51 @@ fn payment_address_gen(
         payment_addr,
         alias,
     );
+    if let Some(used_index) = used_index {
+        display_line!(
+            io,
+            "It was derived from the diversifier index {}",
+            used_index
+        );
+    }
+}
+
+/// Derive a payment address from the given viewing key. If a diversifier
+/// index is given, the first valid diversifier at or after it is used and its
+/// index is returned along with the payment address. Otherwise, the
+/// diversifier is picked at random.
+fn derive_payment_address(
+    viewing_key: ExtendedViewingKey,
+    diversifier_index: Option<u64>,
+) -> Option<(Option<u64>, PaymentAddress)> {
+    let viewing_key = ExtendedFullViewingKey::from(viewing_key);
+    let (used_index, masp_payment_addr) = match diversifier_index {
+        Some(index) => {
+            let mut index_bytes = [0u8; 11];
+            index_bytes[..8].copy_from_slice(&index.to_le_bytes());
+            let (DiversifierIndex(used_bytes), payment_addr) =
+                viewing_key.find_address(DiversifierIndex(index_bytes))?;
+            // The used index must be one that can be passed back in
+            let (used_bytes, overflow) = used_bytes.split_at(8);
+            if overflow.iter().any(|byte| *byte != 0) {
+                return None;
+            }
+            let used_index = u64::from_le_bytes(
+                used_bytes.try_into().expect("8 bytes should fit a u64"),
+            );
+            (Some(used_index), payment_addr)
+        }
+        None => {
+            let (div, _g_d) = find_valid_diversifier(&mut OsRng);
+            let payment_addr = viewing_key
+                .fvk
+                .vk
+                .to_payment_address(div)
+                .expect("a PaymentAddress");
+            (None, payment_addr)
+        }
+    };
+    Some((used_index, PaymentAddress::from(masp_payment_addr)))
 }
 
 /// Add a viewing key, spending key, or payment address to wallet.
@@ -432,6 +516,22 @@ pub fn decode_shielded_derivation_path(
     Ok(parsed_derivation_path)
 }
 
+/// Refuse a derivation path that is not compliant with Namada's schemes, or
+/// only warn about it when non-compliant paths are allowed
+fn check_derivation_path_compliance(
+    io: &impl Io,
+    compliance: Result<(), DerivationPathError>,
+    allow_non_compliant: bool,
+) -> Result<(), DerivationPathError> {
+    match compliance {
+        Err(err) if allow_non_compliant => {
+            display_line!(io, "Warning: {}", err);
+            Ok(())
+        }
+        compliance => compliance,
+    }
+}
+
 /// Derives a keypair and an implicit address from the mnemonic code in the
 /// wallet.
 async fn transparent_key_and_address_derive(
@@ -457,13 +557,16 @@ async fn transparent_key_and_address_derive(
                 cli::safe_exit(1)
             });
     println!("Using HD derivation path {}", derivation_path);
-    if !allow_non_compliant
-        && !derivation_path.is_namada_transparent_compliant(scheme)
-    {
-        display_line!(io, "Path {} is not compliant.", derivation_path);
+    check_derivation_path_compliance(
+        io,
+        derivation_path.validate_for_scheme(scheme),
+        allow_non_compliant,
+    )
+    .unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", err);
         display_line!(io, "No changes are persisted. Exiting.");
         cli::safe_exit(1)
-    }
+    });
     let alias = alias.to_lowercase();
     let alias = if !use_device {
         let encryption_password =
@@ -555,6 +658,8 @@ fn transparent_key_and_address_gen(
         derivation_path,
         allow_non_compliant,
         prompt_bip39_passphrase,
+        mnemonic_type,
+        store_mnemonic,
         ..
     }: args::KeyGen,
 ) {
@@ -578,26 +683,42 @@ fn transparent_key_and_address_gen(
                     cli::safe_exit(1)
                 });
         println!("Using HD derivation path {}", derivation_path);
-        if !allow_non_compliant
-            && !derivation_path.is_namada_transparent_compliant(scheme)
-        {
-            display_line!(io, "Path {} is not compliant.", derivation_path);
+        check_derivation_path_compliance(
+            io,
+            derivation_path.validate_for_scheme(scheme),
+            allow_non_compliant,
+        )
+        .unwrap_or_else(|err| {
+            edisplay_line!(io, "{}", err);
             display_line!(io, "No changes are persisted. Exiting.");
             cli::safe_exit(1)
-        }
-        let (_mnemonic, seed) = Wallet::<CliWalletUtils>::gen_hd_seed(
-            None,
+        });
+        let passphrase =
+            read_passphrase_to_store(store_mnemonic, prompt_bip39_passphrase);
+        let (mnemonic, seed) = Wallet::<CliWalletUtils>::gen_hd_seed(
+            mnemonic_type,
+            passphrase.clone(),
             &mut OsRng,
             prompt_bip39_passphrase,
         );
-        wallet.derive_store_hd_secret_key(
+        let added = wallet.derive_store_hd_secret_key(
             scheme,
             Some(alias),
             alias_force,
             seed,
-            derivation_path,
-            encryption_password,
-        )
+            derivation_path.clone(),
+            encryption_password.clone(),
+        );
+        if let (Some((alias, _)), Some(passphrase)) = (&added, passphrase) {
+            wallet.store_mnemonic(
+                alias,
+                &mnemonic,
+                &passphrase,
+                &derivation_path,
+                encryption_password,
+            );
+        }
+        added
     }
     .map(|x| x.0)
     .unwrap_or_else(|| {
@@ -615,6 +736,21 @@ fn transparent_key_and_address_gen(
     );
 }
 
+/// Read the BIP39 passphrase upfront if the mnemonic code is to be stored in
+/// the wallet, as it is then needed alongside the mnemonic code
+fn read_passphrase_to_store(
+    store_mnemonic: bool,
+    prompt_bip39_passphrase: bool,
+) -> Option<Zeroizing<String>> {
+    store_mnemonic.then(|| {
+        if prompt_bip39_passphrase {
+            CliWalletUtils::read_mnemonic_passphrase(true)
+        } else {
+            Zeroizing::default()
+        }
+    })
+}
+
 /// Key generation
 fn key_gen(ctx: Context, io: &impl Io, args_key_gen: args::KeyGen) {
     if !args_key_gen.shielded {
@@ -630,13 +766,88 @@ async fn key_derive(
     io: &impl Io,
     args_key_derive: args::KeyDerive,
 ) {
-    if !args_key_derive.shielded {
+    if args_key_derive.from_stored_mnemonic {
+        key_derive_from_stored_mnemonic(ctx, io, args_key_derive.alias)
+    } else if !args_key_derive.shielded {
         transparent_key_and_address_derive(ctx, io, args_key_derive).await
     } else {
         shielded_key_derive(ctx, io, args_key_derive)
     }
 }
 
+/// Derive the next key from the mnemonic code stored in the wallet for the
+/// given alias
+fn key_derive_from_stored_mnemonic(ctx: Context, io: &impl Io, alias: String) {
+    let mut wallet = load_wallet(ctx);
+    match wallet.derive_next_from_stored_mnemonic(alias.to_lowercase(), None) {
+        Ok(Some((alias, derivation_path))) => {
+            wallet
+                .save()
+                .unwrap_or_else(|err| edisplay_line!(io, "{}", err));
+            display_line!(
+                io,
+                "Successfully added a key with alias \"{}\" derived with HD \
+                 derivation path {}",
+                alias,
+                derivation_path
+            );
+        }
+        Ok(None) => {
+            display_line!(io, "No changes are persisted. Exiting.");
+            cli::safe_exit(1)
+        }
+        Err(err) => {
+            edisplay_line!(io, "{}", err);
+            display_line!(io, "No changes are persisted. Exiting.");
+            cli::safe_exit(1)
+        }
+    }
+}
+
+/// Derive a keypair and an implicit address from the mnemonic code and print
+/// the address, without storing anything in the wallet.
+fn key_mnemonic_check(
+    io: &impl Io,
+    args::KeyMnemonicCheck {
+        scheme,
+        derivation_path,
+        allow_non_compliant,
+        prompt_bip39_passphrase,
+    }: args::KeyMnemonicCheck,
+) {
+    let derivation_path =
+        decode_transparent_derivation_path(scheme, derivation_path)
+            .unwrap_or_else(|err| {
+                edisplay_line!(io, "{}", err);
+                cli::safe_exit(1)
+            });
+    display_line!(io, "Using HD derivation path {}", derivation_path);
+    check_derivation_path_compliance(
+        io,
+        derivation_path.validate_for_scheme(scheme),
+        allow_non_compliant,
+    )
+    .unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", err);
+        cli::safe_exit(1)
+    });
+    let sk = Wallet::<CliWalletUtils>::derive_key_from_mnemonic_code(
+        scheme,
+        derivation_path,
+        None,
+        prompt_bip39_passphrase,
+    )
+    .unwrap_or_else(|| {
+        edisplay_line!(io, "Failed to derive a keypair.");
+        cli::safe_exit(1)
+    });
+    let pk = sk.to_public();
+    let address = Address::Implicit((&pk).into());
+    display_line!(io, "Public key: {}", pk);
+    display_line!(io, "Implicit address: {}", address);
+    display_line!(io, "Nothing was stored in the wallet.");
+}
+
 /// List keys and addresses
 fn key_address_list(
     ctx: Context,
@@ -648,9 +859,22 @@ fn key_address_list(
         keys_only,
         addresses_only,
         unsafe_show_secret,
+        scheme,
+        output_format,
     }: args::KeyAddressList,
 ) {
     let wallet = load_wallet(ctx);
+    if output_format == OutputFormat::Json {
+        let output = keys_addresses_json(
+            &wallet,
+            !shielded_only && !addresses_only,
+            !shielded_only && !keys_only,
+            !transparent_only && !keys_only,
+            scheme,
+        );
+        display_line!(io, "{}", output);
+        return;
+    }
     if !shielded_only {
         if !addresses_only {
             transparent_keys_list(
@@ -658,6 +882,7 @@ fn key_address_list(
                 io,
                 decrypt,
                 unsafe_show_secret,
+                scheme,
                 transparent_only && keys_only,
             )
         }
@@ -863,6 +1088,74 @@ fn key_address_remove(
     display_line!(io, "Successfully removed alias: \"{}\"", alias);
 }
 
+/// Rename keys and addresses
+fn key_address_rename(
+    ctx: Context,
+    io: &impl Io,
+    args::KeyAddressRename {
+        alias,
+        new_alias,
+        alias_force,
+    }: args::KeyAddressRename,
+) {
+    let alias = alias.to_lowercase();
+    let mut wallet = load_wallet(ctx);
+    match wallet.rename_alias(&alias, new_alias, alias_force) {
+        RenameResult::Renamed(new_alias) => {
+            wallet
+                .save()
+                .unwrap_or_else(|err| edisplay_line!(io, "{}", err));
+            display_line!(
+                io,
+                "Successfully renamed alias \"{}\" to \"{}\"",
+                alias,
+                new_alias
+            );
+        }
+        RenameResult::NotFound => {
+            edisplay_line!(io, "No keys or addresses found with alias {alias}");
+            cli::safe_exit(1)
+        }
+        RenameResult::Skipped => {
+            edisplay_line!(io, "Alias {alias} was not renamed.");
+            cli::safe_exit(1)
+        }
+        RenameResult::InvalidAlias(new_alias) => {
+            edisplay_line!(
+                io,
+                "The alias {:?} is invalid. Aliases must not be blank nor \
+                 contain non-printable characters.",
+                new_alias.normalize()
+            );
+            cli::safe_exit(1)
+        }
+    }
+}
+
+/// Check the integrity of the wallet store
+fn wallet_check(ctx: Context, io: &impl Io) {
+    let wallet = load_wallet(ctx);
+    let inconsistencies = wallet.store().check_integrity();
+    if inconsistencies.is_empty() {
+        display_line!(io, "No inconsistencies found in the wallet.");
+        return;
+    }
+    display_line!(
+        io,
+        "Found {} inconsistenc{} in the wallet:",
+        inconsistencies.len(),
+        if inconsistencies.len() == 1 {
+            "y"
+        } else {
+            "ies"
+        }
+    );
+    for inconsistency in inconsistencies {
+        display_line!(io, "  - {inconsistency}");
+    }
+    cli::safe_exit(1)
+}
+
 /// Find a keypair in the wallet store.
 fn transparent_key_find(
     ctx: Context,
@@ -1149,9 +1442,14 @@ fn transparent_keys_list(
     io: &impl Io,
     decrypt: bool,
     unsafe_show_secret: bool,
+    scheme: Option<SchemeType>,
     show_hint: bool,
 ) {
-    let known_public_keys = wallet.get_public_keys();
+    let mut known_public_keys = wallet.get_public_keys();
+    if let Some(scheme) = scheme {
+        known_public_keys
+            .retain(|_alias, public_key| is_of_scheme(public_key, scheme));
+    }
     if known_public_keys.is_empty() {
         if show_hint {
             display_line!(
@@ -1221,31 +1519,142 @@ fn transparent_keys_list(
     }
 }
 
+/// Keys and addresses, as printed in the JSON output format
+#[derive(Serialize)]
+struct KeyAddressListOutput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keys: Option<BTreeMap<String, KeyOutput>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    addresses: Option<BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payment_addresses: Option<BTreeMap<String, String>>,
+}
+
+/// A transparent key, as printed in the JSON output format
+#[derive(Serialize)]
+struct KeyOutput {
+    public_key_hash: String,
+    encrypted: bool,
+}
+
+/// Format the known transparent keys, transparent addresses and / or payment
+/// addresses as JSON.
+fn keys_addresses_json(
+    wallet: &Wallet<CliWalletUtils>,
+    list_keys: bool,
+    list_addresses: bool,
+    list_payment_addresses: bool,
+    scheme: Option<SchemeType>,
+) -> String {
+    let keys = list_keys.then(|| {
+        let known_secret_keys = wallet.get_secret_keys();
+        wallet
+            .get_public_keys()
+            .into_iter()
+            .filter(|(_alias, public_key)| {
+                scheme.map_or(true, |scheme| is_of_scheme(public_key, scheme))
+            })
+            .map(|(alias, public_key)| {
+                let encrypted = known_secret_keys.get(&alias).is_some_and(
+                    |(stored_keypair, _pkh)| stored_keypair.is_encrypted(),
+                );
+                let key = KeyOutput {
+                    public_key_hash: PublicKeyHash::from(&public_key)
+                        .to_string(),
+                    encrypted,
+                };
+                (alias, key)
+            })
+            .collect()
+    });
+    let addresses = list_addresses.then(|| {
+        wallet
+            .get_addresses()
+            .into_iter()
+            .map(|(alias, address)| (alias, address.to_string()))
+            .collect()
+    });
+    let payment_addresses = list_payment_addresses.then(|| {
+        wallet
+            .get_payment_addrs()
+            .into_iter()
+            .map(|(alias, address)| (alias, address.to_string()))
+            .collect()
+    });
+    let output = KeyAddressListOutput {
+        keys,
+        addresses,
+        payment_addresses,
+    };
+    serde_json::to_string(&output)
+        .expect("Serializing keys and addresses should not fail")
+}
+
+/// Check if the given public key belongs to the given scheme. All keys belong
+/// to the common scheme.
+fn is_of_scheme(public_key: &common::PublicKey, scheme: SchemeType) -> bool {
+    match (public_key, scheme) {
+        (_, SchemeType::Common)
+        | (common::PublicKey::Ed25519(_), SchemeType::Ed25519)
+        | (common::PublicKey::Secp256k1(_), SchemeType::Secp256k1) => true,
+        (common::PublicKey::Ed25519(_), SchemeType::Secp256k1)
+        | (common::PublicKey::Secp256k1(_), SchemeType::Ed25519) => false,
+    }
+}
+
 /// Export a transparent keypair / MASP spending key to a file.
-fn key_export(
+async fn key_export(
     ctx: Context,
     io: &impl Io,
     args::KeyExport { alias }: args::KeyExport,
 ) {
     let alias = alias.to_lowercase();
     let mut wallet = load_wallet(ctx);
-    let key_to_export = wallet
-        .find_secret_key(&alias, None)
-        .map(|sk| Box::new(sk) as Box<dyn BorshSerializeExt>)
-        .or(wallet
-            .find_spending_key(&alias, None)
-            .map(|spk| Box::new(spk) as Box<dyn BorshSerializeExt>));
-    key_to_export
-        .map(|key| {
-            let file_data = key.serialize_to_vec();
-            let file_name = format!("key_{}", alias);
-            let mut file = File::create(&file_name).unwrap();
-            file.write_all(file_data.as_ref()).unwrap();
-            display_line!(io, "Exported to file {}", file_name);
-        })
-        .unwrap_or_else(|err| {
+    let file_data =
+        export_key_data(&mut wallet, &alias).unwrap_or_else(|err| {
             edisplay_line!(io, "{}", err);
             cli::safe_exit(1)
+        });
+    let file_name = format!("key_{}", alias);
+    if !confirm_raw_key_export(io, &file_name).await {
+        edisplay_line!(io, "Key export cancelled.");
+        cli::safe_exit(1)
+    }
+    let mut file = File::create(&file_name).unwrap();
+    file.write_all(file_data.as_ref()).unwrap();
+    display_line!(io, "Exported to file {}", file_name);
+}
+
+/// Ask the user to confirm writing an unencrypted key to the given file.
+/// Anything but a "y" answer, including a failure to read it, is a refusal.
+async fn confirm_raw_key_export(io: &impl Io, file_name: &str) -> bool {
+    display!(
+        io,
+        "Warning: the exported key will not be encrypted. Anyone with access \
+         to {file_name} will be able to use it. Do you wish to proceed? \
+         (y/n): "
+    );
+    io.flush();
+    io.read()
+        .await
+        .map(|resp| resp.trim() == "y")
+        .unwrap_or_default()
+}
+
+/// Find the transparent secret key or the MASP spending key with the given
+/// alias, decrypting it if needed, and serialize it in the format expected by
+/// the key import command
+fn export_key_data(
+    wallet: &mut Wallet<CliWalletUtils>,
+    alias: &str,
+) -> Result<Vec<u8>, FindKeyError> {
+    wallet
+        .find_secret_key(alias, None)
+        .map(|sk| sk.serialize_to_vec())
+        .or_else(|_| {
+            wallet
+                .find_spending_key(alias, None)
+                .map(|spk| spk.serialize_to_vec())
         })
 }
 
@@ -1284,34 +1693,58 @@ fn key_import(
         display_line!(io, "No changes are persisted. Exiting.");
         cli::safe_exit(1)
     });
-    if let Ok(sk) = common::SecretKey::try_from_slice(&file_data) {
-        transparent_secret_key_add(
+    match decode_key_file(&file_data) {
+        Some(ImportedKey::Transparent(sk)) => transparent_secret_key_add(
             ctx,
             io,
             alias,
             alias_force,
             sk,
             unsafe_dont_encrypt,
-        );
-    } else if let Ok(spend_key) =
-        ExtendedSpendingKey::try_from_slice(&file_data)
-    {
-        let masp_value = MaspValue::ExtendedSpendingKey(spend_key);
-        shielded_key_address_add(
-            ctx,
-            io,
-            alias,
-            alias_force,
-            masp_value,
-            unsafe_dont_encrypt,
-        );
-    } else {
-        display_line!(io, "Could not parse the data.");
-        display_line!(io, "No changes are persisted. Exiting.");
-        cli::safe_exit(1)
+        ),
+        Some(ImportedKey::Shielded(spend_key)) => {
+            let masp_value = MaspValue::ExtendedSpendingKey(spend_key);
+            shielded_key_address_add(
+                ctx,
+                io,
+                alias,
+                alias_force,
+                masp_value,
+                unsafe_dont_encrypt,
+            );
+        }
+        None => {
+            display_line!(io, "Could not parse the data.");
+            display_line!(io, "No changes are persisted. Exiting.");
+            cli::safe_exit(1)
+        }
     }
 }
 
+/// A key read from a file to be imported into the wallet
+enum ImportedKey {
+    Transparent(common::SecretKey),
+    Shielded(ExtendedSpendingKey),
+}
+
+/// Decode a key file, as written by the key export command, or holding the
+/// text encoding of a transparent secret key or of a MASP spending key
+fn decode_key_file(file_data: &[u8]) -> Option<ImportedKey> {
+    if let Ok(sk) = common::SecretKey::try_from_slice(file_data) {
+        return Some(ImportedKey::Transparent(sk));
+    }
+    if let Ok(spend_key) = ExtendedSpendingKey::try_from_slice(file_data) {
+        return Some(ImportedKey::Shielded(spend_key));
+    }
+    let text = std::str::from_utf8(file_data).ok()?.trim();
+    if let Ok(sk) = common::SecretKey::from_str(text) {
+        return Some(ImportedKey::Transparent(sk));
+    }
+    ExtendedSpendingKey::from_str(text)
+        .ok()
+        .map(ImportedKey::Shielded)
+}
+
 /// List all known transparent addresses.
 fn transparent_addresses_list(
     wallet: &Wallet<CliWalletUtils>,
@@ -1433,3 +1866,330 @@ fn load_wallet(ctx: Context) -> Wallet<CliWalletUtils> {
         ctx.take_chain_or_exit().wallet
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+
+    use namada_sdk::io::StdIo;
+    use namada_sdk::wallet::Compatibility;
+
+    use super::*;
+
+    /// An IO that answers every read with the same input
+    struct InputIo(&'static str);
+
+    #[async_trait::async_trait(?Send)]
+    impl Io for InputIo {
+        async fn read(&self) -> std::io::Result<String> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn test_check_derivation_path_compliance() {
+        let path =
+            DerivationPath::from_path_string("m/44'/877'/0'/0/0").unwrap();
+        let scheme = SchemeType::Ed25519;
+
+        // A compliant path is accepted in either mode
+        let compliant = DerivationPath::default_for_transparent_scheme(scheme);
+        for allow_non_compliant in [false, true] {
+            assert!(
+                check_derivation_path_compliance(
+                    &StdIo,
+                    compliant.validate_for_scheme(scheme),
+                    allow_non_compliant,
+                )
+                .is_ok()
+            );
+        }
+
+        // A non-compliant path is refused by default
+        assert!(matches!(
+            check_derivation_path_compliance(
+                &StdIo,
+                path.validate_for_scheme(scheme),
+                false,
+            ),
+            Err(DerivationPathError::NonCompliant {
+                reason: Compatibility::WrongHardening,
+                ..
+            })
+        ));
+
+        // and only warned about when non-compliant paths are allowed
+        assert!(
+            check_derivation_path_compliance(
+                &StdIo,
+                path.validate_for_scheme(scheme),
+                true,
+            )
+            .is_ok()
+        );
+    }
+
+    /// An IO that records the printed lines instead of writing them out
+    #[derive(Default)]
+    struct CaptureIo(RefCell<Vec<String>>);
+
+    #[async_trait::async_trait(?Send)]
+    impl Io for CaptureIo {
+        fn println(&self, output: impl AsRef<str>) {
+            self.0.borrow_mut().push(output.as_ref().to_string());
+        }
+
+        fn writeln<W: std::io::Write>(
+            &self,
+            _writer: W,
+            output: impl AsRef<str>,
+        ) -> std::io::Result<()> {
+            self.println(output);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_transparent_keys_list_scheme() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut wallet = wallet::load_or_new(dir.path());
+        let ed25519_pk = namada_sdk::key::testing::keypair_1().ref_to();
+        let secp256k1_pk = namada_sdk::key::testing::keypair_3().ref_to();
+        for (alias, sk) in [
+            ("ed25519-key", namada_sdk::key::testing::keypair_1()),
+            ("secp256k1-key", namada_sdk::key::testing::keypair_3()),
+        ] {
+            wallet
+                .insert_keypair(alias.to_string(), true, sk, None, None, None)
+                .unwrap();
+        }
+        let list = |scheme| {
+            let io = CaptureIo::default();
+            transparent_keys_list(&wallet, &io, false, false, scheme, true);
+            io.0.into_inner().join("\n")
+        };
+
+        let output = list(Some(SchemeType::Ed25519));
+        assert!(output.contains("Alias \"ed25519-key\" (not encrypted)"));
+        assert!(output.contains(&format!("Public key: {ed25519_pk}")));
+        assert!(!output.contains("secp256k1-key"));
+        assert!(!output.contains(&secp256k1_pk.to_string()));
+
+        let output = list(Some(SchemeType::Secp256k1));
+        assert!(output.contains("Alias \"secp256k1-key\" (not encrypted)"));
+        assert!(output.contains(&format!("Public key: {secp256k1_pk}")));
+        assert!(!output.contains("ed25519-key"));
+        assert!(!output.contains(&ed25519_pk.to_string()));
+
+        // All the keys belong to the common scheme
+        for scheme in [Some(SchemeType::Common), None] {
+            let output = list(scheme);
+            assert!(output.contains("Alias \"ed25519-key\""));
+            assert!(output.contains("Alias \"secp256k1-key\""));
+        }
+    }
+
+    #[test]
+    fn test_keys_addresses_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut wallet = wallet::load_or_new(dir.path());
+        let password = Zeroizing::new("password".to_string());
+        for (alias, sk, password) in [
+            ("alice", namada_sdk::key::testing::keypair_1(), Some(password)),
+            ("bob", namada_sdk::key::testing::keypair_3(), None),
+        ] {
+            wallet
+                .insert_keypair(
+                    alias.to_string(),
+                    true,
+                    sk,
+                    password,
+                    None,
+                    None,
+                )
+                .unwrap();
+        }
+        let alice_pk = namada_sdk::key::testing::keypair_1().ref_to();
+        let bob_pk = namada_sdk::key::testing::keypair_3().ref_to();
+        let alice_pkh = PublicKeyHash::from(&alice_pk);
+        let bob_pkh = PublicKeyHash::from(&bob_pk);
+
+        let output = keys_addresses_json(&wallet, true, true, false, None);
+        let json: serde_json::Value =
+            serde_json::from_str(&output).expect("Output should be valid JSON");
+        let keys = &json["keys"];
+        assert_eq!(keys["alice"]["public_key_hash"], alice_pkh.to_string());
+        assert_eq!(keys["alice"]["encrypted"], true);
+        assert_eq!(keys["bob"]["public_key_hash"], bob_pkh.to_string());
+        assert_eq!(keys["bob"]["encrypted"], false);
+        let addresses = &json["addresses"];
+        assert_eq!(
+            addresses["alice"],
+            Address::Implicit((&alice_pk).into()).to_string()
+        );
+        assert_eq!(
+            addresses["bob"],
+            Address::Implicit((&bob_pk).into()).to_string()
+        );
+
+        // Only list the secp256k1 keys, without any addresses
+        let output = keys_addresses_json(
+            &wallet,
+            true,
+            false,
+            false,
+            Some(SchemeType::Secp256k1),
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&output).expect("Output should be valid JSON");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "keys": {
+                    "bob": {
+                        "public_key_hash": bob_pkh.to_string(),
+                        "encrypted": false,
+                    },
+                },
+            })
+        );
+
+        // Only list the payment addresses
+        let payment_address = PaymentAddress::from_str(
+            "znam1ky620tz7z658cralqt693qpvk42wvth468zp38nqvq2apmex5rfut3dfqm2\
+             asrsqv0tc7saqje7",
+        )
+        .unwrap();
+        wallet
+            .insert_payment_addr("carol".to_string(), payment_address, true)
+            .unwrap();
+        let output = keys_addresses_json(&wallet, false, false, true, None);
+        let json: serde_json::Value =
+            serde_json::from_str(&output).expect("Output should be valid JSON");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "payment_addresses": {
+                    "carol": payment_address.to_string(),
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_export_key_data_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut wallet = wallet::load_or_new(dir.path());
+        let sk = namada_sdk::key::testing::keypair_1();
+        wallet
+            .insert_keypair(
+                "key".to_string(),
+                true,
+                sk.clone(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        let file_data = export_key_data(&mut wallet, "key").unwrap();
+        let imported = common::SecretKey::try_from_slice(&file_data).unwrap();
+        assert_eq!(imported.ref_to(), sk.ref_to());
+        wallet
+            .insert_keypair(
+                "imported".to_string(),
+                true,
+                imported,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            wallet.find_secret_key("imported", None).unwrap().ref_to(),
+            sk.ref_to()
+        );
+
+        assert!(export_key_data(&mut wallet, "unknown").is_err());
+    }
+
+    #[test]
+    fn test_decode_key_file() {
+        let sk = namada_sdk::key::testing::keypair_2();
+        let pk = sk.ref_to();
+        let address = Address::Implicit((&pk).into());
+
+        // The exported binary format and the text encoding (with a trailing
+        // newline, as written by most editors) both decode to the same key
+        for file_data in [sk.serialize_to_vec(), format!("{sk}\n").into_bytes()]
+        {
+            let Some(ImportedKey::Transparent(imported)) =
+                decode_key_file(&file_data)
+            else {
+                panic!("Expected a transparent secret key");
+            };
+            assert_eq!(imported.ref_to(), pk);
+            assert_eq!(Address::Implicit((&imported.ref_to()).into()), address);
+        }
+
+        let spend_key = ExtendedSpendingKey::from(
+            masp_primitives::zip32::ExtendedSpendingKey::master(&[0; 32]),
+        );
+        for file_data in [
+            spend_key.serialize_to_vec(),
+            spend_key.to_string().into_bytes(),
+        ] {
+            let Some(ImportedKey::Shielded(imported)) =
+                decode_key_file(&file_data)
+            else {
+                panic!("Expected a spending key");
+            };
+            assert_eq!(imported, spend_key);
+        }
+
+        assert!(decode_key_file(b"not a key").is_none());
+    }
+
+    #[test]
+    fn test_derive_payment_address_from_index() {
+        let spend_key =
+            masp_primitives::zip32::ExtendedSpendingKey::master(&[0; 32]);
+        let viewing_key =
+            ExtendedViewingKey::from(ExtendedFullViewingKey::from(&spend_key));
+
+        // The same index always yields the same payment address
+        let (used_index, payment_addr) =
+            derive_payment_address(viewing_key, Some(0)).unwrap();
+        assert_eq!(
+            derive_payment_address(viewing_key, Some(0)),
+            Some((used_index, payment_addr))
+        );
+        // and the index that was used yields it again
+        let used_index = used_index.unwrap();
+        assert_eq!(
+            derive_payment_address(viewing_key, Some(used_index)),
+            Some((Some(used_index), payment_addr))
+        );
+
+        // while distant indices yield different payment addresses
+        let (distant_index, distant_addr) =
+            derive_payment_address(viewing_key, Some(1000)).unwrap();
+        assert!(distant_index.unwrap() >= 1000);
+        assert_ne!(distant_addr, payment_addr);
+
+        // No index is reported for a random diversifier
+        let (random_index, _) =
+            derive_payment_address(viewing_key, None).unwrap();
+        assert_eq!(random_index, None);
+    }
+
+    #[tokio::test]
+    async fn test_confirm_raw_key_export() {
+        assert!(confirm_raw_key_export(&InputIo("y\n"), "key_test").await);
+        for input in ["n\n", "yes please\n", "\n", ""] {
+            assert!(
+                !confirm_raw_key_export(&InputIo(input), "key_test").await
+            );
+        }
+    }
+}
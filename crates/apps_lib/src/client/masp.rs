@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::num::NonZeroUsize;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
@@ -30,6 +31,7 @@ pub async fn syncing<
     last_query_height: Option<BlockHeight>,
     sks: &[ExtendedSpendingKey],
     fvks: &[ViewingKey],
+    scan_jobs: NonZeroUsize,
 ) -> Result<ShieldedContext<U>, Error> {
     if indexer_addr.is_some() {
         display_line!(
@@ -58,6 +60,7 @@ pub async fn syncing<
                     RetryStrategy::Forever,
                     sks,
                     fvks,
+                    scan_jobs,
                 )
                 .await
                 .map(|_| shielded)
@@ -8,11 +8,17 @@ use data_encoding::HEXLOWER;
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
 use masp_primitives::sapling::Node;
-use masp_primitives::transaction::components::I128Sum;
+use masp_primitives::transaction::components::{I128Sum, ValueSum};
 use masp_primitives::zip32::ExtendedFullViewingKey;
+use namada_sdk::account::Account;
 use namada_sdk::address::{Address, InternalAddress, MASP};
 use namada_sdk::collections::{HashMap, HashSet};
 use namada_sdk::control_flow::time::{Duration, Instant};
+use namada_sdk::dec::Dec;
+use namada_sdk::eth_abi::Encode;
+use namada_sdk::eth_bridge::storage::bridge_pool as bridge_pool_storage;
+use namada_sdk::eth_bridge_pool::PendingTransfer;
+use namada_sdk::ethereum_events::Uint as EthUint;
 use namada_sdk::events::Event;
 use namada_sdk::governance::parameters::GovernanceParameters;
 use namada_sdk::governance::pgf::parameters::PgfParameters;
@@ -21,7 +27,9 @@ use namada_sdk::governance::storage::keys as governance_storage;
 use namada_sdk::governance::storage::proposal::{
     StoragePgfFunding, StorageProposal,
 };
-use namada_sdk::governance::utils::{ProposalVotes, VotePower};
+use namada_sdk::governance::utils::{
+    ProposalResult, ProposalVotes, TallyResult, Vote, VotePower,
+};
 use namada_sdk::governance::ProposalVote;
 use namada_sdk::hash::Hash;
 use namada_sdk::io::Io;
@@ -29,16 +37,18 @@ use namada_sdk::key::*;
 use namada_sdk::masp::{BalanceOwner, MaspEpoch, MaspTokenRewardData};
 use namada_sdk::parameters::{storage as param_storage, EpochDuration};
 use namada_sdk::proof_of_stake::types::{
-    CommissionPair, Slash, ValidatorMetaData, ValidatorState,
-    ValidatorStateInfo, WeightedValidator,
+    BondsAndUnbondsDetails, CommissionPair, Slash, ValidatorMetaData,
+    ValidatorState, ValidatorStateInfo, WeightedValidator,
 };
 use namada_sdk::proof_of_stake::PosParams;
-use namada_sdk::queries::{Client, RPC};
-use namada_sdk::rpc::{
-    self, enriched_bonds_and_unbonds, query_epoch, TxResponse,
+use namada_sdk::queries::vp::pos::{
+    enrich_bonds_and_unbonds, EnrichedBondsAndUnbondsDetails,
 };
-use namada_sdk::storage::{BlockHeight, BlockResults, Epoch};
+use namada_sdk::queries::{Client, RPC};
+use namada_sdk::rpc::{self, query_epoch, TxResponse};
+use namada_sdk::storage::{BlockHeight, BlockResults, Epoch, PrefixValue};
 use namada_sdk::tendermint_rpc::endpoint::status;
+use namada_sdk::time::DateTimeUtc;
 use namada_sdk::token::MaspDigitPos;
 use namada_sdk::tx::display_batch_resp;
 use namada_sdk::wallet::AddressVpType;
@@ -46,7 +56,9 @@ use namada_sdk::{
     display, display_line, edisplay_line, error, state as storage, token,
     Namada,
 };
+use serde::Serialize;
 
+use crate::cli::args::OutputFormat;
 use crate::cli::{self, args};
 use crate::facade::tendermint::merkle::proof::ProofOps;
 
@@ -71,6 +83,57 @@ pub async fn query_and_print_epoch(context: &impl Namada) -> Epoch {
     epoch
 }
 
+/// Query and print the epoch of the last committed block, then keep printing
+/// it each time it advances, until interrupted
+pub async fn query_and_watch_epoch(context: &impl Namada) {
+    let epoch = query_and_print_epoch(context).await;
+    watch_epoch(
+        context.io(),
+        &mut io::stdout(),
+        epoch,
+        Duration::from_secs(1),
+        || async { Some(query_epoch(context.client()).await) },
+    )
+    .await
+    .unwrap()
+}
+
+/// Poll the epoch at the given interval and print it each time it advances
+/// past the last printed epoch. A failed query is reported and the epoch is
+/// polled again at the next interval. Stops once the query yields nothing.
+async fn watch_epoch<F, Fut, E>(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    mut epoch: Epoch,
+    interval: Duration,
+    mut next_epoch: F,
+) -> io::Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Option<Result<Epoch, E>>>,
+    E: std::fmt::Display,
+{
+    loop {
+        tokio::time::sleep(interval).await;
+        let current_epoch = match next_epoch().await {
+            Some(Ok(current_epoch)) => current_epoch,
+            Some(Err(err)) => {
+                edisplay_line!(io, "Failed to query the epoch: {err}");
+                continue;
+            }
+            None => return Ok(()),
+        };
+        if current_epoch > epoch {
+            display_line!(
+                io,
+                &mut *w;
+                "Last committed epoch: {current_epoch}"
+            )?;
+            epoch = current_epoch;
+        }
+    }
+}
+
 /// Query and print the masp epoch of the last committed block
 pub async fn query_and_print_masp_epoch(context: &impl Namada) -> MaspEpoch {
     let epoch = rpc::query_masp_epoch(context.client()).await.unwrap();
@@ -81,28 +144,97 @@ pub async fn query_and_print_masp_epoch(context: &impl Namada) -> MaspEpoch {
 /// Query and print some information to help discern when the next epoch will
 /// begin.
 pub async fn query_and_print_next_epoch_info(context: &impl Namada) {
+    let client = context.client();
+    let epoch = query_epoch(client).await.unwrap();
     let (this_epoch_first_height, epoch_duration) =
-        rpc::query_next_epoch_info(context.client()).await.unwrap();
+        rpc::query_next_epoch_info(client).await.unwrap();
+    let this_epoch_start_time =
+        rpc::query_block_header(client, this_epoch_first_height)
+            .await
+            .unwrap()
+            .map(|header| header.time);
+    let last_block = rpc::query_block(client).await.unwrap();
 
-    display_line!(
+    let stdout = io::stdout();
+    let mut w = stdout.lock();
+    display_next_epoch_info(
         context.io(),
-        "First block height of this current epoch: {this_epoch_first_height}."
+        &mut w,
+        epoch,
+        this_epoch_first_height,
+        this_epoch_start_time,
+        &epoch_duration,
+        last_block.as_ref(),
+    )
+    .unwrap()
+}
+
+/// Print out the current epoch and an estimate of when the next one can
+/// begin, based on the epoch duration parameters and the last committed block
+fn display_next_epoch_info(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    epoch: Epoch,
+    this_epoch_first_height: BlockHeight,
+    this_epoch_start_time: Option<DateTimeUtc>,
+    epoch_duration: &EpochDuration,
+    last_block: Option<&storage::LastBlock>,
+) -> io::Result<()> {
+    let next_epoch_height = BlockHeight(
+        this_epoch_first_height.0 + epoch_duration.min_num_of_blocks,
     );
+    let next_epoch_time = this_epoch_start_time
+        .map(|start_time| start_time + epoch_duration.min_duration);
+
+    display_line!(io, &mut *w; "Last committed epoch: {epoch}.")?;
     display_line!(
-        context.io(),
+        io,
+        &mut *w;
+        "First block height of this current epoch: {this_epoch_first_height}."
+    )?;
+    display_line!(
+        io,
+        &mut *w;
         "Minimum number of blocks in an epoch: {}.",
         epoch_duration.min_num_of_blocks
-    );
+    )?;
     display_line!(
-        context.io(),
+        io,
+        &mut *w;
         "Minimum amount of time for an epoch: {} seconds.",
         epoch_duration.min_duration
-    );
+    )?;
     display_line!(
-        context.io(),
-        "\nEarliest height at which the next epoch can begin is block {}.",
-        this_epoch_first_height.0 + epoch_duration.min_num_of_blocks
-    );
+        io,
+        &mut *w;
+        "\nEarliest height at which the next epoch can begin is block \
+         {next_epoch_height}."
+    )?;
+    if let Some(next_epoch_time) = next_epoch_time {
+        display_line!(
+            io,
+            &mut *w;
+            "Earliest time at which the next epoch can begin is \
+             {next_epoch_time}."
+        )?;
+    }
+    if let Some(last_block) = last_block {
+        display_line!(
+            io,
+            &mut *w;
+            "Blocks remaining until the next epoch can begin: {}.",
+            next_epoch_height.0.saturating_sub(last_block.height.0)
+        )?;
+        if let Some(next_epoch_time) = next_epoch_time {
+            display_line!(
+                io,
+                &mut *w;
+                "Estimated time until the next epoch can begin: {} seconds.",
+                next_epoch_time - last_block.time
+            )?;
+        }
+    }
+    Ok(())
 }
 
 /// Query and print node's status.
@@ -157,6 +289,29 @@ pub async fn query_raw_bytes<N: Namada>(
     context: &N,
     args: args::QueryRawBytes,
 ) {
+    if args.prefix {
+        let response = unwrap_client_response::<N::Client, _>(
+            RPC.shell()
+                .storage_prefix(
+                    context.client(),
+                    None,
+                    None,
+                    false,
+                    &args.storage_key,
+                )
+                .await,
+        );
+        let mut w_lock = io::stdout().lock();
+        display_prefix_values(
+            context.io(),
+            &mut w_lock,
+            &args.storage_key,
+            &response.data,
+            args.decode_as,
+        )
+        .unwrap();
+        return;
+    }
     let response = unwrap_client_response::<N::Client, _>(
         RPC.shell()
             .storage_value(
@@ -171,8 +326,8 @@ pub async fn query_raw_bytes<N: Namada>(
     if !response.data.is_empty() {
         display_line!(
             context.io(),
-            "Found data: 0x{}",
-            HEXLOWER.encode(&response.data)
+            "Found data: {}",
+            format_raw_value(&response.data, args.decode_as)
         );
     } else {
         display_line!(
@@ -183,6 +338,55 @@ pub async fn query_raw_bytes<N: Namada>(
     }
 }
 
+/// Print the raw bytes of every key found under the given storage prefix.
+fn display_prefix_values(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    prefix: &storage::Key,
+    values: &[PrefixValue],
+    decode_as: Option<args::RawValueType>,
+) -> io::Result<()> {
+    if values.is_empty() {
+        return display_line!(
+            io,
+            &mut *w;
+            "No data found under prefix {prefix}"
+        );
+    }
+    for PrefixValue { key, value } in values {
+        display_line!(
+            io,
+            &mut *w;
+            "{key} => {}",
+            format_raw_value(value, decode_as)
+        )?;
+    }
+    Ok(())
+}
+
+/// Format raw storage bytes as the given type, falling back to hex when no
+/// type is given or when the bytes cannot be decoded as that type.
+fn format_raw_value(
+    value: &[u8],
+    decode_as: Option<args::RawValueType>,
+) -> String {
+    fn decode<T: BorshDeserialize + std::fmt::Display>(
+        value: &[u8],
+    ) -> Option<String> {
+        T::try_from_slice(value).ok().map(|value| value.to_string())
+    }
+
+    let decoded = decode_as.and_then(|ty| match ty {
+        args::RawValueType::Amount => decode::<token::Amount>(value),
+        args::RawValueType::Epoch => decode::<Epoch>(value),
+        args::RawValueType::Address => decode::<Address>(value),
+        args::RawValueType::U64 => decode::<u64>(value),
+        args::RawValueType::Bool => decode::<bool>(value),
+        args::RawValueType::PublicKey => decode::<common::PublicKey>(value),
+    });
+    decoded.unwrap_or_else(|| format!("0x{}", HEXLOWER.encode(value)))
+}
+
 /// Query token balance(s)
 pub async fn query_balance(context: &impl Namada, args: args::QueryBalance) {
     match &args.owner {
@@ -203,8 +407,11 @@ async fn query_transparent_balance(
     let args::QueryBalance {
         // Token owner (needs to be a transparent address)
         owner,
-        // The token to query
-        token,
+        // The tokens to query
+        tokens,
+        // The epoch at the end of which to query the balances
+        epoch,
+        output_format,
         ..
     } = args;
 
@@ -212,22 +419,204 @@ async fn query_transparent_balance(
         .address()
         .expect("Balance owner should have been a transparent address");
 
-    let token_alias = lookup_token_alias(context, &token, &owner).await;
-    let token_balance_result =
-        namada_sdk::rpc::get_token_balance(context.client(), &token, &owner)
-            .await;
+    let height = match epoch {
+        Some(epoch) => {
+            match rpc::query_last_block_height_of_epoch(context.client(), epoch)
+                .await
+            {
+                Ok(height) => Some(height),
+                Err(e) => {
+                    edisplay_line!(
+                        context.io(),
+                        "Cannot query the balance at epoch {epoch}: {e}"
+                    );
+                    cli::safe_exit(1)
+                }
+            }
+        }
+        None => None,
+    };
+
+    let mut tokens_with_aliases = vec![];
+    for token in balance_query_tokens(context, tokens).await {
+        let token_alias = lookup_token_alias(context, &token, &owner).await;
+        tokens_with_aliases.push((token, token_alias));
+    }
+    let mut w_lock = io::stdout().lock();
+    display_token_balances(
+        context.client(),
+        context.io(),
+        &mut w_lock,
+        &owner,
+        &tokens_with_aliases,
+        height,
+        output_format,
+    )
+    .await
+    .unwrap();
+}
+
+/// Query and print the balances of the given tokens, paired with their
+/// aliases, of a transparent owner at the given height or at the last
+/// committed block.
+async fn display_token_balances<C: Client + Sync>(
+    client: &C,
+    io: &impl Io,
+    w: &mut impl io::Write,
+    owner: &Address,
+    tokens: &[(Address, String)],
+    height: Option<BlockHeight>,
+    output_format: OutputFormat,
+) -> io::Result<()> {
+    for (token, token_alias) in tokens {
+        let token_balance_result = match height {
+            Some(height) => {
+                rpc::get_token_balance_at_height(client, token, owner, height)
+                    .await
+            }
+            None => rpc::get_token_balance(client, token, owner).await,
+        };
 
-    match token_balance_result {
-        Ok(balance) => {
-            let balance = context.format_amount(&token, balance).await;
-            display_line!(context.io(), "{token_alias}: {balance}");
+        match token_balance_result {
+            Ok(balance) => {
+                let balance =
+                    rpc::format_denominated_amount(client, io, token, balance)
+                        .await;
+                display_line!(
+                    io,
+                    &mut *w;
+                    "{}",
+                    format_balance(
+                        output_format,
+                        token_alias,
+                        token,
+                        &owner.to_string(),
+                        &balance,
+                    )
+                )?;
+            }
+            Err(e) => {
+                display_line!(
+                    io,
+                    &mut *w;
+                    "Error querying balance of {token_alias}: {e}"
+                )?;
+            }
         }
-        Err(e) => {
-            display_line!(
+    }
+    Ok(())
+}
+
+/// Return the given tokens, or all the tokens known to the wallet if none
+/// were given.
+async fn balance_query_tokens(
+    context: &impl Namada,
+    tokens: Vec<Address>,
+) -> Vec<Address> {
+    if tokens.is_empty() {
+        context
+            .wallet()
+            .await
+            .tokens_with_aliases()
+            .into_values()
+            .collect()
+    } else {
+        tokens
+    }
+}
+
+/// Query the total supply of a token
+pub async fn query_total_supply(
+    context: &impl Namada,
+    args: args::QueryTotalSupply,
+) -> Result<(), error::Error> {
+    let token = args.token;
+    let supply = rpc::get_token_total_supply(context.client(), &token)
+        .await
+        .map_err(|err| {
+            edisplay_line!(
+                context.io(),
+                "Failed to query the total supply of {token}: {err}"
+            );
+            err
+        })?;
+    let token_alias = lookup_token_alias(context, &token, &MASP).await;
+    let formatted_supply = rpc::format_denominated_amount(
+        context.client(),
+        context.io(),
+        &token,
+        supply,
+    )
+    .await;
+
+    // For the native token, also show the share of the supply bonded in PoS
+    let staked = if token == context.native_token() {
+        let epoch = query_epoch(context.client()).await?;
+        let staked = rpc::get_total_staked_tokens(context.client(), epoch)
+            .await
+            .map_err(|err| {
+                edisplay_line!(
+                    context.io(),
+                    "Failed to query the total staked tokens at epoch \
+                     {epoch}: {err}"
+                );
+                err
+            })?;
+        let percent = staked_percent(staked, supply);
+        if percent.is_none() {
+            edisplay_line!(
                 context.io(),
-                "Error querying balance of {token_alias}: {e}"
+                "Couldn't compute the staked share of a total supply of {}",
+                supply.to_string_native()
             );
         }
+        Some((staked.to_string_native(), percent))
+    } else {
+        None
+    };
+
+    let mut w_lock = io::stdout().lock();
+    display_total_supply(
+        context.io(),
+        &mut w_lock,
+        &token_alias,
+        &formatted_supply,
+        staked,
+    )
+    .unwrap();
+    Ok(())
+}
+
+/// The percentage of the `supply` that is `staked`, or `None` if it can't be
+/// computed, e.g. for a null supply.
+fn staked_percent(staked: token::Amount, supply: token::Amount) -> Option<Dec> {
+    let staked = Dec::try_from(staked).ok()?;
+    let supply = Dec::try_from(supply).ok()?;
+    staked.checked_div(supply)?.checked_mul(Dec::from(100_u64))
+}
+
+/// Print the total supply of a token and, if given, the amount of it staked
+/// in PoS with the percentage of the supply it represents.
+fn display_total_supply(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    token_alias: &str,
+    supply: &str,
+    staked: Option<(String, Option<Dec>)>,
+) -> io::Result<()> {
+    display_line!(io, &mut *w; "Total supply of {token_alias}: {supply}")?;
+    match staked {
+        Some((staked, Some(percent))) => {
+            display_line!(
+                io,
+                &mut *w;
+                "Staked in PoS: {staked} ({percent}% of the supply)"
+            )
+        }
+        Some((staked, None)) => {
+            display_line!(io, &mut *w; "Staked in PoS: {staked}")
+        }
+        None => Ok(()),
     }
 }
 
@@ -307,6 +696,43 @@ pub async fn query_proposal(context: &impl Namada, args: args::QueryProposal) {
                 "{}",
                 proposal.to_string_with_status(current_epoch)
             );
+            if args.votes {
+                // Voting power is not known past the current epoch yet
+                let epoch =
+                    std::cmp::min(current_epoch, proposal.voting_end_epoch);
+                let votes =
+                    namada_sdk::rpc::query_proposal_votes(context.client(), id)
+                        .await
+                        .unwrap();
+                let mut votes_with_power = Vec::with_capacity(votes.len());
+                for vote in votes {
+                    let power = if vote.is_validator() {
+                        get_validator_stake(
+                            context.client(),
+                            epoch,
+                            &vote.validator,
+                        )
+                        .await
+                    } else {
+                        get_bond_amount_at(
+                            context.client(),
+                            &vote.delegator,
+                            &vote.validator,
+                            epoch,
+                        )
+                        .await
+                    };
+                    votes_with_power.push((vote, power.unwrap_or_default()));
+                }
+                let mut w_lock = io::stdout().lock();
+                display_proposal_votes(
+                    context.io(),
+                    &mut w_lock,
+                    epoch,
+                    &votes_with_power,
+                )
+                .unwrap();
+            }
         } else {
             edisplay_line!(context.io(), "No proposal found with id: {}", id);
         }
@@ -335,6 +761,41 @@ pub async fn query_proposal(context: &impl Namada, args: args::QueryProposal) {
     }
 }
 
+/// Print the votes cast on a proposal with the voting power of each voter at
+/// the given epoch.
+fn display_proposal_votes(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    epoch: Epoch,
+    votes: &[(Vote, token::Amount)],
+) -> io::Result<()> {
+    if votes.is_empty() {
+        return display_line!(io, &mut *w; "No votes cast on the proposal.");
+    }
+    display_line!(io, &mut *w; "Votes (voting power at epoch {epoch}):")?;
+    for (vote, power) in votes {
+        display_line!(io, &mut *w; "{:4}- Voter: {}", "", vote.delegator)?;
+        if !vote.is_validator() {
+            display_line!(
+                io,
+                &mut *w;
+                "{:6}Validator: {}",
+                "",
+                vote.validator
+            )?;
+        }
+        display_line!(io, &mut *w; "{:6}Vote: {}", "", vote.data)?;
+        display_line!(
+            io,
+            &mut *w;
+            "{:6}Voting power: {}",
+            "",
+            power.to_string_native()
+        )?;
+    }
+    Ok(())
+}
+
 /// Query proposal by Id
 pub async fn query_proposal_by_id<C: namada_sdk::queries::Client + Sync>(
     client: &C,
@@ -351,57 +812,66 @@ async fn query_shielded_balance(
     let args::QueryBalance {
         // Token owner (needs to be a viewing key)
         owner,
-        // The token to query
-        token,
+        // The tokens to query
+        tokens,
         // Used to control whether conversions are automatically performed
         no_conversions,
+        epoch,
+        output_format,
         ..
     } = args;
 
-    let viewing_key = ExtendedFullViewingKey::from(
-        owner
-            .full_viewing_key()
-            .expect("Balance owner should have been a masp full viewing key"),
-    )
-    .fvk
-    .vk;
+    if epoch.is_some() {
+        edisplay_line!(
+            context.io(),
+            "Querying the balance at a past epoch is only supported for \
+             transparent addresses"
+        );
+        cli::safe_exit(1)
+    }
 
-    // Pre-compute the masp asset types of `token`
+    let full_viewing_key = owner
+        .full_viewing_key()
+        .expect("Balance owner should have been a masp full viewing key");
+    let viewing_key = ExtendedFullViewingKey::from(full_viewing_key).fvk.vk;
+    let tokens = balance_query_tokens(context, tokens).await;
+
+    // Pre-compute the masp asset types of `tokens`
     {
         let mut shielded = context.shielded_mut().await;
-        let _ = shielded.load().await;
+        if let Err(err) = shielded.load_if_saved().await {
+            edisplay_line!(
+                context.io(),
+                "Failed to load the shielded context: {err}. Run \
+                 shielded-sync to rebuild it."
+            );
+            cli::safe_exit(1)
+        }
         let _ = shielded
-            .precompute_asset_types(context.client(), vec![&token])
+            .precompute_asset_types(context.client(), tokens.iter().collect())
             .await;
         // Save the update state so that future fetches can be short-circuited
         let _ = shielded.save().await;
     }
 
     // The epoch is required to identify timestamped tokens
-    let masp_epoch = query_and_print_masp_epoch(context).await;
-
-    // Query the token alias in the wallet for pretty printing token balances
-    let token_alias = lookup_token_alias(context, &token, &MASP).await;
+    let masp_epoch = match output_format {
+        OutputFormat::Text => query_and_print_masp_epoch(context).await,
+        OutputFormat::Json => {
+            rpc::query_masp_epoch(context.client()).await.unwrap()
+        }
+    };
 
     // Query the multi-asset balance at the given spending key
     let mut shielded = context.shielded_mut().await;
 
-    let no_balance = || {
-        display_line!(context.io(), "{token_alias}: 0");
-    };
-
     let balance = if no_conversions {
-        let Some(bal) = shielded
+        shielded
             .compute_shielded_balance(&viewing_key)
             .await
             .unwrap()
-        else {
-            no_balance();
-            return;
-        };
-        bal
     } else {
-        let Some(bal) = shielded
+        shielded
             .compute_exchanged_balance(
                 context.client(),
                 context.io(),
@@ -410,31 +880,77 @@ async fn query_shielded_balance(
             )
             .await
             .unwrap()
-        else {
-            no_balance();
-            return;
-        };
-        bal
     };
 
-    let total_balance = shielded
-        .decode_combine_sum_to_epoch(context.client(), balance, masp_epoch)
-        .await
-        .0
-        .get(&token);
+    let total_balances = match balance {
+        Some(balance) => {
+            shielded
+                .decode_combine_sum_to_epoch(
+                    context.client(),
+                    balance,
+                    masp_epoch,
+                )
+                .await
+                .0
+        }
+        None => ValueSum::zero(),
+    };
 
-    if total_balance.is_zero() {
-        no_balance();
-    } else {
+    let owner = full_viewing_key.to_string();
+    for token in tokens {
+        // Query the token alias in the wallet for pretty printing token
+        // balances
+        let token_alias = lookup_token_alias(context, &token, &MASP).await;
+        let total_balance = total_balances.get(&token);
+        let balance = if total_balance.is_zero() {
+            "0".to_string()
+        } else {
+            context.format_amount(&token, total_balance.into()).await
+        };
         display_line!(
             context.io(),
-            "{}: {}",
-            token_alias,
-            context.format_amount(&token, total_balance.into()).await
+            "{}",
+            format_balance(
+                output_format,
+                &token_alias,
+                &token,
+                &owner,
+                &balance,
+            )
         );
     }
 }
 
+/// A token balance, as printed in the JSON output format
+#[derive(Serialize)]
+struct BalanceOutput<'a> {
+    token: String,
+    owner: &'a str,
+    amount: &'a str,
+}
+
+/// Format the balance of a token in the given output format.
+fn format_balance(
+    output_format: OutputFormat,
+    token_alias: &str,
+    token: &Address,
+    owner: &str,
+    balance: &str,
+) -> String {
+    match output_format {
+        OutputFormat::Text => format!("{token_alias}: {balance}"),
+        OutputFormat::Json => {
+            let output = BalanceOutput {
+                token: token.to_string(),
+                owner,
+                amount: balance,
+            };
+            serde_json::to_string(&output)
+                .expect("Serializing a balance should not fail")
+        }
+    }
+}
+
 pub async fn query_proposal_result(
     context: &impl Namada,
     args: args::QueryProposalResult,
@@ -453,46 +969,95 @@ pub async fn query_proposal_result(
         (proposal_result, proposal_query)
     {
         display_line!(context.io(), "Proposal Id: {} ", proposal_id);
-        if current_epoch >= proposal_query.voting_end_epoch {
-            display_line!(context.io(), "{:4}{}", "", proposal_result);
-        } else {
-            display_line!(
-                context.io(),
-                "{:4}Still voting until epoch {} begins.",
-                "",
-                proposal_query.voting_end_epoch
-            );
-            let res = format!("{}", proposal_result);
-            if let Some(idx) = res.find(' ') {
-                let slice = &res[idx..];
-                display_line!(context.io(), "{:4}Currently{}", "", slice);
-            } else {
-                display_line!(
-                    context.io(),
-                    "{:4}Error parsing the result string",
-                    "",
-                );
-            }
-        }
+        let mut w_lock = io::stdout().lock();
+        display_proposal_result(
+            context.io(),
+            &mut w_lock,
+            current_epoch,
+            proposal_query.voting_end_epoch,
+            &proposal_result,
+        )
+        .unwrap();
     } else {
         edisplay_line!(context.io(), "Proposal {} not found.", proposal_id);
     }
 }
 
+/// Print the result of a proposal. While the voting is still ongoing, the
+/// result is projected from the votes cast so far.
+fn display_proposal_result(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    current_epoch: Epoch,
+    voting_end_epoch: Epoch,
+    result: &ProposalResult,
+) -> io::Result<()> {
+    if current_epoch >= voting_end_epoch {
+        return display_line!(io, &mut *w; "{:4}{}", "", result);
+    }
+    display_line!(
+        io,
+        &mut *w;
+        "{:4}Still voting until epoch {} begins.",
+        "",
+        voting_end_epoch
+    )?;
+    // Drop the leading tally result from the rendered result
+    let result_str = result.to_string();
+    let votes = result_str
+        .split_once(' ')
+        .map(|(_, votes)| votes)
+        .unwrap_or_default();
+    display_line!(io, &mut *w; "{:4}Currently {}", "", votes)?;
+    let projection = match result.result {
+        TallyResult::Passed => "pass",
+        TallyResult::Rejected => "be rejected",
+    };
+    display_line!(
+        io,
+        &mut *w;
+        "{:4}The proposal would {} if voting ended now.",
+        "",
+        projection
+    )
+}
+
 pub async fn query_account(context: &impl Namada, args: args::QueryAccount) {
     let account = rpc::get_account_info(context.client(), &args.owner)
         .await
         .unwrap();
-    if let Some(account) = account {
-        display_line!(context.io(), "Address: {}", account.address);
-        display_line!(context.io(), "Threshold: {}", account.threshold);
-        display_line!(context.io(), "Public keys:");
-        for (public_key, _) in account.public_keys_map.pk_to_idx {
-            display_line!(context.io(), "- {}", public_key);
-        }
-    } else {
-        display_line!(context.io(), "No account exists for {}", args.owner);
+    let vp_code_hash = rpc::get_vp_code_hash(context.client(), &args.owner)
+        .await
+        .unwrap();
+
+    let stdout = io::stdout();
+    let mut w = stdout.lock();
+    display_account(context.io(), &mut w, &args.owner, account, vp_code_hash)
+        .unwrap()
+}
+
+/// Print out an account's validity predicate code hash, signature threshold
+/// and revealed public keys
+fn display_account(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    owner: &Address,
+    account: Option<Account>,
+    vp_code_hash: Option<Hash>,
+) -> io::Result<()> {
+    let Some(account) = account else {
+        return display_line!(io, &mut *w; "No account exists for {owner}");
+    };
+    display_line!(io, &mut *w; "Address: {}", account.address)?;
+    if let Some(vp_code_hash) = vp_code_hash {
+        display_line!(io, &mut *w; "VP code hash: {vp_code_hash}")?;
     }
+    display_line!(io, &mut *w; "Threshold: {}", account.threshold)?;
+    display_line!(io, &mut *w; "Public keys:")?;
+    for (public_key, _) in account.public_keys_map.pk_to_idx {
+        display_line!(io, &mut *w; "- {}", public_key)?;
+    }
+    Ok(())
 }
 
 pub async fn query_pgf(context: &impl Namada, _args: args::QueryPgf) {
@@ -546,6 +1111,75 @@ pub async fn query_pgf(context: &impl Namada, _args: args::QueryPgf) {
     }
 }
 
+/// Query the transfers pending in the Ethereum bridge pool and print them,
+/// along with the current nonce of the pool.
+pub async fn query_eth_bridge_pool(
+    context: &impl Namada,
+    _args: args::QueryEthBridgePool,
+) {
+    let nonce: EthUint = rpc::query_storage_value(
+        context.client(),
+        &bridge_pool_storage::get_nonce_key(),
+    )
+    .await
+    .unwrap_or_else(|err| {
+        edisplay_line!(
+            context.io(),
+            "Failed to query the Ethereum bridge pool nonce: {err}"
+        );
+        cli::safe_exit(1)
+    });
+    let transfers = unwrap_client_response::<_, Vec<PendingTransfer>>(
+        RPC.shell()
+            .eth_bridge()
+            .read_ethereum_bridge_pool(context.client())
+            .await,
+    );
+    let mut w_lock = io::stdout().lock();
+    display_eth_bridge_pool(context.io(), &mut w_lock, nonce, &transfers)
+        .unwrap();
+}
+
+/// Print the transfers pending in the Ethereum bridge pool.
+fn display_eth_bridge_pool(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    nonce: EthUint,
+    transfers: &[PendingTransfer],
+) -> io::Result<()> {
+    display_line!(io, &mut *w; "Ethereum bridge pool nonce: {nonce}")?;
+    if transfers.is_empty() {
+        return display_line!(io, &mut *w; "Ethereum bridge pool is empty.");
+    }
+    display_line!(io, &mut *w; "Pending transfers:")?;
+    for pending in transfers {
+        let transfer = &pending.transfer;
+        let gas_fee = &pending.gas_fee;
+        display_line!(io, &mut *w; "{:4}- {}", "", pending.keccak256())?;
+        display_line!(io, &mut *w; "{:6}Kind: {}", "", transfer.kind)?;
+        display_line!(io, &mut *w; "{:6}Sender: {}", "", transfer.sender)?;
+        display_line!(
+            io,
+            &mut *w;
+            "{:6}Recipient: {}",
+            "",
+            transfer.recipient
+        )?;
+        display_line!(io, &mut *w; "{:6}Asset: {}", "", transfer.asset)?;
+        display_line!(io, &mut *w; "{:6}Amount: {}", "", transfer.amount)?;
+        display_line!(
+            io,
+            &mut *w;
+            "{:6}Gas fee: {} of {} paid by {}",
+            "",
+            gas_fee.amount,
+            gas_fee.token,
+            gas_fee.payer
+        )?;
+    }
+    Ok(())
+}
+
 pub async fn query_protocol_parameters(
     context: &impl Namada,
     _args: args::QueryProtocolParameters,
@@ -891,7 +1525,10 @@ pub async fn query_bonds(
     context: &impl Namada,
     args: args::QueryBonds,
 ) -> std::io::Result<()> {
-    let epoch = query_and_print_epoch(context).await;
+    let epoch = match args.output_format {
+        OutputFormat::Text => query_and_print_epoch(context).await,
+        OutputFormat::Json => query_epoch(context.client()).await.unwrap(),
+    };
 
     let source = args.owner;
     let validator = args.validator;
@@ -899,15 +1536,59 @@ pub async fn query_bonds(
     let stdout = io::stdout();
     let mut w = stdout.lock();
 
-    let bonds_and_unbonds = enriched_bonds_and_unbonds(
-        context.client(),
-        epoch,
-        &source,
-        &validator,
-    )
-    .await
-    .unwrap();
+    let bonds_and_unbonds =
+        rpc::bonds_and_unbonds(context.client(), &source, &validator)
+            .await
+            .unwrap();
+    let bonds_and_unbonds = filter_bonds_by_epoch(
+        bonds_and_unbonds,
+        args.from_epoch,
+        args.to_epoch,
+    );
+    let bonds_and_unbonds =
+        enrich_bonds_and_unbonds(epoch, bonds_and_unbonds).unwrap();
+
+    if args.output_format == OutputFormat::Json {
+        let output = BondsOutput::new(epoch, &bonds_and_unbonds);
+        return display_line!(
+            context.io(),
+            &mut w;
+            "{}",
+            serde_json::to_string(&output).map_err(io::Error::from)?
+        );
+    }
+
+    display_bonds(context.io(), &mut w, &bonds_and_unbonds)
+}
 
+/// Only keep the bonds that started and the unbonds that become withdrawable
+/// within the given epoch range. Bond ids left with neither are dropped.
+fn filter_bonds_by_epoch(
+    bonds_and_unbonds: BondsAndUnbondsDetails,
+    from_epoch: Option<Epoch>,
+    to_epoch: Option<Epoch>,
+) -> BondsAndUnbondsDetails {
+    let in_range = |epoch: Epoch| {
+        from_epoch.map_or(true, |from| epoch >= from)
+            && to_epoch.map_or(true, |to| epoch <= to)
+    };
+    bonds_and_unbonds
+        .into_iter()
+        .filter_map(|(bond_id, mut details)| {
+            details.bonds.retain(|bond| in_range(bond.start));
+            details.unbonds.retain(|unbond| in_range(unbond.withdraw));
+            (!details.bonds.is_empty() || !details.unbonds.is_empty())
+                .then_some((bond_id, details))
+        })
+        .collect()
+}
+
+/// Print out the given bonds and unbonds with their totals
+fn display_bonds(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    bonds_and_unbonds: &EnrichedBondsAndUnbondsDetails,
+) -> io::Result<()> {
     for (bond_id, details) in &bonds_and_unbonds.data {
         let bond_type = if bond_id.source == bond_id.validator {
             format!("Self-bonds from {}", bond_id.validator)
@@ -917,11 +1598,11 @@ pub async fn query_bonds(
                 bond_id.source, bond_id.validator
             )
         };
-        display_line!(context.io(), &mut w; "{}:", bond_type)?;
+        display_line!(io, &mut *w; "{}:", bond_type)?;
         for bond in &details.data.bonds {
             display_line!(
-                context.io(),
-                &mut w;
+                io,
+                &mut *w;
                 "  Remaining active bond from epoch {}: Δ {} (slashed {})",
                 bond.start,
                 bond.amount.to_string_native(),
@@ -930,14 +1611,14 @@ pub async fn query_bonds(
         }
         if !details.bonds_total.is_zero() {
             display_line!(
-                context.io(),
-                &mut w;
+                io,
+                &mut *w;
                 "Active (slashable) bonds total: {}",
                 details.bonds_total_active().unwrap().to_string_native()
             )?;
         }
-        display_line!(context.io(), &mut w; "Bonds total: {}", details.bonds_total.to_string_native())?;
-        display_line!(context.io(), &mut w; "")?;
+        display_line!(io, &mut *w; "Bonds total: {}", details.bonds_total.to_string_native())?;
+        display_line!(io, &mut *w; "")?;
 
         if !details.data.unbonds.is_empty() {
             let bond_type = if bond_id.source == bond_id.validator {
@@ -945,11 +1626,11 @@ pub async fn query_bonds(
             } else {
                 format!("Unbonded delegations from {}", bond_id.source)
             };
-            display_line!(context.io(), &mut w; "{}:", bond_type)?;
+            display_line!(io, &mut *w; "{}:", bond_type)?;
             for unbond in &details.data.unbonds {
                 display_line!(
-                    context.io(),
-                    &mut w;
+                    io,
+                    &mut *w;
                     "  Withdrawable from epoch {} (active from {}): Δ {} (slashed {})",
                     unbond.withdraw,
                     unbond.start,
@@ -958,37 +1639,37 @@ pub async fn query_bonds(
                 )?;
             }
             display_line!(
-                context.io(),
-                &mut w;
+                io,
+                &mut *w;
                 "Unbonded total: {}",
                 details.unbonds_total.to_string_native()
             )?;
         }
         display_line!(
-            context.io(),
-            &mut w;
+            io,
+            &mut *w;
             "Withdrawable total: {}",
             details.total_withdrawable.to_string_native()
         )?;
-        display_line!(context.io(), &mut w; "")?;
+        display_line!(io, &mut *w; "")?;
     }
     if bonds_and_unbonds.bonds_total != bonds_and_unbonds.bonds_total_slashed {
         display_line!(
-            context.io(),
-            &mut w;
+            io,
+            &mut *w;
             "All bonds total active: {}",
             bonds_and_unbonds.bonds_total_active().unwrap().to_string_native()
         )?;
     }
     display_line!(
-        context.io(),
-        &mut w;
+        io,
+        &mut *w;
         "All bonds total: {}",
         bonds_and_unbonds.bonds_total.to_string_native()
     )?;
     display_line!(
-        context.io(),
-        &mut w;
+        io,
+        &mut *w;
         "All bonds total slashed: {}",
         bonds_and_unbonds.bonds_total_slashed.to_string_native()
     )?;
@@ -997,33 +1678,136 @@ pub async fn query_bonds(
         != bonds_and_unbonds.unbonds_total_slashed
     {
         display_line!(
-            context.io(),
-            &mut w;
+            io,
+            &mut *w;
             "All unbonds total active: {}",
             bonds_and_unbonds.unbonds_total_active().unwrap().to_string_native()
         )?;
     }
     display_line!(
-        context.io(),
-        &mut w;
+        io,
+        &mut *w;
         "All unbonds total: {}",
         bonds_and_unbonds.unbonds_total.to_string_native()
     )?;
     display_line!(
-        context.io(),
-        &mut w;
+        io,
+        &mut *w;
         "All unbonds total withdrawable: {}",
         bonds_and_unbonds.total_withdrawable.to_string_native()
     )?;
     display_line!(
-        context.io(),
-        &mut w;
+        io,
+        &mut *w;
         "All unbonds total slashed: {}",
         bonds_and_unbonds.unbonds_total_slashed.to_string_native()
     )?;
     Ok(())
 }
 
+/// Bonds and unbonds, as printed in the JSON output format
+#[derive(Serialize)]
+struct BondsOutput {
+    epoch: u64,
+    bonds: Vec<BondOutput>,
+    bonds_total: String,
+    bonds_total_slashed: String,
+    unbonds_total: String,
+    unbonds_total_slashed: String,
+    total_withdrawable: String,
+}
+
+/// The bonds and unbonds of a single bond id, as printed in the JSON output
+/// format
+#[derive(Serialize)]
+struct BondOutput {
+    source: String,
+    validator: String,
+    bonds: Vec<BondAmountOutput>,
+    unbonds: Vec<UnbondAmountOutput>,
+    bonds_total: String,
+    unbonds_total: String,
+    total_withdrawable: String,
+}
+
+#[derive(Serialize)]
+struct BondAmountOutput {
+    start: u64,
+    amount: String,
+    slashed: String,
+}
+
+#[derive(Serialize)]
+struct UnbondAmountOutput {
+    start: u64,
+    withdraw: u64,
+    amount: String,
+    slashed: String,
+}
+
+impl BondsOutput {
+    fn new(
+        epoch: Epoch,
+        bonds_and_unbonds: &EnrichedBondsAndUnbondsDetails,
+    ) -> Self {
+        let bonds = bonds_and_unbonds
+            .data
+            .iter()
+            .map(|(bond_id, details)| BondOutput {
+                source: bond_id.source.to_string(),
+                validator: bond_id.validator.to_string(),
+                bonds: details
+                    .data
+                    .bonds
+                    .iter()
+                    .map(|bond| BondAmountOutput {
+                        start: bond.start.0,
+                        amount: bond.amount.to_string_native(),
+                        slashed: bond
+                            .slashed_amount
+                            .unwrap_or_default()
+                            .to_string_native(),
+                    })
+                    .collect(),
+                unbonds: details
+                    .data
+                    .unbonds
+                    .iter()
+                    .map(|unbond| UnbondAmountOutput {
+                        start: unbond.start.0,
+                        withdraw: unbond.withdraw.0,
+                        amount: unbond.amount.to_string_native(),
+                        slashed: unbond
+                            .slashed_amount
+                            .unwrap_or_default()
+                            .to_string_native(),
+                    })
+                    .collect(),
+                bonds_total: details.bonds_total.to_string_native(),
+                unbonds_total: details.unbonds_total.to_string_native(),
+                total_withdrawable: details
+                    .total_withdrawable
+                    .to_string_native(),
+            })
+            .collect();
+        Self {
+            epoch: epoch.0,
+            bonds,
+            bonds_total: bonds_and_unbonds.bonds_total.to_string_native(),
+            bonds_total_slashed: bonds_and_unbonds
+                .bonds_total_slashed
+                .to_string_native(),
+            unbonds_total: bonds_and_unbonds.unbonds_total.to_string_native(),
+            unbonds_total_slashed: bonds_and_unbonds
+                .unbonds_total_slashed
+                .to_string_native(),
+            total_withdrawable: bonds_and_unbonds
+                .total_withdrawable
+                .to_string_native(),
+        }
+    }
+}
+
 /// Query PoS bonded stake
 pub async fn query_bonded_stake<N: Namada>(
     context: &N,
@@ -1089,7 +1873,7 @@ pub async fn query_bonded_stake<N: Namada>(
 
             display_line!(context.io(), &mut w; "Consensus validators:")
                 .unwrap();
-            for val in consensus.into_iter().rev() {
+            for val in sort_validators(consensus, args.sort, args.desc) {
                 display_line!(
                     context.io(),
                     &mut w;
@@ -1102,7 +1886,9 @@ pub async fn query_bonded_stake<N: Namada>(
             if !below_capacity.is_empty() {
                 display_line!(context.io(), &mut w; "Below capacity validators:")
                     .unwrap();
-                for val in below_capacity.into_iter().rev() {
+                for val in
+                    sort_validators(below_capacity, args.sort, args.desc)
+                {
                     display_line!(
                         context.io(),
                         &mut w;
@@ -1125,6 +1911,31 @@ pub async fn query_bonded_stake<N: Namada>(
     );
 }
 
+/// Order the given validators for display. Without a sort key, they are listed
+/// by descending bonded stake.
+fn sort_validators(
+    validators: BTreeSet<WeightedValidator>,
+    sort: Option<args::ValidatorSortBy>,
+    desc: bool,
+) -> Vec<WeightedValidator> {
+    // The set is already ordered by stake, with ties ordered by address
+    let mut validators: Vec<_> = validators.into_iter().collect();
+    match sort {
+        None => {
+            validators.reverse();
+            return validators;
+        }
+        Some(args::ValidatorSortBy::Stake) => {}
+        Some(args::ValidatorSortBy::Address) => {
+            validators.sort_by_cached_key(|val| val.address.encode());
+        }
+    }
+    if desc {
+        validators.reverse();
+    }
+    validators
+}
+
 /// Query and return validator's commission rate and max commission rate change
 /// per epoch
 pub async fn query_commission_rate<C: namada_sdk::queries::Client + Sync>(
@@ -1184,44 +1995,64 @@ pub async fn query_and_print_validator_state(
     let (state, epoch): ValidatorStateInfo =
         query_validator_state(context.client(), &validator, args.epoch).await;
 
+    let stdout = io::stdout();
+    let mut w = stdout.lock();
+    display_validator_state(context.io(), &mut w, &validator, state, epoch)
+        .unwrap()
+}
+
+/// Print out the state of a validator in the given epoch
+fn display_validator_state(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    validator: &Address,
+    state: Option<ValidatorState>,
+    epoch: Epoch,
+) -> io::Result<()> {
     match state {
         Some(state) => match state {
             ValidatorState::Consensus => {
                 display_line!(
-                    context.io(),
+                    io,
+                    &mut *w;
                     "Validator {validator} is in the consensus set in epoch \
                      {epoch}"
                 )
             }
             ValidatorState::BelowCapacity => {
                 display_line!(
-                    context.io(),
+                    io,
+                    &mut *w;
                     "Validator {validator} is in the below-capacity set in \
                      epoch {epoch}"
                 )
             }
             ValidatorState::BelowThreshold => {
                 display_line!(
-                    context.io(),
+                    io,
+                    &mut *w;
                     "Validator {validator} is in the below-threshold set in \
                      epoch {epoch}"
                 )
             }
             ValidatorState::Inactive => {
                 display_line!(
-                    context.io(),
+                    io,
+                    &mut *w;
                     "Validator {validator} is inactive in epoch {epoch}"
                 )
             }
             ValidatorState::Jailed => {
                 display_line!(
-                    context.io(),
+                    io,
+                    &mut *w;
                     "Validator {validator} is jailed in epoch {epoch}"
                 )
             }
         },
         None => display_line!(
-            context.io(),
+            io,
+            &mut *w;
             "Validator {validator} not found in epoch {epoch}. This account \
              may not be a validator, or the validator account has been \
              recently initialized and may not be active yet. It is also \
@@ -1238,6 +2069,35 @@ pub async fn query_and_print_commission_rate(
 ) {
     let validator = args.validator;
 
+    if let Some(from_epoch) = args.from_epoch {
+        let to_epoch = match args.to_epoch {
+            Some(epoch) => epoch,
+            None => query_epoch(context.client()).await.unwrap(),
+        };
+        if let Err(err) =
+            check_commission_rate_history_range(from_epoch, to_epoch)
+        {
+            edisplay_line!(context.io(), "{err}");
+            cli::safe_exit(1)
+        }
+        let mut history = vec![];
+        for epoch in Epoch::iter_bounds_inclusive(from_epoch, to_epoch) {
+            history.push(
+                query_commission_rate(context.client(), &validator, Some(epoch))
+                    .await,
+            );
+        }
+        let mut w_lock = io::stdout().lock();
+        display_commission_rate_history(
+            context.io(),
+            &mut w_lock,
+            &validator,
+            &history,
+        )
+        .unwrap();
+        return;
+    }
+
     let CommissionPair {
         commission_rate,
         max_commission_change_per_epoch,
@@ -1269,6 +2129,68 @@ pub async fn query_and_print_commission_rate(
     }
 }
 
+/// The maximum number of epochs a commission rate history can span. Each
+/// epoch of the history is a separate query.
+const MAX_COMMISSION_RATE_HISTORY_EPOCHS: u64 = 100;
+
+/// Check that a commission rate history range is non-empty and that it
+/// doesn't span more than [`MAX_COMMISSION_RATE_HISTORY_EPOCHS`] epochs
+fn check_commission_rate_history_range(
+    from_epoch: Epoch,
+    to_epoch: Epoch,
+) -> Result<(), String> {
+    if from_epoch > to_epoch {
+        return Err(format!(
+            "The first epoch of the commission rate history ({from_epoch}) \
+             is after its last epoch ({to_epoch})."
+        ));
+    }
+    if to_epoch.0 - from_epoch.0 >= MAX_COMMISSION_RATE_HISTORY_EPOCHS {
+        return Err(format!(
+            "The commission rate history from epoch {from_epoch} to epoch \
+             {to_epoch} spans more than {MAX_COMMISSION_RATE_HISTORY_EPOCHS} \
+             epochs. Use `--to-epoch` to query a shorter range."
+        ));
+    }
+    Ok(())
+}
+
+/// Print the commission rate of a validator in each of the queried epochs
+fn display_commission_rate_history(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    validator: &Address,
+    history: &[CommissionPair],
+) -> io::Result<()> {
+    display_line!(
+        io,
+        &mut *w;
+        "Validator {validator} commission rate history:"
+    )?;
+    display_line!(
+        io,
+        &mut *w;
+        "  Epoch | Commission rate | Max change per epoch"
+    )?;
+    for commission in history {
+        let epoch = commission.epoch;
+        match (
+            commission.commission_rate,
+            commission.max_commission_change_per_epoch,
+        ) {
+            (Some(rate), Some(max_change)) => {
+                display_line!(io, &mut *w; "  {epoch} | {rate} | {max_change}")?
+            }
+            _ => display_line!(
+                io,
+                &mut *w;
+                "  {epoch} | not found | not found"
+            )?,
+        }
+    }
+    Ok(())
+}
+
 /// Query PoS validator's metadata
 pub async fn query_and_print_metadata(
     context: &impl Namada,
@@ -1365,7 +2287,7 @@ pub async fn query_and_print_metadata(
 
 /// Query PoS slashes
 pub async fn query_slashes<N: Namada>(context: &N, args: args::QuerySlashes) {
-    match args.validator {
+    match args.validator.clone() {
         Some(validator) => {
             // Find slashes for the given validator
             let slashes: Vec<Slash> = unwrap_client_response::<N::Client, _>(
@@ -1513,6 +2435,90 @@ pub async fn query_slashes<N: Namada>(context: &N, args: args::QuerySlashes) {
             }
         }
     }
+
+    if let Some(owner) = args.owner {
+        let epoch = query_epoch(context.client()).await.unwrap();
+        let bonds_and_unbonds = rpc::bonds_and_unbonds(
+            context.client(),
+            &Some(owner.clone()),
+            &args.validator,
+        )
+        .await
+        .unwrap();
+        let bonds_and_unbonds =
+            enrich_bonds_and_unbonds(epoch, bonds_and_unbonds).unwrap();
+        let mut w_lock = io::stdout().lock();
+        display_slashed_amounts(
+            context.io(),
+            &mut w_lock,
+            &owner,
+            &bonds_and_unbonds,
+        )
+        .unwrap();
+    }
+}
+
+/// Print the slashes applied to the bonds of `owner` with the amounts that
+/// its bonds and unbonds lost to them, as computed by PoS
+fn display_slashed_amounts(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    owner: &Address,
+    bonds_and_unbonds: &EnrichedBondsAndUnbondsDetails,
+) -> io::Result<()> {
+    let mut slashed: Vec<_> = bonds_and_unbonds
+        .data
+        .iter()
+        .filter(|(_, details)| !details.data.slashes.is_empty())
+        .collect();
+    if slashed.is_empty() {
+        return display_line!(
+            io,
+            &mut *w;
+            "\nNo slashes affect the bonds of {owner}"
+        );
+    }
+    slashed.sort_by_key(|(bond_id, _)| *bond_id);
+
+    display_line!(io, &mut *w; "\nSlashed amounts for {owner}:")?;
+    for (bond_id, details) in slashed {
+        display_line!(io, &mut *w; "  Validator {}:", bond_id.validator)?;
+        for slash in &details.data.slashes {
+            display_line!(
+                io,
+                &mut *w;
+                "    Slash at infraction epoch {}, rate {}",
+                slash.epoch,
+                slash.rate
+            )?;
+        }
+        display_line!(
+            io,
+            &mut *w;
+            "    Bonds slashed: {} of {}",
+            details.bonds_total_slashed.to_string_native(),
+            details.bonds_total.to_string_native()
+        )?;
+        display_line!(
+            io,
+            &mut *w;
+            "    Unbonds slashed: {} of {}",
+            details.unbonds_total_slashed.to_string_native(),
+            details.unbonds_total.to_string_native()
+        )?;
+    }
+    display_line!(
+        io,
+        &mut *w;
+        "All bonds total slashed: {}",
+        bonds_and_unbonds.bonds_total_slashed.to_string_native()
+    )?;
+    display_line!(
+        io,
+        &mut *w;
+        "All unbonds total slashed: {}",
+        bonds_and_unbonds.unbonds_total_slashed.to_string_native()
+    )
 }
 
 pub async fn query_and_print_rewards<N: Namada>(
@@ -1600,27 +2606,48 @@ pub async fn query_find_validator<N: Namada>(
         }
     }
     if let Some(validator_addr) = validator_addr {
-        if let Some(consensus_key) = unwrap_client_response::<N::Client, _>(
+        let consensus_key = unwrap_client_response::<N::Client, _>(
             RPC.vp()
                 .pos()
                 .consensus_key(context.client(), &validator_addr)
                 .await,
-        ) {
-            let pkh: PublicKeyHash = (&consensus_key).into();
-            display_line!(context.io(), "Consensus key: {consensus_key}");
+        );
+        let mut w_lock = io::stdout().lock();
+        display_validator_consensus_key(
+            context.io(),
+            &mut w_lock,
+            &validator_addr,
+            consensus_key.as_ref(),
+        )
+        .unwrap();
+    }
+}
+
+/// Print the consensus key of a validator, together with the Tendermint
+/// address and the hash derived from it.
+fn display_validator_consensus_key(
+    io: &impl Io,
+    w: &mut impl io::Write,
+    validator_addr: &Address,
+    consensus_key: Option<&common::PublicKey>,
+) -> io::Result<()> {
+    match consensus_key {
+        Some(consensus_key) => {
+            let pkh: PublicKeyHash = consensus_key.into();
+            display_line!(io, &mut *w; "Consensus key: {consensus_key}")?;
             display_line!(
-                context.io(),
+                io,
+                &mut *w;
                 "Tendermint key: {}",
-                tm_consensus_key_raw_hash(&consensus_key)
-            );
-            display_line!(context.io(), "Consensus key hash: {}", pkh);
-        } else {
-            display_line!(
-                context.io(),
-                "Consensus key for validator {validator_addr} could not be \
-                 found."
-            )
+                tm_consensus_key_raw_hash(consensus_key)
+            )?;
+            display_line!(io, &mut *w; "Consensus key hash: {}", pkh)
         }
+        None => display_line!(
+            io,
+            &mut *w;
+            "Consensus key for validator {validator_addr} could not be found."
+        ),
     }
 }
 
@@ -1691,6 +2718,35 @@ pub async fn query_conversions(
     // The chosen token type of the conversions
     let target_token = args.token;
 
+    if args.output_format == OutputFormat::Json {
+        let total_rewards = if target_token.is_none() {
+            let total_rewards = rpc::query_masp_total_rewards(context.client())
+                .await
+                .expect("MASP total rewards should be present");
+            Some(total_rewards.to_string_native())
+        } else {
+            None
+        };
+        let conversions = rpc::query_conversions(context.client())
+            .await
+            .expect("Conversions should be defined");
+        let output = ConversionsOutput {
+            total_rewards,
+            conversions: conversions_output(
+                &conversions,
+                target_token.as_ref(),
+                args.epoch.as_ref(),
+            ),
+        };
+        display_line!(
+            context.io(),
+            "{}",
+            serde_json::to_string(&output)
+                .expect("Conversions should be serializable")
+        );
+        return;
+    }
+
     if target_token.as_ref().is_none() {
         // Query and print the total rewards first
         let total_rewards = rpc::query_masp_total_rewards(context.client())
@@ -1761,6 +2817,92 @@ pub async fn query_conversions(
     }
 }
 
+/// The conversions of the MASP, keyed by the asset type they apply to
+type Conversions = BTreeMap<
+    AssetType,
+    (
+        Address,
+        token::Denomination,
+        MaspDigitPos,
+        MaspEpoch,
+        I128Sum,
+    ),
+>;
+
+/// The conversions of the MASP, as printed in the JSON output format
+#[derive(Serialize)]
+struct ConversionsOutput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_rewards: Option<String>,
+    conversions: Vec<ConversionOutput>,
+}
+
+/// A conversion, as printed in the JSON output format. The amounts of its
+/// components sum up to zero.
+#[derive(Serialize)]
+struct ConversionOutput {
+    #[serde(flatten)]
+    asset: AssetOutput,
+    components: Vec<ConversionComponentOutput>,
+}
+
+/// A component of a conversion, as printed in the JSON output format
+#[derive(Serialize)]
+struct ConversionComponentOutput {
+    #[serde(flatten)]
+    asset: AssetOutput,
+    /// A string, as it may not fit in the numbers of some JSON parsers
+    amount: String,
+}
+
+/// A MASP asset type and what it stands for
+#[derive(Serialize)]
+struct AssetOutput {
+    asset_type: String,
+    token: String,
+    digit: u8,
+    epoch: MaspEpoch,
+}
+
+impl AssetOutput {
+    fn new(asset_type: &AssetType, conversions: &Conversions) -> Self {
+        let (token, _denom, digit, epoch, _) = &conversions[asset_type];
+        Self {
+            asset_type: asset_type.to_string(),
+            token: token.to_string(),
+            digit: *digit as u8,
+            epoch: *epoch,
+        }
+    }
+}
+
+/// Collect the non-sentinel conversions of the given token and epoch, or of
+/// all of them if not given, for the JSON output format
+fn conversions_output(
+    conversions: &Conversions,
+    target_token: Option<&Address>,
+    target_epoch: Option<&MaspEpoch>,
+) -> Vec<ConversionOutput> {
+    conversions
+        .iter()
+        .filter(|(_, (addr, _denom, _digit, epoch, amt))| {
+            target_token.map_or(true, |target| target == addr)
+                && target_epoch.map_or(true, |target| target == epoch)
+                && !amt.is_zero()
+        })
+        .map(|(asset_type, (_, _, _, _, amt))| ConversionOutput {
+            asset: AssetOutput::new(asset_type, conversions),
+            components: amt
+                .components()
+                .map(|(component, val)| ConversionComponentOutput {
+                    asset: AssetOutput::new(component, conversions),
+                    amount: val.to_string(),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
 /// Query a conversion.
 pub async fn query_conversion<C: namada_sdk::queries::Client + Sync>(
     client: &C,
@@ -2064,3 +3206,990 @@ pub async fn compute_proposal_votes<C: namada_sdk::queries::Client + Sync>(
         delegator_voting_power,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use borsh_ext::BorshSerializeExt;
+    use masp_primitives::sapling::Rseed;
+    use masp_primitives::zip32::ExtendedSpendingKey;
+    use namada_sdk::address::testing::{
+        btc, established_address_1, established_address_2,
+        established_address_3, nam,
+    };
+    use namada_sdk::eth_bridge_pool::{
+        GasFee, TransferToEthereum, TransferToEthereumKind,
+    };
+    use namada_sdk::ethereum_events::EthAddress;
+    use namada_sdk::governance::utils::TallyType;
+    use namada_sdk::io::StdIo;
+    use namada_sdk::key::testing::keypair_1;
+    use namada_sdk::masp::fs::FsShieldedUtils;
+    use namada_sdk::masp::{
+        encode_asset_type, ExtendedViewingKey, ShieldedContext,
+    };
+    use namada_sdk::proof_of_stake::types::{
+        BondDetails, BondId, BondsAndUnbondsDetail, SlashType, UnbondDetails,
+    };
+    use namada_sdk::queries::testing::TestClient;
+    use namada_sdk::state::testing::TestState;
+    use namada_sdk::storage::{KeySeg, StorageWrite};
+    use namada_sdk::time::DurationSecs;
+    use namada_sdk::token::storage_key::balance_key;
+    use namada_sdk::NamadaImpl;
+
+    use super::*;
+    use crate::wallet::CliWalletUtils;
+
+    #[test]
+    fn test_display_eth_bridge_pool() {
+        let transfers: Vec<_> = [1u64, 2]
+            .into_iter()
+            .map(|amount| PendingTransfer {
+                transfer: TransferToEthereum {
+                    kind: TransferToEthereumKind::Erc20,
+                    asset: EthAddress([1; 20]),
+                    recipient: EthAddress([2; 20]),
+                    sender: established_address_1(),
+                    amount: token::Amount::from_u64(amount),
+                },
+                gas_fee: GasFee {
+                    token: nam(),
+                    amount: token::Amount::from_u64(10),
+                    payer: established_address_1(),
+                },
+            })
+            .collect();
+
+        let mut out = vec![];
+        display_eth_bridge_pool(&StdIo, &mut out, 5.into(), &transfers)
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+
+        assert_eq!(lines[0], "Ethereum bridge pool nonce: 5");
+        assert_eq!(lines[1], "Pending transfers:");
+        // each transfer is rendered with its hash and 6 details
+        assert_eq!(lines.len(), 2 + 2 * 7);
+        for (transfer, rendered) in transfers.iter().zip(lines[2..].chunks(7)) {
+            assert_eq!(rendered[0], format!("    - {}", transfer.keccak256()));
+            assert_eq!(
+                rendered[2],
+                format!("      Sender: {}", established_address_1())
+            );
+            assert_eq!(
+                rendered[3],
+                format!("      Recipient: {}", EthAddress([2; 20]))
+            );
+            assert_eq!(
+                rendered[5],
+                format!("      Amount: {}", transfer.transfer.amount)
+            );
+        }
+
+        let mut out = vec![];
+        display_eth_bridge_pool(&StdIo, &mut out, 0.into(), &[]).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Ethereum bridge pool nonce: 0\nEthereum bridge pool is empty.\n"
+        );
+    }
+
+    #[test]
+    fn test_display_proposal_votes() {
+        let validator = established_address_1();
+        let delegator = established_address_2();
+        let other = established_address_3();
+        let vote = |delegator: &Address, data| Vote {
+            validator: validator.clone(),
+            delegator: delegator.clone(),
+            data,
+        };
+        let votes = [
+            (
+                vote(&validator, ProposalVote::Yay),
+                token::Amount::native_whole(100),
+            ),
+            (
+                vote(&delegator, ProposalVote::Yay),
+                token::Amount::native_whole(20),
+            ),
+            (
+                vote(&other, ProposalVote::Nay),
+                token::Amount::native_whole(5),
+            ),
+        ];
+
+        let mut out = vec![];
+        display_proposal_votes(&StdIo, &mut out, 7.into(), &votes).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+
+        assert_eq!(lines[0], "Votes (voting power at epoch 7):");
+        // the validator's own vote is not attributed to a validator
+        assert_eq!(lines.len(), 1 + 3 + 4 + 4);
+        assert_eq!(lines[1], format!("    - Voter: {validator}"));
+        assert_eq!(lines[2], "      Vote: yay");
+        assert_eq!(lines[3], "      Voting power: 100.000000");
+        assert_eq!(lines[4], format!("    - Voter: {delegator}"));
+        assert_eq!(lines[5], format!("      Validator: {validator}"));
+        assert_eq!(lines[6], "      Vote: yay");
+        assert_eq!(lines[7], "      Voting power: 20.000000");
+        assert_eq!(lines[8], format!("    - Voter: {other}"));
+        assert_eq!(lines[9], format!("      Validator: {validator}"));
+        assert_eq!(lines[10], "      Vote: nay");
+        assert_eq!(lines[11], "      Voting power: 5.000000");
+
+        let mut out = vec![];
+        display_proposal_votes(&StdIo, &mut out, 7.into(), &[]).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "No votes cast on the proposal.\n"
+        );
+    }
+
+    #[test]
+    fn test_display_proposal_result_projection() {
+        let total = token::Amount::native_whole(90);
+        let result = |yay: u64| {
+            let yay = token::Amount::native_whole(yay);
+            let nay = token::Amount::zero();
+            let abstain = token::Amount::zero();
+            let tally_type = TallyType::TwoThirds;
+            ProposalResult {
+                result: TallyResult::new(&tally_type, yay, nay, abstain, total)
+                    .unwrap(),
+                tally_type,
+                total_voting_power: total,
+                total_yay_power: yay,
+                total_nay_power: nay,
+                total_abstain_power: abstain,
+            }
+        };
+        let render = |result: &ProposalResult| {
+            let mut out = vec![];
+            display_proposal_result(
+                &StdIo,
+                &mut out,
+                3.into(),
+                6.into(),
+                result,
+            )
+            .unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        // 2/3 of the total voting power is needed to reach the quorum
+        let out = render(&result(60));
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(lines[0], "    Still voting until epoch 6 begins.");
+        assert!(lines[1].starts_with("    Currently with 60.000000 yay votes"));
+        assert_eq!(
+            lines[2],
+            "    The proposal would pass if voting ended now."
+        );
+
+        let out = render(&result(59));
+        let lines: Vec<_> = out.lines().collect();
+        assert!(lines[1].starts_with("    Currently with 59.000000 yay votes"));
+        assert_eq!(
+            lines[2],
+            "    The proposal would be rejected if voting ended now."
+        );
+
+        // once voting has ended, the final result is shown
+        let mut out = vec![];
+        display_proposal_result(
+            &StdIo,
+            &mut out,
+            6.into(),
+            6.into(),
+            &result(60),
+        )
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("    passed with 60.000000 yay votes"));
+        assert_eq!(out.lines().count(), 1);
+    }
+
+    #[test]
+    fn test_display_prefix_values() {
+        let mut state = TestState::default();
+        let prefix = storage::Key::from(established_address_1().to_db_key());
+        let keys: Vec<_> = ["a", "b", "c"]
+            .iter()
+            .map(|seg| prefix.push(&seg.to_string()).unwrap())
+            .collect();
+        // a key outside of the prefix must not be dumped
+        let other = storage::Key::from(established_address_2().to_db_key());
+        for (key, value) in [(&keys[2], 3u8), (&keys[0], 1), (&keys[1], 2)]
+            .into_iter()
+            .chain([(&other, 4)])
+        {
+            state.write_bytes(key, [value]).unwrap();
+        }
+
+        let values: Vec<_> =
+            namada_sdk::storage::iter_prefix_bytes(&state, &prefix)
+                .unwrap()
+                .map(|res| {
+                    let (key, value) = res.unwrap();
+                    PrefixValue { key, value }
+                })
+                .collect();
+        let mut out = vec![];
+        display_prefix_values(&StdIo, &mut out, &prefix, &values, None)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!(
+                "{} => 0x01\n{} => 0x02\n{} => 0x03\n",
+                keys[0], keys[1], keys[2]
+            )
+        );
+
+        let mut out = vec![];
+        display_prefix_values(&StdIo, &mut out, &prefix, &[], None).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("No data found under prefix {prefix}\n")
+        );
+    }
+
+    #[test]
+    fn test_format_raw_value() {
+        let amount = token::Amount::native_whole(12);
+        let amount_bytes = amount.serialize_to_vec();
+        let epoch_bytes = Epoch(42).serialize_to_vec();
+
+        assert_eq!(
+            format_raw_value(&amount_bytes, Some(args::RawValueType::Amount)),
+            amount.to_string()
+        );
+        assert_eq!(
+            format_raw_value(&epoch_bytes, Some(args::RawValueType::Epoch)),
+            "42"
+        );
+        // the bytes of an epoch don't decode as an amount
+        assert_eq!(
+            format_raw_value(&epoch_bytes, Some(args::RawValueType::Amount)),
+            format!("0x{}", HEXLOWER.encode(&epoch_bytes))
+        );
+        assert_eq!(
+            format_raw_value(&epoch_bytes, None),
+            format!("0x{}", HEXLOWER.encode(&epoch_bytes))
+        );
+    }
+
+    #[test]
+    fn test_format_balance_json() {
+        let token = nam();
+        let owner = established_address_1().to_string();
+        let output =
+            format_balance(OutputFormat::Json, "nam", &token, &owner, "1234.5");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&output).expect("Output should be valid JSON");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "token": token.to_string(),
+                "owner": owner,
+                "amount": "1234.5",
+            })
+        );
+        assert_eq!(
+            format_balance(OutputFormat::Text, "nam", &token, &owner, "1234.5"),
+            "nam: 1234.5"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_display_token_balances() {
+        let mut client = TestClient::new(RPC);
+        let owner = established_address_1();
+        let balances = [
+            (nam(), token::Amount::native_whole(10)),
+            (btc(), token::Amount::native_whole(20)),
+        ];
+        for (token, amount) in &balances {
+            token::write_denom(&mut client.state, token, 6.into()).unwrap();
+            client
+                .state
+                .write(&balance_key(token, &owner), *amount)
+                .unwrap();
+        }
+        // A token without a balance of the owner is queried as zero
+        let eth = established_address_2();
+        token::write_denom(&mut client.state, &eth, 18.into()).unwrap();
+        let tokens = [
+            (nam(), "nam".to_string()),
+            (btc(), "btc".to_string()),
+            (eth, "eth".to_string()),
+        ];
+
+        let mut out = vec![];
+        display_token_balances(
+            &client,
+            &StdIo,
+            &mut out,
+            &owner,
+            &tokens,
+            None,
+            OutputFormat::Text,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "nam: 10\nbtc: 20\neth: 0\n"
+        );
+
+        let mut out = vec![];
+        display_token_balances(
+            &client,
+            &StdIo,
+            &mut out,
+            &owner,
+            &tokens[..1],
+            None,
+            OutputFormat::Json,
+        )
+        .await
+        .unwrap();
+        let json: serde_json::Value =
+            serde_json::from_slice(&out).expect("Output should be valid JSON");
+        assert_eq!(json["token"], nam().to_string());
+        assert_eq!(json["amount"], "10");
+    }
+
+    /// An IO that records the printed lines
+    #[derive(Default)]
+    struct CaptureIo(std::sync::Mutex<Vec<String>>);
+
+    #[async_trait::async_trait(?Send)]
+    impl Io for CaptureIo {
+        fn println(&self, output: impl AsRef<str>) {
+            self.0.lock().unwrap().push(output.as_ref().to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query_balance_viewing_key_file() {
+        let spend_key = ExtendedSpendingKey::master(&[0; 32]);
+        let full_viewing_key = ExtendedFullViewingKey::from(&spend_key);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("viewing-key");
+        std::fs::write(
+            &path,
+            format!("{}\n", ExtendedViewingKey::from(full_viewing_key)),
+        )
+        .unwrap();
+
+        // Read the viewing key from the file given to the balance query
+        let matches = cli::namada_client_app()
+            .try_get_matches_from([
+                "namadac",
+                "balance",
+                "--viewing-key-file",
+                path.to_str().unwrap(),
+                "--no-conversions",
+                "--node",
+                "http://127.0.0.1:26657",
+            ])
+            .expect("Arguments should be valid");
+        let cmd = <cli::cmds::NamadaClient as cli::Cmd>::parse(&matches)
+            .expect("Command should be recognized");
+        let cli::cmds::NamadaClient::WithContext(
+            cli::cmds::NamadaClientWithContext::QueryBalance(
+                cli::cmds::QueryBalance(cli_args),
+            ),
+        ) = cmd
+        else {
+            panic!("Expected a balance query, got {cmd:?}");
+        };
+        let owner: ExtendedViewingKey = cli_args.owner.raw.parse().unwrap();
+
+        let mut client = TestClient::new(RPC);
+        client
+            .state
+            .write(&param_storage::get_masp_epoch_multiplier_key(), 1_u64)
+            .unwrap();
+        token::write_denom(&mut client.state, &nam(), 6.into()).unwrap();
+        let mut wallet = CliWalletUtils::new(PathBuf::new());
+        wallet.insert_address("nam", nam(), false).unwrap();
+
+        // Mock a spent and an unspent note of the viewing key
+        let asset_type =
+            encode_asset_type(nam(), 6.into(), MaspDigitPos::Zero, None)
+                .unwrap();
+        let viewing_key = full_viewing_key.fvk.vk;
+        let mut shielded = ShieldedContext::<FsShieldedUtils>::default();
+        for (idx, value) in [(0, 1_000_000), (1, 2_000_000)] {
+            let note = full_viewing_key
+                .default_address()
+                .1
+                .create_note(asset_type, value, Rseed::AfterZip212([0; 32]))
+                .unwrap();
+            shielded.note_map.insert(idx, note);
+            shielded.pos_map.entry(viewing_key).or_default().insert(idx);
+        }
+        shielded.spents.insert(1);
+
+        let context = NamadaImpl::native_new(
+            client,
+            wallet,
+            shielded,
+            CaptureIo::default(),
+            nam(),
+        );
+        query_balance(
+            &context,
+            args::QueryBalance {
+                query: args::Query {
+                    ledger_address: "http://127.0.0.1:26657".parse().unwrap(),
+                },
+                owner: BalanceOwner::FullViewingKey(owner),
+                tokens: vec![nam()],
+                no_conversions: cli_args.no_conversions,
+                epoch: cli_args.epoch,
+                output_format: cli_args.output_format,
+            },
+        )
+        .await;
+        assert_eq!(
+            *context.io().0.lock().unwrap(),
+            ["Last committed masp epoch: 0", "nam: 1"]
+        );
+    }
+
+    #[test]
+    fn test_display_total_supply() {
+        let mut out = vec![];
+        display_total_supply(&StdIo, &mut out, "btc", "21.000000", None)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Total supply of btc: 21.000000\n"
+        );
+
+        let supply = token::Amount::native_whole(1000);
+        let staked = token::Amount::native_whole(250);
+        let mut out = vec![];
+        display_total_supply(
+            &StdIo,
+            &mut out,
+            "nam",
+            &supply.to_string_native(),
+            Some((staked.to_string_native(), staked_percent(staked, supply))),
+        )
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(lines[0], "Total supply of nam: 1000.000000");
+        assert_eq!(lines[1], "Staked in PoS: 250.000000 (25% of the supply)");
+    }
+
+    #[test]
+    fn test_staked_percent() {
+        let supply = token::Amount::native_whole(1000);
+        assert_eq!(
+            staked_percent(token::Amount::native_whole(250), supply),
+            Some(Dec::from(25_u64))
+        );
+        // The share of a null supply can't be computed
+        let staked = token::Amount::native_whole(250);
+        assert_eq!(staked_percent(staked, token::Amount::zero()), None);
+        // Neither can a supply too large to be represented as a decimal
+        let staked = token::Amount::zero();
+        assert_eq!(staked_percent(staked, token::Amount::max()), None);
+    }
+
+    #[test]
+    fn test_filter_bonds_by_epoch() {
+        let bond_id = BondId {
+            source: established_address_1(),
+            validator: established_address_2(),
+        };
+        let bond = |epoch: u64, amount: u64| BondDetails {
+            start: Epoch(epoch),
+            amount: token::Amount::native_whole(amount),
+            slashed_amount: None,
+        };
+        let bonds_and_unbonds = BondsAndUnbondsDetails::from([(
+            bond_id.clone(),
+            BondsAndUnbondsDetail {
+                bonds: vec![bond(1, 10), bond(5, 20), bond(10, 30)],
+                unbonds: vec![],
+                slashes: vec![],
+            },
+        )]);
+
+        let filtered = filter_bonds_by_epoch(
+            bonds_and_unbonds.clone(),
+            Some(Epoch(3)),
+            Some(Epoch(7)),
+        );
+        let filtered = enrich_bonds_and_unbonds(Epoch(12), filtered).unwrap();
+        let mut out = vec![];
+        display_bonds(&StdIo, &mut out, &filtered).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Remaining active bond from epoch 5: Δ 20"));
+        assert!(!out.contains("from epoch 1:"));
+        assert!(!out.contains("from epoch 10:"));
+        assert!(out.contains("All bonds total: 20.000000"));
+
+        // Without a range, all the bonds are kept
+        let unfiltered =
+            filter_bonds_by_epoch(bonds_and_unbonds.clone(), None, None);
+        assert_eq!(
+            unfiltered[&bond_id].bonds,
+            bonds_and_unbonds[&bond_id].bonds
+        );
+
+        // Bond ids without any bonds in range are dropped
+        let empty =
+            filter_bonds_by_epoch(bonds_and_unbonds, Some(Epoch(11)), None);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_display_validator_state() {
+        let validator = established_address_1();
+
+        let mut out = vec![];
+        display_validator_state(
+            &StdIo,
+            &mut out,
+            &validator,
+            Some(ValidatorState::Jailed),
+            Epoch(7),
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("Validator {validator} is jailed in epoch 7\n")
+        );
+
+        let mut out = vec![];
+        display_validator_state(&StdIo, &mut out, &validator, None, Epoch(7))
+            .unwrap();
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .starts_with(&format!("Validator {validator} not found"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watch_epoch() {
+        // The epoch advances every other poll until the mock runs out. A
+        // failed query doesn't stop the watcher.
+        let mut epochs = [Ok(1), Ok(1), Ok(2), Err("timeout"), Ok(3), Ok(4)]
+            .into_iter()
+            .map(|epoch| epoch.map(Epoch));
+
+        let mut out = vec![];
+        watch_epoch(&StdIo, &mut out, Epoch(1), Duration::ZERO, || {
+            std::future::ready(epochs.next())
+        })
+        .await
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Last committed epoch: 2\nLast committed epoch: 3\nLast \
+             committed epoch: 4\n"
+        );
+    }
+
+    #[test]
+    fn test_display_next_epoch_info() {
+        let epoch_duration = EpochDuration {
+            min_num_of_blocks: 10,
+            min_duration: DurationSecs(60),
+        };
+        let start_time = DateTimeUtc::from_unix_timestamp(0).unwrap();
+        let last_block = storage::LastBlock {
+            height: BlockHeight(104),
+            time: DateTimeUtc::from_unix_timestamp(20).unwrap(),
+        };
+
+        let mut out = vec![];
+        display_next_epoch_info(
+            &StdIo,
+            &mut out,
+            Epoch(3),
+            BlockHeight(100),
+            Some(start_time),
+            &epoch_duration,
+            Some(&last_block),
+        )
+        .unwrap();
+        let next_epoch_time = DateTimeUtc::from_unix_timestamp(60).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!(
+                "Last committed epoch: 3.\nFirst block height of this \
+                 current epoch: 100.\nMinimum number of blocks in an epoch: \
+                 10.\nMinimum amount of time for an epoch: 60 seconds.\n\n\
+                 Earliest height at which the next epoch can begin is block \
+                 110.\nEarliest time at which the next epoch can begin is \
+                 {next_epoch_time}.\nBlocks remaining until the next epoch \
+                 can begin: 6.\nEstimated time until the next epoch can \
+                 begin: 40 seconds.\n"
+            )
+        );
+
+        // Without the block times, only the next epoch height is estimated
+        let mut out = vec![];
+        display_next_epoch_info(
+            &StdIo,
+            &mut out,
+            Epoch(3),
+            BlockHeight(100),
+            None,
+            &epoch_duration,
+            None,
+        )
+        .unwrap();
+        assert!(String::from_utf8(out).unwrap().ends_with(
+            "Earliest height at which the next epoch can begin is block 110.\n"
+        ));
+    }
+
+    #[test]
+    fn test_display_account() {
+        let owner = established_address_1();
+        let public_key = keypair_1().ref_to();
+        let vp_code_hash = Hash::sha256(b"vp_user");
+        let account = Account {
+            public_keys_map: [public_key.clone()].into_iter().collect(),
+            threshold: 1,
+            address: owner.clone(),
+        };
+
+        let mut out = vec![];
+        display_account(
+            &StdIo,
+            &mut out,
+            &owner,
+            Some(account),
+            Some(vp_code_hash),
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!(
+                "Address: {owner}\nVP code hash: {vp_code_hash}\nThreshold: \
+                 1\nPublic keys:\n- {public_key}\n"
+            )
+        );
+
+        let mut out = vec![];
+        display_account(&StdIo, &mut out, &owner, None, None).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("No account exists for {owner}\n")
+        );
+    }
+
+    #[test]
+    fn test_conversions_output() {
+        let denom = token::Denomination(token::NATIVE_MAX_DECIMAL_PLACES);
+        let asset = |token: Address, epoch| {
+            namada_sdk::masp::encode_asset_type(
+                token,
+                denom,
+                MaspDigitPos::Zero,
+                Some(MaspEpoch::new(epoch)),
+            )
+            .unwrap()
+        };
+        let (nam_0, nam_1) = (asset(nam(), 0), asset(nam(), 1));
+        let (btc_0, btc_1) = (asset(btc(), 0), asset(btc(), 1));
+        let conversions: Conversions = [
+            (nam_0, nam(), 0, I128Sum::from_pair(nam_0, -1)),
+            (nam_1, nam(), 1, I128Sum::zero()),
+            (
+                btc_0,
+                btc(),
+                0,
+                I128Sum::from_pair(btc_0, -1)
+                    + &I128Sum::from_pair(btc_1, 1)
+                    + &I128Sum::from_pair(nam_1, 5),
+            ),
+            (btc_1, btc(), 1, I128Sum::zero()),
+        ]
+        .into_iter()
+        .map(|(asset_type, token, epoch, amt)| {
+            let conv =
+                (token, denom, MaspDigitPos::Zero, MaspEpoch::new(epoch), amt);
+            (asset_type, conv)
+        })
+        .collect();
+
+        let output = ConversionsOutput {
+            total_rewards: None,
+            conversions: conversions_output(&conversions, Some(&btc()), None),
+        };
+        let json: serde_json::Value =
+            serde_json::to_value(&output).expect("Output should be valid JSON");
+        assert!(json.get("total_rewards").is_none());
+        // Sentinel conversions and other tokens are left out
+        let conversions = json["conversions"].as_array().unwrap();
+        assert_eq!(conversions.len(), 1);
+        let conversion = &conversions[0];
+        assert_eq!(conversion["asset_type"], btc_0.to_string());
+        assert_eq!(conversion["token"], btc().to_string());
+        assert_eq!(conversion["digit"], 0);
+        assert_eq!(conversion["epoch"], 0);
+        let mut components: Vec<_> = conversion["components"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|component| {
+                (
+                    component["asset_type"].as_str().unwrap().to_string(),
+                    component["amount"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect();
+        components.sort();
+        let mut expected = vec![
+            (btc_0.to_string(), "-1".to_string()),
+            (btc_1.to_string(), "1".to_string()),
+            (nam_1.to_string(), "5".to_string()),
+        ];
+        expected.sort();
+        assert_eq!(components, expected);
+    }
+
+    #[test]
+    fn test_sort_validators() {
+        let validator = |address: Address, stake: u64| WeightedValidator {
+            bonded_stake: token::Amount::native_whole(stake),
+            address,
+        };
+        let validators = BTreeSet::from([
+            validator(established_address_1(), 10),
+            validator(established_address_2(), 30),
+            validator(established_address_3(), 20),
+        ]);
+        let addresses = |sorted: Vec<WeightedValidator>| {
+            sorted.into_iter().map(|val| val.address).collect::<Vec<_>>()
+        };
+        let by_desc_stake = vec![
+            established_address_2(),
+            established_address_3(),
+            established_address_1(),
+        ];
+
+        let sorted = sort_validators(
+            validators.clone(),
+            Some(args::ValidatorSortBy::Stake),
+            true,
+        );
+        assert_eq!(addresses(sorted), by_desc_stake);
+
+        // Validators are listed by descending stake by default
+        let sorted = sort_validators(validators.clone(), None, false);
+        assert_eq!(addresses(sorted), by_desc_stake);
+
+        let sorted = sort_validators(
+            validators.clone(),
+            Some(args::ValidatorSortBy::Stake),
+            false,
+        );
+        let mut by_stake = by_desc_stake.clone();
+        by_stake.reverse();
+        assert_eq!(addresses(sorted), by_stake);
+
+        let sorted = sort_validators(
+            validators,
+            Some(args::ValidatorSortBy::Address),
+            false,
+        );
+        let mut by_address = by_desc_stake;
+        by_address.sort_by_key(|address| address.encode());
+        assert_eq!(addresses(sorted), by_address);
+    }
+
+    #[test]
+    fn test_display_validator_consensus_key() {
+        let validator = established_address_1();
+        let consensus_key = keypair_1().ref_to();
+        let mut out = vec![];
+        display_validator_consensus_key(
+            &StdIo,
+            &mut out,
+            &validator,
+            Some(&consensus_key),
+        )
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], format!("Consensus key: {consensus_key}"));
+        assert_eq!(
+            lines[1],
+            format!(
+                "Tendermint key: {}",
+                tm_consensus_key_raw_hash(&consensus_key)
+            )
+        );
+        assert_eq!(
+            lines[2],
+            format!(
+                "Consensus key hash: {}",
+                PublicKeyHash::from(&consensus_key)
+            )
+        );
+
+        let mut out = vec![];
+        display_validator_consensus_key(&StdIo, &mut out, &validator, None)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!(
+                "Consensus key for validator {validator} could not be found.\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_display_commission_rate_history() {
+        let validator = established_address_1();
+        let max_change = Dec::new(1, 2).unwrap();
+        let commission = |epoch: u64, rate: Dec| CommissionPair {
+            commission_rate: Some(rate),
+            max_commission_change_per_epoch: Some(max_change),
+            epoch: Epoch(epoch),
+        };
+        let history = [
+            commission(1, Dec::new(5, 2).unwrap()),
+            commission(2, Dec::new(6, 2).unwrap()),
+            CommissionPair {
+                commission_rate: None,
+                max_commission_change_per_epoch: None,
+                epoch: Epoch(3),
+            },
+        ];
+        let mut out = vec![];
+        display_commission_rate_history(&StdIo, &mut out, &validator, &history)
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                format!("Validator {validator} commission rate history:"),
+                "  Epoch | Commission rate | Max change per epoch".to_string(),
+                "  1 | 0.05 | 0.01".to_string(),
+                "  2 | 0.06 | 0.01".to_string(),
+                "  3 | not found | not found".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_commission_rate_history_range() {
+        let max = MAX_COMMISSION_RATE_HISTORY_EPOCHS;
+        let (from, to) = (Epoch(5), Epoch(5));
+        assert!(check_commission_rate_history_range(from, to).is_ok());
+        let (from, to) = (Epoch(5), Epoch(4));
+        assert!(check_commission_rate_history_range(from, to).is_err());
+        let (from, to) = (Epoch(10), Epoch(10 + max - 1));
+        assert!(check_commission_rate_history_range(from, to).is_ok());
+        let (from, to) = (Epoch(10), Epoch(10 + max));
+        assert!(check_commission_rate_history_range(from, to).is_err());
+    }
+
+    #[test]
+    fn test_display_slashed_amounts() {
+        let owner = established_address_1();
+        let validator = established_address_2();
+        let amount = token::Amount::native_whole;
+        let slash = Slash {
+            epoch: Epoch(5),
+            block_height: 50,
+            r#type: SlashType::DuplicateVote,
+            rate: Dec::new(1, 1).unwrap(),
+        };
+        let bonds_and_unbonds = BondsAndUnbondsDetails::from([
+            (
+                BondId {
+                    source: owner.clone(),
+                    validator: validator.clone(),
+                },
+                BondsAndUnbondsDetail {
+                    bonds: vec![
+                        BondDetails {
+                            start: Epoch(1),
+                            amount: amount(100),
+                            slashed_amount: Some(amount(10)),
+                        },
+                        BondDetails {
+                            start: Epoch(6),
+                            amount: amount(400),
+                            slashed_amount: None,
+                        },
+                    ],
+                    unbonds: vec![UnbondDetails {
+                        start: Epoch(2),
+                        withdraw: Epoch(9),
+                        amount: amount(200),
+                        slashed_amount: Some(amount(20)),
+                    }],
+                    slashes: vec![slash],
+                },
+            ),
+            // Bonds that weren't slashed aren't shown
+            (
+                BondId {
+                    source: owner.clone(),
+                    validator: established_address_3(),
+                },
+                BondsAndUnbondsDetail {
+                    bonds: vec![BondDetails {
+                        start: Epoch(1),
+                        amount: amount(50),
+                        slashed_amount: None,
+                    }],
+                    unbonds: vec![],
+                    slashes: vec![],
+                },
+            ),
+        ]);
+        let bonds_and_unbonds =
+            enrich_bonds_and_unbonds(Epoch(10), bonds_and_unbonds).unwrap();
+
+        let mut out = vec![];
+        display_slashed_amounts(&StdIo, &mut out, &owner, &bonds_and_unbonds)
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                String::new(),
+                format!("Slashed amounts for {owner}:"),
+                format!("  Validator {validator}:"),
+                "    Slash at infraction epoch 5, rate 0.1".to_string(),
+                "    Bonds slashed: 10.000000 of 500.000000".to_string(),
+                "    Unbonds slashed: 20.000000 of 200.000000".to_string(),
+                "All bonds total slashed: 10.000000".to_string(),
+                "All unbonds total slashed: 20.000000".to_string(),
+            ]
+        );
+
+        let mut out = vec![];
+        let no_bonds =
+            enrich_bonds_and_unbonds(Epoch(10), Default::default()).unwrap();
+        display_slashed_amounts(&StdIo, &mut out, &owner, &no_bonds).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            format!("\nNo slashes affect the bonds of {owner}\n")
+        );
+    }
+}
@@ -1,11 +1,14 @@
 use std::fs::File;
+use std::future::Future;
 use std::io::Write;
+use std::path::PathBuf;
 
 use borsh::BorshDeserialize;
 use borsh_ext::BorshSerializeExt;
 use ledger_namada_rs::{BIP44Path, NamadaApp};
 use ledger_transport_hid::hidapi::HidApi;
 use ledger_transport_hid::TransportNativeHID;
+use namada_sdk::account::AccountPublicKeysMap;
 use namada_sdk::address::{Address, ImplicitAddress};
 use namada_sdk::args::TxBecomeValidator;
 use namada_sdk::collections::HashSet;
@@ -699,6 +702,25 @@ pub async fn submit_init_validator(
         tx_become_validator_code_path,
     }: args::TxInitValidator,
 ) -> Result<(), error::Error> {
+    // Validate the commission rate data before the account gets initialized
+    if !tx_args.force {
+        if !tx::is_valid_commission_rate(&commission_rate) {
+            eprintln!(
+                "Invalid validator commission rate {commission_rate}, it must \
+                 be between 0.0 and 1.0."
+            );
+            safe_exit(1)
+        }
+        if !tx::is_valid_commission_rate(&max_commission_rate_change) {
+            eprintln!(
+                "Invalid validator maximum change in commission rate per \
+                 epoch {max_commission_rate_change}, it must be between 0.0 \
+                 and 1.0."
+            );
+            safe_exit(1)
+        }
+    }
+
     let address = submit_init_account(
         namada,
         args::TxInitAccount {
@@ -1022,6 +1044,9 @@ pub async fn sign_tx<N: Namada>(
         tx: tx_args,
         tx_data,
         owner,
+        account_public_keys,
+        threshold,
+        output,
     }: args::SignTx,
 ) -> Result<(), error::Error>
 where
@@ -1033,10 +1058,31 @@ where
         edisplay_line!(namada.io(), "Couldn't decode the transaction.");
         safe_exit(1)
     };
-    let default_signer = Some(owner.clone());
-    let signing_data =
+    let signing_data = if account_public_keys.is_empty() {
+        let default_signer = Some(owner.clone());
         aux_signing_data(namada, &tx_args, Some(owner.clone()), default_signer)
-            .await?;
+            .await?
+    } else {
+        offline_signing_data(
+            &tx_args,
+            &tx,
+            owner.clone(),
+            account_public_keys,
+            threshold,
+        )?
+    };
+
+    if let Some(output) = output {
+        return sign_tx_to_file(
+            namada,
+            tx,
+            tx_args,
+            owner,
+            signing_data,
+            output,
+        )
+        .await;
+    }
 
     let mut wallet = namada.wallet_mut().await;
     let secret_keys = &signing_data
@@ -1097,18 +1143,316 @@ where
     Ok(())
 }
 
-pub async fn submit_reveal_pk<N: Namada>(
+/// Add the signatures of the owner's keys held in the wallet to a tx, on top
+/// of the ones it already carries, and write it to `output`. The wrapper is
+/// only signed by the fee payer once the owner's signatures meet the
+/// account's threshold, because signatures added after it would invalidate
+/// it. Until then, the tx file can be passed on to the other signers of a
+/// multisig account, who each run `sign-tx` on it in turn.
+async fn sign_tx_to_file<N: Namada>(
     namada: &N,
-    args: args::RevealPk,
+    mut tx: Tx,
+    tx_args: args::Tx,
+    owner: Address,
+    signing_data: SigningTxData,
+    output: PathBuf,
+) -> Result<(), error::Error> {
+    let SigningTxData {
+        public_keys,
+        threshold,
+        account_public_keys_map,
+        fee_payer,
+        ..
+    } = signing_data;
+    let account_public_keys_map = account_public_keys_map.ok_or_else(|| {
+        error::Error::from(error::TxSubmitError::InvalidAccount(owner.encode()))
+    })?;
+    let meets_threshold = |tx: &Tx| {
+        tx.verify_signatures(
+            &[tx.raw_header_hash()],
+            account_public_keys_map.clone(),
+            &Some(owner.clone()),
+            threshold,
+            || Ok(()),
+        )
+        .is_ok()
+    };
+
+    let mut wallet = namada.wallet_mut().await;
+    if !meets_threshold(&tx) {
+        // The indices of the owner's keys that already signed the tx
+        let signed = tx
+            .sections
+            .iter()
+            .filter_map(|section| match section {
+                Section::Authorization(authorization) => {
+                    match &authorization.signer {
+                        Signer::Address(signer) if *signer == owner => {
+                            Some(authorization.signatures.keys().copied())
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .flatten()
+            .collect::<HashSet<u8>>();
+        let secret_keys = public_keys
+            .iter()
+            .filter(|public_key| {
+                account_public_keys_map
+                    .get_index_from_public_key(public_key)
+                    .is_some_and(|index| !signed.contains(&index))
+            })
+            .filter_map(|public_key| {
+                signing::find_key_by_pk(&mut wallet, &tx_args, public_key).ok()
+            })
+            .collect::<Vec<common::SecretKey>>();
+        if secret_keys.is_empty() {
+            return Err(error::Error::Other(format!(
+                "The wallet holds none of the keys of {owner} that haven't \
+                 signed the tx yet."
+            )));
+        }
+        tx.sign_raw(
+            secret_keys,
+            account_public_keys_map.clone(),
+            Some(owner.clone()),
+        );
+    }
+
+    if !meets_threshold(&tx) {
+        display_line!(
+            namada.io(),
+            "The tx doesn't carry the {threshold} signature(s) required by \
+             {owner} yet. Pass {} on to the other signers to add theirs with \
+             `sign-tx --output` before broadcasting it.",
+            output.display()
+        );
+    } else if let Ok(fee_payer_key) =
+        signing::find_key_by_pk(&mut wallet, &tx_args, &fee_payer)
+    {
+        tx.sign_wrapper(fee_payer_key);
+        display_line!(
+            namada.io(),
+            "The tx is fully signed and can be submitted with `broadcast-tx`."
+        );
+    } else {
+        display_line!(
+            namada.io(),
+            "The tx carries the signatures required by {owner}. Its wrapper \
+             must still be signed by the fee payer {fee_payer}, with \
+             `sign-tx --output` on {}.",
+            output.display()
+        );
+    }
+    drop(wallet);
+
+    let dump_args = args::Tx {
+        dump_tx_path: Some(output),
+        ..tx_args
+    };
+    tx::dump_tx(namada.io(), &dump_args, tx);
+    Ok(())
+}
+
+/// The signing data of an account whose public keys and threshold are
+/// given, so that the node doesn't have to be queried for them
+fn offline_signing_data(
+    tx_args: &args::Tx,
+    tx: &Tx,
+    owner: Address,
+    account_public_keys: Vec<common::PublicKey>,
+    threshold: Option<u8>,
+) -> Result<SigningTxData, error::Error> {
+    let threshold = match &owner {
+        Address::Implicit(_) => 1,
+        _ => match threshold {
+            Some(threshold)
+                if threshold > 0
+                    && usize::from(threshold) <= account_public_keys.len() =>
+            {
+                threshold
+            }
+            Some(_) => {
+                return Err(
+                    error::TxSubmitError::InvalidAccountThreshold.into()
+                );
+            }
+            None => {
+                return Err(
+                    error::TxSubmitError::MissingAccountThreshold.into()
+                );
+            }
+        },
+    };
+    let public_keys = if tx_args.signing_keys.is_empty() {
+        account_public_keys.clone()
+    } else {
+        tx_args.signing_keys.clone()
+    };
+    // Unless another one is requested, the fee payer is the one picked when
+    // the wrapper was built
+    let fee_payer = tx_args
+        .wrapper_fee_payer
+        .clone()
+        .or_else(|| tx.header.wrapper().map(|wrapper| wrapper.pk))
+        .ok_or(error::TxSubmitError::InvalidFeePayer)?;
+    Ok(SigningTxData {
+        owner: Some(owner),
+        public_keys,
+        threshold,
+        account_public_keys_map: Some(AccountPublicKeysMap::from_iter(
+            account_public_keys,
+        )),
+        fee_payer,
+    })
+}
+
+/// Broadcast a tx that was signed offline
+pub async fn submit_signed_tx<N: Namada>(
+    namada: &N,
+    args: args::BroadcastTx,
 ) -> Result<(), error::Error>
 where
     <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
 {
-    submit_reveal_aux(namada, args.tx, &(&args.public_key).into()).await?;
+    let tx = if let Ok(transaction) = Tx::deserialize(args.tx_data.as_ref()) {
+        transaction
+    } else {
+        edisplay_line!(namada.io(), "Couldn't decode the transaction.");
+        safe_exit(1)
+    };
+    check_signed_tx(namada.client(), &tx).await?;
+    namada.submit(tx, &args.tx).await?;
+    Ok(())
+}
 
+/// Check that a tx signed offline carries the signature of its fee payer and
+/// enough signatures to meet the threshold of every account that signed it,
+/// so that a multisig tx missing some signatures isn't broadcast. Accounts
+/// unknown to the node are left for the protocol to check.
+async fn check_signed_tx<C: namada_sdk::queries::Client + Sync>(
+    client: &C,
+    tx: &Tx,
+) -> Result<(), error::Error> {
+    tx.validate_tx()
+        .map_err(|err| error::Error::Other(err.to_string()))?;
+    let signers = tx
+        .sections
+        .iter()
+        .filter_map(|section| match section {
+            Section::Authorization(authorization) => {
+                match &authorization.signer {
+                    Signer::Address(signer) => Some(signer.clone()),
+                    Signer::PubKeys(_) => None,
+                }
+            }
+            _ => None,
+        })
+        .collect::<HashSet<Address>>();
+    for signer in signers {
+        let Some(account) =
+            namada_sdk::rpc::get_account_info(client, &signer).await?
+        else {
+            continue;
+        };
+        tx.verify_signatures(
+            &[tx.raw_header_hash()],
+            account.public_keys_map,
+            &Some(signer.clone()),
+            account.threshold,
+            || Ok(()),
+        )
+        .map_err(|err| {
+            error::Error::Other(format!(
+                "The tx doesn't carry the {} signature(s) required by \
+                 {signer}: {err}",
+                account.threshold
+            ))
+        })?;
+    }
     Ok(())
 }
 
+pub async fn submit_reveal_pk<N: Namada>(
+    namada: &N,
+    args: args::RevealPks,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::queries::Client>::Error: std::fmt::Display,
+{
+    reveal_pks(namada.io(), &args.public_keys, |public_key| {
+        let tx_args = args.tx.clone();
+        let address = Address::from(public_key);
+        async move {
+            reveal_pk_if_needed(
+                namada.io(),
+                &address,
+                tx::has_revealed_pk(namada.client(), &address),
+                || submit_reveal_aux(namada, tx_args, &address),
+            )
+            .await
+        }
+    })
+    .await
+}
+
+/// Reveal the public key of the given address with `submit`, unless
+/// `is_revealed` finds that it has already been revealed on-chain, in which
+/// case no tx is submitted.
+async fn reveal_pk_if_needed<IsRevealed, Submit, Fut>(
+    io: &impl Io,
+    address: &Address,
+    is_revealed: IsRevealed,
+    submit: Submit,
+) -> Result<(), error::Error>
+where
+    IsRevealed: Future<Output = Result<bool, error::Error>>,
+    Submit: FnOnce() -> Fut,
+    Fut: Future<Output = Result<(), error::Error>>,
+{
+    if is_revealed.await? {
+        display_line!(
+            io,
+            "The public key for address {address} is already revealed, \
+             skipping it."
+        );
+        return Ok(());
+    }
+    submit().await
+}
+
+/// Reveal each of the given public keys with `reveal`, reporting the keys that
+/// failed. A failure doesn't prevent the remaining keys from being revealed,
+/// but the first error is returned once all the keys have been tried.
+async fn reveal_pks<F, Fut>(
+    io: &impl Io,
+    public_keys: &[common::PublicKey],
+    mut reveal: F,
+) -> Result<(), error::Error>
+where
+    F: FnMut(&common::PublicKey) -> Fut,
+    Fut: Future<Output = Result<(), error::Error>>,
+{
+    let mut first_err = None;
+    for public_key in public_keys {
+        if let Err(err) = reveal(public_key).await {
+            if public_keys.len() > 1 {
+                edisplay_line!(
+                    io,
+                    "Failed to reveal the public key {public_key}: {err}"
+                );
+            }
+            first_err.get_or_insert(err);
+        }
+    }
+    match first_err {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
 pub async fn submit_bond<N: Namada>(
     namada: &N,
     args: args::Bond,
@@ -1363,12 +1707,13 @@ where
     Ok(())
 }
 
-/// Save accounts initialized from a tx into the wallet, if any.
+/// Save accounts initialized from a tx into the wallet, if any. Returns the
+/// aliases under which the accounts were saved.
 pub async fn save_initialized_accounts(
     namada: &impl Namada,
     args: &args::Tx,
     initialized_accounts: Vec<Address>,
-) {
+) -> Vec<String> {
     tx::save_initialized_accounts(namada, args, initialized_accounts).await
 }
 
@@ -1426,3 +1771,328 @@ pub async fn gen_ibc_shielding_transfer(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use namada_sdk::account::init_account_storage;
+    use namada_sdk::address::testing::{established_address_1, nam};
+    use namada_sdk::chain::ChainId;
+    use namada_sdk::hash::Hash;
+    use namada_sdk::io::StdIo;
+    use namada_sdk::key::testing::{keypair_1, keypair_2, keypair_3};
+    use namada_sdk::masp::fs::FsShieldedUtils;
+    use namada_sdk::masp::ShieldedContext;
+    use namada_sdk::queries::testing::TestClient;
+    use namada_sdk::queries::{Rpc, RPC};
+    use namada_sdk::state::StorageWrite;
+    use namada_sdk::storage::Key;
+    use namada_sdk::token::{Amount, DenominatedAmount};
+    use namada_sdk::tx::data::{Fee, GasLimit};
+    use namada_sdk::NamadaImpl;
+
+    use super::*;
+    use crate::wallet::CliWalletUtils;
+
+    #[tokio::test]
+    async fn test_reveal_pks() {
+        let public_keys = vec![
+            keypair_1().ref_to(),
+            keypair_2().ref_to(),
+            keypair_3().ref_to(),
+        ];
+
+        let mut revealed = vec![];
+        reveal_pks(&StdIo, &public_keys, |public_key| {
+            revealed.push(public_key.clone());
+            async { Ok(()) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(revealed, public_keys);
+
+        // A failure is returned, but the other keys are still revealed
+        let mut revealed = vec![];
+        let result = reveal_pks(&StdIo, &public_keys, |public_key| {
+            revealed.push(public_key.clone());
+            let is_second = *public_key == public_keys[1];
+            async move {
+                if is_second {
+                    Err(error::Error::Other("rejected".to_string()))
+                } else {
+                    Ok(())
+                }
+            }
+        })
+        .await;
+        assert!(matches!(
+            result,
+            Err(error::Error::Other(msg)) if msg == "rejected"
+        ));
+        assert_eq!(revealed, public_keys);
+    }
+
+    #[tokio::test]
+    async fn test_reveal_pk_if_needed() {
+        let address = Address::from(&keypair_1().ref_to());
+
+        // Nothing is submitted for a key that is already revealed
+        let mut submitted = false;
+        reveal_pk_if_needed(&StdIo, &address, async { Ok(true) }, || {
+            submitted = true;
+            async { Ok(()) }
+        })
+        .await
+        .unwrap();
+        assert!(!submitted);
+
+        let mut submitted = false;
+        reveal_pk_if_needed(&StdIo, &address, async { Ok(false) }, || {
+            submitted = true;
+            async { Ok(()) }
+        })
+        .await
+        .unwrap();
+        assert!(submitted);
+    }
+
+    /// A context whose wallet holds the given keys and whose client has no
+    /// accounts, so that a tx can only be signed without querying the node
+    fn offline_signer(
+        keypairs: Vec<common::SecretKey>,
+    ) -> NamadaImpl<TestClient<Rpc>, CliWalletUtils, FsShieldedUtils, StdIo>
+    {
+        let mut wallet = CliWalletUtils::new(PathBuf::new());
+        for (ix, keypair) in keypairs.into_iter().enumerate() {
+            wallet
+                .insert_keypair(
+                    format!("signer-{ix}"),
+                    true,
+                    keypair,
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+        }
+        NamadaImpl::native_new(
+            TestClient::new(RPC),
+            wallet,
+            ShieldedContext::<FsShieldedUtils>::default(),
+            StdIo,
+            nam(),
+        )
+    }
+
+    /// An unsigned tx whose wrapper is paid for by the given key
+    fn unsigned_tx(fee_payer: common::PublicKey) -> Tx {
+        let mut unsigned = Tx::new(ChainId::default(), None);
+        unsigned
+            .add_code_from_hash(Hash::sha256(b"tx_transfer.wasm"), None)
+            .add_serialized_data(vec![1, 2, 3])
+            .add_wrapper(
+                Fee {
+                    amount_per_gas_unit: DenominatedAmount::native(
+                        Amount::from_u64(1),
+                    ),
+                    token: nam(),
+                },
+                fee_payer,
+                GasLimit::from(50_000),
+            );
+        unsigned
+    }
+
+    #[tokio::test]
+    async fn test_offline_sign_tx_round_trip() {
+        let account_public_keys =
+            vec![keypair_1().ref_to(), keypair_2().ref_to()];
+        // The fee payer picked when building the tx isn't an account key
+        let fee_payer = keypair_3().ref_to();
+        let namada =
+            offline_signer(vec![keypair_1(), keypair_2(), keypair_3()]);
+
+        let unsigned = unsigned_tx(fee_payer);
+        let output = tempfile::tempdir().unwrap();
+        let signed_path = output.path().join("signed.tx");
+        let owner = established_address_1();
+        sign_tx(
+            &namada,
+            args::SignTx {
+                tx: namada.tx_builder(),
+                tx_data: serde_json::to_vec(&unsigned.serialize()).unwrap(),
+                owner: owner.clone(),
+                account_public_keys: account_public_keys.clone(),
+                threshold: Some(2),
+                output: Some(signed_path.clone()),
+            },
+        )
+        .await
+        .unwrap();
+
+        let signed_bytes = std::fs::read(&signed_path).unwrap();
+        let signed = Tx::deserialize(&signed_bytes).unwrap();
+        assert_eq!(signed.raw_header_hash(), unsigned.raw_header_hash());
+        // The owner's signatures of the inner tx meet the threshold
+        let owner_signature = signed.verify_signatures(
+            &[signed.raw_header_hash()],
+            AccountPublicKeysMap::from_iter(account_public_keys),
+            &Some(owner),
+            2,
+            || Ok(()),
+        );
+        assert!(owner_signature.is_ok());
+        // The fee payer's signature of the wrapper verifies
+        assert!(signed.validate_tx().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_offline_sign_tx_multisig() {
+        let account_public_keys =
+            vec![keypair_1().ref_to(), keypair_2().ref_to()];
+        let owner = established_address_1();
+        let unsigned = unsigned_tx(keypair_3().ref_to());
+        let output = tempfile::tempdir().unwrap();
+        let sign_args =
+            |namada: &NamadaImpl<_, _, _, _>,
+             tx_data: Vec<u8>,
+             output: PathBuf| args::SignTx {
+                tx: namada.tx_builder(),
+                tx_data,
+                owner: owner.clone(),
+                account_public_keys: account_public_keys.clone(),
+                threshold: Some(2),
+                output: Some(output),
+            };
+        let meets_threshold = |tx: &Tx| {
+            tx.verify_signatures(
+                &[tx.raw_header_hash()],
+                AccountPublicKeysMap::from_iter(account_public_keys.clone()),
+                &Some(owner.clone()),
+                2,
+                || Ok(()),
+            )
+            .is_ok()
+        };
+
+        // The first signer holds only one of the two required keys, so the
+        // tx is written without the fee payer's signature
+        let first_signer = offline_signer(vec![keypair_1()]);
+        let partial_path = output.path().join("partial.tx");
+        let unsigned_data = serde_json::to_vec(&unsigned.serialize()).unwrap();
+        sign_tx(
+            &first_signer,
+            sign_args(
+                &first_signer,
+                unsigned_data.clone(),
+                partial_path.clone(),
+            ),
+        )
+        .await
+        .unwrap();
+        let partial_data = std::fs::read(&partial_path).unwrap();
+        let partial = Tx::deserialize(&partial_data).unwrap();
+        assert!(!meets_threshold(&partial));
+        assert!(partial.validate_tx().is_err());
+        // The same key can't sign it twice
+        let resigned_path = output.path().join("resigned.tx");
+        let resigned = sign_tx(
+            &first_signer,
+            sign_args(
+                &first_signer,
+                partial_data.clone(),
+                resigned_path.clone(),
+            ),
+        )
+        .await;
+        assert!(resigned.is_err());
+        assert!(!resigned_path.exists());
+
+        // The second signer adds their signature to the first one's
+        let second_signer = offline_signer(vec![keypair_2()]);
+        let collected_path = output.path().join("collected.tx");
+        sign_tx(
+            &second_signer,
+            sign_args(&second_signer, partial_data, collected_path.clone()),
+        )
+        .await
+        .unwrap();
+        let collected_data = std::fs::read(&collected_path).unwrap();
+        let collected = Tx::deserialize(&collected_data).unwrap();
+        assert!(meets_threshold(&collected));
+        assert!(collected.validate_tx().is_err());
+
+        // The fee payer signs the wrapper last
+        let fee_payer = offline_signer(vec![keypair_3()]);
+        let signed_path = output.path().join("signed.tx");
+        sign_tx(
+            &fee_payer,
+            sign_args(&fee_payer, collected_data, signed_path.clone()),
+        )
+        .await
+        .unwrap();
+        let signed =
+            Tx::deserialize(&std::fs::read(&signed_path).unwrap()).unwrap();
+        assert_eq!(signed.raw_header_hash(), unsigned.raw_header_hash());
+        assert!(meets_threshold(&signed));
+        assert!(signed.validate_tx().is_ok());
+
+        // The threshold of an established account must be given
+        let missing_threshold = args::SignTx {
+            threshold: None,
+            ..sign_args(&first_signer, unsigned_data.clone(), PathBuf::new())
+        };
+        assert!(sign_tx(&first_signer, missing_threshold).await.is_err());
+        // It can't exceed the number of keys
+        let invalid_threshold = args::SignTx {
+            threshold: Some(3),
+            ..sign_args(&first_signer, unsigned_data, PathBuf::new())
+        };
+        assert!(sign_tx(&first_signer, invalid_threshold).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_check_signed_tx() {
+        let mut client = TestClient::new(RPC);
+        let owner = established_address_1();
+        let account_public_keys = AccountPublicKeysMap::from_iter([
+            keypair_1().ref_to(),
+            keypair_2().ref_to(),
+        ]);
+        client
+            .state
+            .write(
+                &Key::validity_predicate(&owner),
+                Hash::sha256(b"vp_user.wasm"),
+            )
+            .unwrap();
+        init_account_storage(
+            &mut client.state,
+            &owner,
+            &[keypair_1().ref_to(), keypair_2().ref_to()],
+            2,
+        )
+        .unwrap();
+        client.state.commit_block().unwrap();
+
+        // The wrapper must be signed by the fee payer
+        let mut tx = unsigned_tx(keypair_3().ref_to());
+        tx.sign_raw(
+            vec![keypair_1()],
+            account_public_keys.clone(),
+            Some(owner.clone()),
+        );
+        assert!(check_signed_tx(&client, &tx).await.is_err());
+
+        // The owner's signatures must meet its threshold
+        let mut partial = tx.clone();
+        partial.sign_wrapper(keypair_3());
+        assert!(partial.validate_tx().is_ok());
+        assert!(check_signed_tx(&client, &partial).await.is_err());
+
+        tx.sign_raw(vec![keypair_2()], account_public_keys, Some(owner));
+        tx.sign_wrapper(keypair_3());
+        check_signed_tx(&client, &tx).await.unwrap();
+    }
+}
@@ -1,10 +1,11 @@
 use std::env;
-use std::fs::{self, File, OpenOptions};
+use std::fs::{self, OpenOptions};
 use std::io::Write;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use borsh_ext::BorshSerializeExt;
 use color_eyre::owo_colors::OwoColorize;
+use data_encoding::HEXLOWER_PERMISSIVE;
 use flate2::read::GzDecoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
@@ -14,16 +15,19 @@ use namada_sdk::dec::Dec;
 use namada_sdk::key::*;
 use namada_sdk::string_encoding::StringEncoded;
 use namada_sdk::token;
+use namada_sdk::tx::is_valid_commission_rate;
 use namada_sdk::uint::Uint;
 use namada_sdk::wallet::{alias, Wallet};
 use namada_vm::validate_untrusted_wasm;
 use prost::bytes::Bytes;
+use rand::rngs::{OsRng, StdRng};
+use rand::SeedableRng;
 use serde_json::json;
 use sha2::{Digest, Sha256};
 use tokio::sync::RwLock;
 
-use crate::cli::args;
-use crate::cli::context::wasm_dir_from_env_or;
+use crate::cli::context::{wasm_dir_from_env_or, wasm_dir_from_env_or_args};
+use crate::cli::{args, Context};
 use crate::config::genesis::chain::DeriveEstablishedAddress;
 use crate::config::genesis::transactions::{
     sign_delegation_bond_tx, sign_validator_account_tx, UnsignedTransactions,
@@ -57,6 +61,7 @@ pub async fn join_network(
         dont_prefetch_wasm,
         allow_duplicate_ip,
         add_persistent_peers,
+        from_archive,
     }: args::JoinNetwork,
 ) {
     use tokio::fs;
@@ -122,7 +127,15 @@ pub async fn join_network(
         });
 
     let release_filename = format!("{}.tar.gz", chain_id);
-    let net_config = if let Some(configs_dir) = network_configs_dir() {
+    let net_config = if let Some(archive_path) = from_archive.as_ref() {
+        fs::read(archive_path).await.unwrap_or_else(|err| {
+            eprintln!(
+                "Failed to read the release archive {}: {err}.",
+                archive_path.to_string_lossy()
+            );
+            safe_exit(1)
+        })
+    } else if let Some(configs_dir) = network_configs_dir() {
         fs::read(PathBuf::from(&configs_dir).join(release_filename))
             .await
             .unwrap_or_else(|err| {
@@ -151,11 +164,17 @@ pub async fn join_network(
         release.to_vec()
     };
 
-    // Decode and unpack the archive
-    let decoder = GzDecoder::new(&net_config[..]);
-    let mut archive = tar::Archive::new(decoder);
-    archive.unpack(&base_dir_full).unwrap();
-    _ = archive;
+    // Decode and unpack the archive. Only a local archive given by the user
+    // is checked, the downloaded ones are unpacked as they are.
+    if let Err(err) = unpack_release_archive(
+        &net_config,
+        &chain_id,
+        &base_dir_full,
+        from_archive.is_some(),
+    ) {
+        eprintln!("{err}");
+        safe_exit(1)
+    }
 
     // Read the genesis files
     let genesis = genesis::chain::Finalized::read_toml_files(&chain_dir)
@@ -166,6 +185,13 @@ pub async fn join_network(
             );
             safe_exit(1)
         });
+    if genesis.metadata.chain_id != chain_id {
+        eprintln!(
+            "The genesis files are for chain ID {}, not {chain_id}.",
+            genesis.metadata.chain_id
+        );
+        safe_exit(1)
+    }
 
     // Try to find validator data when using a pre-genesis validator
     let validator_keys = validator_alias_and_pre_genesis_wallet.as_ref().map(
@@ -250,7 +276,10 @@ pub async fn join_network(
         config.wasm_dir = wasm_dir_full;
     }
 
-    if !dont_prefetch_wasm {
+    if from_archive.is_some() {
+        // Don't download anything when joining offline
+        check_archived_wasms(&config.wasm_dir);
+    } else if !dont_prefetch_wasm {
         fetch_wasms_aux(&chain_id, &config.wasm_dir).await;
     }
 
@@ -261,6 +290,104 @@ pub async fn join_network(
     println!("Successfully configured for chain ID {chain_id}");
 }
 
+/// Unpack a release archive into the base dir. When `strict`, the archive
+/// must contain the chain dir of the given chain and nothing else than the
+/// global config.
+fn unpack_release_archive(
+    release: &[u8],
+    chain_id: &ChainId,
+    base_dir: &Path,
+    strict: bool,
+) -> Result<(), String> {
+    if strict {
+        check_release_archive(release, chain_id)?;
+    }
+    let mut archive = tar::Archive::new(GzDecoder::new(release));
+    archive
+        .unpack(base_dir)
+        .map_err(|err| format!("Failed to unpack the release archive: {err}"))
+}
+
+/// Check that a release archive contains the chain dir of the given chain and
+/// nothing else than the global config.
+fn check_release_archive(
+    release: &[u8],
+    chain_id: &ChainId,
+) -> Result<(), String> {
+    let global_config_path = GlobalConfig::file_path("");
+    let mut archive = tar::Archive::new(GzDecoder::new(release));
+    let entries = archive
+        .entries()
+        .map_err(|err| format!("Failed to read the release archive: {err}"))?;
+    let mut has_chain_dir = false;
+    for entry in entries {
+        let entry = entry.map_err(|err| {
+            format!("Failed to read the release archive: {err}")
+        })?;
+        let path = entry.path().map_err(|err| {
+            format!("Failed to read the release archive: {err}")
+        })?;
+        // Archives made with e.g. `tar -czf <archive> .` prefix the paths
+        // with `./`
+        let path: PathBuf = path
+            .components()
+            .filter(|component| *component != Component::CurDir)
+            .collect();
+        if path.as_os_str().is_empty() {
+            continue;
+        }
+        if path.starts_with(chain_id.as_str()) {
+            has_chain_dir = true;
+        } else if path != global_config_path {
+            return Err(format!(
+                "The release archive contains {}, which is not part of the \
+                 chain {chain_id}.",
+                path.to_string_lossy()
+            ));
+        }
+    }
+    if !has_chain_dir {
+        return Err(format!(
+            "The release archive doesn't contain the chain {chain_id}."
+        ));
+    }
+    Ok(())
+}
+
+/// Check the wasms unpacked from a release archive against their checksums.
+/// Missing wasms are reported, but invalid ones are an error.
+fn check_archived_wasms(wasm_dir: &Path) {
+    let checksums = wasm_loader::Checksums::read_checksums(wasm_dir)
+        .unwrap_or_else(|_| safe_exit(1));
+    let wasms =
+        wasm_loader::verify_wasms(wasm_dir, &checksums).unwrap_or_else(|err| {
+            eprintln!("{err}");
+            safe_exit(1)
+        });
+    let mut missing = 0;
+    for (name, full_name, status) in wasms {
+        match status {
+            wasm_loader::WasmStatus::Valid => {}
+            wasm_loader::WasmStatus::Mismatch(derived_name) => {
+                eprintln!(
+                    "The wasm {name} from the release archive doesn't match \
+                     its checksum: expected {full_name}, got {derived_name}."
+                );
+                safe_exit(1)
+            }
+            wasm_loader::WasmStatus::Missing => missing += 1,
+        }
+    }
+    if missing > 0 {
+        println!(
+            "{missing} wasm(s) are not in the release archive. Copy them to \
+             {} before running the node, and check them with `namadac utils \
+             verify-wasm`.",
+            wasm_dir.to_string_lossy()
+        );
+    }
+}
+
 async fn fetch_wasms_aux(chain_id: &ChainId, wasm_dir: &Path) {
     println!("Fetching missing wasms for chain ID {chain_id}...");
     wasm_loader::pre_fetch_wasm(wasm_dir).await;
@@ -277,6 +404,52 @@ pub fn validate_wasm(args::ValidateWasm { code_path }: args::ValidateWasm) {
     }
 }
 
+/// Check the wasms in the wasm directory against the given checksums file,
+/// without downloading anything.
+pub fn verify_wasm(global_args: args::Global, args: args::VerifyWasm) {
+    let checksums =
+        wasm_loader::Checksums::read_checksums_file(&args.wasm_checksums_path)
+            .unwrap_or_else(|_| safe_exit(1));
+    // Defaults to the directory of the checksums file
+    let wasm_dir =
+        wasm_dir_from_env_or_args(&global_args).unwrap_or_else(|| {
+            args.wasm_checksums_path
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default()
+        });
+    let wasms = wasm_loader::verify_wasms(&wasm_dir, &checksums)
+        .unwrap_or_else(|err| {
+            eprintln!("{err}");
+            safe_exit(1)
+        });
+
+    let mut invalid = 0;
+    for (name, full_name, status) in wasms {
+        match status {
+            wasm_loader::WasmStatus::Valid => println!("{name}: OK"),
+            wasm_loader::WasmStatus::Mismatch(derived_name) => {
+                invalid += 1;
+                println!(
+                    "{name}: checksum mismatch, expected {full_name}, got \
+                     {derived_name}"
+                );
+            }
+            wasm_loader::WasmStatus::Missing => {
+                invalid += 1;
+                println!("{name}: missing {full_name}");
+            }
+        }
+    }
+    if invalid > 0 {
+        eprintln!(
+            "{invalid} wasm(s) in {} don't match their checksums.",
+            wasm_dir.to_string_lossy()
+        );
+        safe_exit(1)
+    }
+}
+
 /// Length of a Tendermint Node ID in bytes
 const TENDERMINT_NODE_ID_LENGTH: usize = 20;
 
@@ -309,6 +482,7 @@ pub fn init_network(
         genesis_time,
         consensus_timeout_commit,
         archive_dir,
+        deterministic,
     }: args::InitNetwork,
 ) -> PathBuf {
     let base_dir = tempfile::tempdir().unwrap();
@@ -412,25 +586,30 @@ pub fn init_network(
         fs::copy(file, wasm_dir_full.join(&full_name)).unwrap();
     }
 
+    if deterministic {
+        println!(
+            "{}",
+            "Warning: Creating a deterministic release archive. This mode is \
+             intended for reproducible test networks and must NOT be used for \
+             production networks."
+                .bold()
+                .yellow()
+        );
+    }
+
     // Create release tarball
-    let mut release = tar::Builder::new(Vec::new());
-    release
-        .append_dir_all(PathBuf::from(chain_id.as_str()), &chain_dir)
-        .unwrap();
-    let global_config_path = GlobalConfig::file_path(base_dir.path());
-    let release_global_config_path = GlobalConfig::file_path("");
-    release
-        .append_path_with_name(global_config_path, release_global_config_path)
-        .unwrap();
+    let release = build_release_archive(
+        chain_id,
+        &chain_dir,
+        base_dir.path(),
+        deterministic,
+    );
 
-    // Gzip tar release and write to file
+    // Write the gzipped tar release to file
     let release_file = archive_dir
         .unwrap_or_else(|| env::current_dir().unwrap())
         .join(format!("{}.tar.gz", chain_id));
-    let compressed_file = File::create(&release_file).unwrap();
-    let mut encoder = GzEncoder::new(compressed_file, Compression::default());
-    encoder.write_all(&release.into_inner().unwrap()).unwrap();
-    encoder.finish().unwrap();
+    fs::write(&release_file, release).unwrap();
     println!(
         "Release archive created at {}",
         release_file.to_string_lossy()
@@ -439,6 +618,63 @@ pub fn init_network(
     release_file
 }
 
+/// Build the gzipped release tarball containing the given chain directory
+/// and the global config from the `base_dir`.
+///
+/// In deterministic mode, the archive entries are appended in a sorted order
+/// and their file metadata (modification times, owners and permissions) is
+/// normalized, such that the same inputs always produce a byte-identical
+/// archive.
+fn build_release_archive(
+    chain_id: &ChainId,
+    chain_dir: &Path,
+    base_dir: &Path,
+    deterministic: bool,
+) -> Vec<u8> {
+    let mut release = tar::Builder::new(Vec::new());
+    let chain_dir_name = PathBuf::from(chain_id.as_str());
+    if deterministic {
+        release.mode(tar::HeaderMode::Deterministic);
+        append_dir_sorted(&mut release, &chain_dir_name, chain_dir);
+    } else {
+        release.append_dir_all(chain_dir_name, chain_dir).unwrap();
+    }
+    let global_config_path = GlobalConfig::file_path(base_dir);
+    let release_global_config_path = GlobalConfig::file_path("");
+    release
+        .append_path_with_name(global_config_path, release_global_config_path)
+        .unwrap();
+
+    // Gzip the tar release. The gzip header produced by `GzEncoder::new`
+    // carries no timestamp, so the output stays deterministic.
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&release.into_inner().unwrap()).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// Recursively append the contents of `dir` to the `archive` under `name`,
+/// visiting the directory entries in a sorted order.
+fn append_dir_sorted(
+    archive: &mut tar::Builder<Vec<u8>>,
+    name: &Path,
+    dir: &Path,
+) {
+    archive.append_dir(name, dir).unwrap();
+    let mut entries = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    entries.sort();
+    for path in entries {
+        let entry_name = name.join(path.file_name().unwrap());
+        if path.is_dir() {
+            append_dir_sorted(archive, &entry_name, &path);
+        } else {
+            archive.append_path_with_name(&path, &entry_name).unwrap();
+        }
+    }
+}
+
 pub fn pk_to_tm_address(
     _global_args: args::Global,
     args::PkToTmAddress { public_key }: args::PkToTmAddress,
@@ -447,10 +683,23 @@ pub fn pk_to_tm_address(
     println!("{tm_addr}");
 }
 
+/// Print the wasm files available in the wasm directory of the chain with
+/// their hashes.
+pub fn list_wasms(ctx: &Context, _args: args::ListWasms) {
+    let chain_ctx = ctx.borrow_chain_or_exit();
+    for (name, hash) in chain_ctx.list_wasms() {
+        println!("{name}: {hash}");
+    }
+}
+
 pub fn default_base_dir(
     _global_args: args::Global,
-    _args: args::DefaultBaseDir,
+    args: args::DefaultBaseDir,
 ) {
+    if args.json {
+        println!("{}", base_dir_json(&args));
+        return;
+    }
     println!(
         "{}",
         get_default_namada_folder().to_str().expect(
@@ -459,6 +708,14 @@ pub fn default_base_dir(
     );
 }
 
+/// The base dir in use and what set it, as JSON.
+pub fn base_dir_json(args: &args::DefaultBaseDir) -> serde_json::Value {
+    json!({
+        "path": args.base_dir,
+        "source": args.source,
+    })
+}
+
 /// Derive and print all established addresses from the provided
 /// genesis txs toml file.
 pub fn derive_genesis_addresses(
@@ -646,6 +903,7 @@ pub fn init_genesis_validator(
         name,
         tx_path,
         address,
+        seed,
     }: args::InitGenesisValidator,
 ) {
     let contents = fs::read_to_string(&tx_path).unwrap_or_else(|err| {
@@ -675,14 +933,17 @@ pub fn init_genesis_validator(
     }
 
     // Validate the commission rate data
-    if commission_rate > Dec::one() {
-        eprintln!("The validator commission rate must not exceed 1.0 or 100%");
+    if !is_valid_commission_rate(&commission_rate) {
+        eprintln!(
+            "Invalid validator commission rate {commission_rate}, it must be \
+             between 0.0 and 1.0."
+        );
         safe_exit(1)
     }
-    if max_commission_rate_change > Dec::one() {
+    if !is_valid_commission_rate(&max_commission_rate_change) {
         eprintln!(
-            "The validator maximum change in commission rate per epoch must \
-             not exceed 1.0 or 100%"
+            "Invalid validator maximum change in commission rate per epoch \
+             {max_commission_rate_change}, it must be between 0.0 and 1.0."
         );
         safe_exit(1)
     }
@@ -691,14 +952,44 @@ pub fn init_genesis_validator(
         eprintln!("The validator email must not be an empty string");
         safe_exit(1)
     }
+    // Decode the seed of the keys, if any
+    let seed: Option<[u8; 32]> = seed.map(|seed| {
+        let seed =
+            HEXLOWER_PERMISSIVE
+                .decode(seed.as_bytes())
+                .unwrap_or_else(|err| {
+                    eprintln!("The seed must be hex-encoded: {err}");
+                    safe_exit(1)
+                });
+        Sha256::digest(seed).into()
+    });
     let pre_genesis_dir =
         validator_pre_genesis_dir(&global_args.base_dir, &alias);
     println!("Generating validator keys...");
-    let validator_wallet = pre_genesis::gen_and_store(
-        key_scheme,
-        unsafe_dont_encrypt,
-        &pre_genesis_dir,
-    )
+    let validator_wallet = if let Some(seed) = seed {
+        println!(
+            "{}",
+            "Warning: Deriving the validator keys from the given seed. Anyone \
+             who knows the seed can derive the same keys. This mode is \
+             intended for reproducible test networks and must NOT be used \
+             for production networks."
+                .bold()
+                .yellow()
+        );
+        pre_genesis::gen_and_store(
+            key_scheme,
+            unsafe_dont_encrypt,
+            &pre_genesis_dir,
+            &mut StdRng::from_seed(seed),
+        )
+    } else {
+        pre_genesis::gen_and_store(
+            key_scheme,
+            unsafe_dont_encrypt,
+            &pre_genesis_dir,
+            &mut OsRng,
+        )
+    }
     .unwrap_or_else(|err| {
         eprintln!(
             "Unable to generate the validator pre-genesis wallet: {}",
@@ -759,6 +1050,60 @@ pub fn init_genesis_validator(
     println!("{}: {toml_path_str}", "Wrote genesis tx to".bold());
 }
 
+/// Export the keys of a genesis validator from the wallet of the chain into a
+/// pre-genesis validator wallet. The Ethereum cold key is copied from the
+/// validator's existing pre-genesis wallet, as it is never added to the wallet
+/// of the chain.
+pub fn export_pre_genesis_validator(
+    ctx: &Context,
+    args::ExportPreGenesisValidator {
+        alias,
+        pre_genesis_path,
+        output_folder,
+    }: args::ExportPreGenesisValidator,
+) {
+    let pre_genesis_path = pre_genesis_path.unwrap_or_else(|| {
+        validator_pre_genesis_dir(&ctx.global_args.base_dir, &alias)
+    });
+    let eth_cold_key = pre_genesis::load_store(&pre_genesis_path)
+        .unwrap_or_else(|err| {
+            eprintln!(
+                "Unable to read the Ethereum cold key from the validator \
+                 pre-genesis wallet: {err}"
+            );
+            safe_exit(1)
+        })
+        .eth_cold_key;
+    let chain_ctx = ctx.borrow_chain_or_exit();
+    let store = chain_ctx
+        .wallet
+        .store()
+        .export_pre_genesis_validator(&alias.as_str().into(), eth_cold_key)
+        .unwrap_or_else(|| {
+            eprintln!(
+                "The wallet does not contain the keys of a genesis validator \
+                 with alias {alias}"
+            );
+            safe_exit(1)
+        });
+    let wallet_file = pre_genesis::validator_file_name(&output_folder);
+    if wallet_file.exists() {
+        eprintln!(
+            "A pre-genesis validator wallet already exists at {}",
+            wallet_file.to_string_lossy()
+        );
+        safe_exit(1)
+    }
+    pre_genesis::store(&store, &output_folder).unwrap_or_else(|err| {
+        eprintln!("Unable to write the validator pre-genesis wallet: {err}");
+        safe_exit(1)
+    });
+    println!(
+        "The validator's keys were exported to the wallet at {}",
+        wallet_file.to_string_lossy()
+    );
+}
+
 /// Try to load a pre-genesis wallet or return nothing,
 /// if it cannot be found.
 pub fn try_load_pre_genesis_wallet(
@@ -1021,3 +1366,213 @@ fn safe_exit(code: i32) -> ! {
 fn safe_exit(code: i32) -> ! {
     panic!("Process exited unsuccessfully with error code: {}", code);
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use namada_sdk::chain::ChainIdPrefix;
+    use namada_sdk::time::DateTimeUtc;
+
+    use super::*;
+    use crate::time::test_utils::GENESIS_TIME;
+
+    /// Write the same chain dir and global config to a fresh base dir.
+    fn write_test_network(chain_id: &ChainId) -> tempfile::TempDir {
+        let base_dir = tempfile::tempdir().unwrap();
+        let chain_dir = base_dir.path().join(chain_id.as_str());
+        let wasm_dir = chain_dir.join(config::DEFAULT_WASM_DIR);
+        fs::create_dir_all(&wasm_dir).unwrap();
+        for (name, contents) in [
+            ("parameters.toml", "param = 1"),
+            ("validity-predicates.toml", "vp = 2"),
+            ("balances.toml", "balance = 3"),
+        ] {
+            fs::write(chain_dir.join(name), contents).unwrap();
+        }
+        fs::write(wasm_dir.join(config::DEFAULT_WASM_CHECKSUMS_FILE), "{}")
+            .unwrap();
+        GlobalConfig::new(chain_id.clone())
+            .write(base_dir.path())
+            .unwrap();
+        base_dir
+    }
+
+    #[test]
+    fn test_deterministic_release_archive() {
+        let chain_id = ChainId("test-chain.abcdef".to_string());
+
+        let build = |deterministic: bool| {
+            let base_dir = write_test_network(&chain_id);
+            let chain_dir = base_dir.path().join(chain_id.as_str());
+            build_release_archive(
+                &chain_id,
+                &chain_dir,
+                base_dir.path(),
+                deterministic,
+            )
+        };
+
+        let first = build(true);
+        // Make sure that the file modification times differ between runs
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        let second = build(true);
+        assert_eq!(first, second);
+
+        // The archive must still contain the chain dir and global config
+        let mut archive = tar::Archive::new(GzDecoder::new(&first[..]));
+        let paths = archive
+            .entries()
+            .unwrap()
+            .map(|entry| entry.unwrap().path().unwrap().into_owned())
+            .collect::<Vec<_>>();
+        assert!(paths.contains(&GlobalConfig::file_path("")));
+        assert!(paths.contains(
+            &PathBuf::from(chain_id.as_str()).join("parameters.toml")
+        ));
+    }
+
+    /// Test that `init-network` produces a byte-identical release archive
+    /// when it runs twice with the same inputs in deterministic mode.
+    #[test]
+    fn test_init_network_is_reproducible() {
+        let templates_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .join("genesis/localnet");
+        let wasm_dir = tempfile::tempdir().unwrap();
+        let wasm_checksums_path =
+            wasm_dir.path().join(config::DEFAULT_WASM_CHECKSUMS_FILE);
+        fs::write(&wasm_checksums_path, "{}").unwrap();
+
+        let init_network = |genesis_time: &str| {
+            let archive_dir = tempfile::tempdir().unwrap();
+            let global_args = args::Global {
+                is_pre_genesis: false,
+                chain_id: None,
+                base_dir: PathBuf::new(),
+                wasm_dir: Some(wasm_dir.path().to_path_buf()),
+            };
+            let release_file = super::init_network(
+                global_args,
+                args::InitNetwork {
+                    templates_path: templates_path.clone(),
+                    wasm_checksums_path: wasm_checksums_path.clone(),
+                    chain_id_prefix: ChainIdPrefix::from_str("test-prefix")
+                        .unwrap(),
+                    genesis_time: DateTimeUtc::from_str(genesis_time).unwrap(),
+                    consensus_timeout_commit:
+                        crate::facade::tendermint::Timeout::from_str("1s")
+                            .unwrap(),
+                    archive_dir: Some(archive_dir.path().to_path_buf()),
+                    deterministic: true,
+                },
+            );
+            (
+                release_file.file_name().unwrap().to_owned(),
+                fs::read(release_file).unwrap(),
+            )
+        };
+
+        let (first_name, first) = init_network(GENESIS_TIME);
+        // Make sure that the file modification times differ between runs
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        let (second_name, second) = init_network(GENESIS_TIME);
+        assert_eq!(first_name, second_name);
+        assert!(first == second, "The release archives differ");
+
+        // The genesis time is an input, so changing it changes the chain
+        let (other_name, _) = init_network("2024-01-01T00:00:00Z");
+        assert_ne!(first_name, other_name);
+    }
+
+    #[test]
+    fn test_unpack_release_archive() {
+        let chain_id = ChainId("test-chain.abcdef".to_string());
+        let network = write_test_network(&chain_id);
+        let release = build_release_archive(
+            &chain_id,
+            &network.path().join(chain_id.as_str()),
+            network.path(),
+            true,
+        );
+
+        // The archive must be for the chain that is being joined
+        let base_dir = tempfile::tempdir().unwrap();
+        let other_chain_id = ChainId("other-chain.abcdef".to_string());
+        let err = unpack_release_archive(
+            &release,
+            &other_chain_id,
+            base_dir.path(),
+            true,
+        )
+        .unwrap_err();
+        assert!(err.contains("not part of the chain"));
+        assert_eq!(fs::read_dir(base_dir.path()).unwrap().count(), 0);
+
+        // Downloaded archives are not checked
+        let unchecked_dir = tempfile::tempdir().unwrap();
+        unpack_release_archive(
+            &release,
+            &other_chain_id,
+            unchecked_dir.path(),
+            false,
+        )
+        .unwrap();
+        assert!(unchecked_dir.path().join(chain_id.as_str()).is_dir());
+
+        unpack_release_archive(&release, &chain_id, base_dir.path(), true)
+            .unwrap();
+        let chain_dir = base_dir.path().join(chain_id.as_str());
+        assert!(GlobalConfig::file_path(base_dir.path()).is_file());
+        for name in [
+            "parameters.toml",
+            "validity-predicates.toml",
+            "balances.toml",
+        ] {
+            assert_eq!(
+                fs::read(chain_dir.join(name)).unwrap(),
+                fs::read(network.path().join(chain_id.as_str()).join(name))
+                    .unwrap()
+            );
+        }
+        assert!(chain_dir
+            .join(config::DEFAULT_WASM_DIR)
+            .join(config::DEFAULT_WASM_CHECKSUMS_FILE)
+            .is_file());
+
+        // Paths prefixed with `./` are part of the chain too. `tar::Builder`
+        // strips the prefix, so the names are written to the headers as is.
+        let mut builder = tar::Builder::new(Vec::new());
+        let parameters_path = format!("./{chain_id}/parameters.toml");
+        for (path, contents) in [
+            ("./", &b""[..]),
+            (parameters_path.as_str(), &b"parameters"[..]),
+        ] {
+            let mut header = tar::Header::new_ustar();
+            header.as_old_mut().name[..path.len()]
+                .copy_from_slice(path.as_bytes());
+            header.set_entry_type(if contents.is_empty() {
+                tar::EntryType::Directory
+            } else {
+                tar::EntryType::Regular
+            });
+            header.set_mode(0o755);
+            header.set_size(contents.len() as u64);
+            header.set_cksum();
+            builder.append(&header, contents).unwrap();
+        }
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&builder.into_inner().unwrap()).unwrap();
+        let release = encoder.finish().unwrap();
+        let base_dir = tempfile::tempdir().unwrap();
+        unpack_release_archive(&release, &chain_id, base_dir.path(), true)
+            .unwrap();
+        assert_eq!(
+            fs::read(base_dir.path().join(&parameters_path)).unwrap(),
+            b"parameters"
+        );
+    }
+}
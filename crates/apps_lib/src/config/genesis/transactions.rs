@@ -74,6 +74,7 @@ fn get_tx_args(use_device: bool) -> TxArgs {
         dry_run_wrapper: false,
         dump_tx: false,
         output_folder: None,
+        dump_tx_path: None,
         force: false,
         broadcast_only: false,
         ledger_address: tendermint_rpc::Url::from_str("http://127.0.0.1:26657")
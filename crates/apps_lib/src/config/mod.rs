@@ -14,7 +14,7 @@ use directories::ProjectDirs;
 use namada_sdk::chain::ChainId;
 use namada_sdk::collections::HashMap;
 use namada_sdk::storage::BlockHeight;
-use namada_sdk::time::Rfc3339String;
+use namada_sdk::time::{DateTimeUtc, Rfc3339String};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -82,12 +82,14 @@ pub enum Action {
 }
 
 /// An action to be performed at a
-/// certain block height along with the
-/// given height.
+/// certain block height or block time
+/// along with the given height and time.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionAtHeight {
     /// The height at which to take action.
-    pub height: BlockHeight,
+    pub height: Option<BlockHeight>,
+    /// The block time at or after which to take action.
+    pub time: Option<DateTimeUtc>,
     /// The action to take.
     pub action: Action,
 }
@@ -121,7 +123,8 @@ pub struct Shell {
     db_dir: PathBuf,
     /// Use the [`Ledger::cometbft_dir()`] method to read the value.
     cometbft_dir: PathBuf,
-    /// An optional action to take when a given blockheight is reached.
+    /// An optional action to take when a given block height or block time is
+    /// reached.
     pub action_at_height: Option<ActionAtHeight>,
     /// Specify if tendermint is started as validator, fullnode or seednode
     pub tendermint_mode: TendermintMode,
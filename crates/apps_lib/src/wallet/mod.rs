@@ -12,14 +12,32 @@ pub use namada_sdk::wallet::alias::Alias;
 use namada_sdk::wallet::fs::FsWalletStorage;
 use namada_sdk::wallet::store::Store;
 use namada_sdk::wallet::{
-    ConfirmationResponse, FindKeyError, Wallet, WalletIo,
+    mnemonic_type_for_word_count, ConfirmationResponse, FindKeyError, Wallet,
+    WalletIo,
 };
 pub use namada_sdk::wallet::{ValidatorData, ValidatorKeys};
 use rand_core::OsRng;
 pub use store::wallet_file;
+use thiserror::Error;
 use zeroize::Zeroizing;
 
 use crate::cli;
+
+/// The number of times the user is prompted for a mnemonic code before
+/// giving up.
+const MNEMONIC_INPUT_ATTEMPTS: usize = 3;
+
+/// Errors that can occur when validating a mnemonic code input by the user
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum MnemonicInputError {
+    #[error("Invalid number of words {0}, expected 12, 15, 18, 21 or 24")]
+    InvalidWordCount(usize),
+    #[error("Word {0} is not in the BIP39 English wordlist")]
+    UnknownWord(usize),
+    #[error("The mnemonic code checksum is invalid")]
+    InvalidChecksum,
+}
+
 #[derive(Debug, Clone)]
 pub struct CliWalletUtils {
     store_dir: PathBuf,
@@ -86,13 +104,13 @@ impl WalletIo for CliWalletUtils {
     }
 
     fn read_mnemonic_code() -> Option<Mnemonic> {
-        let phrase = get_secure_user_input("Input mnemonic code: ")
-            .unwrap_or_else(|e| {
+        read_mnemonic_code_with_retries(MNEMONIC_INPUT_ATTEMPTS, || {
+            get_secure_user_input("Input mnemonic code: ").unwrap_or_else(|e| {
                 eprintln!("{}", e);
                 eprintln!("Action cancelled, no changes persisted.");
                 cli::safe_exit(1)
-            });
-        Mnemonic::from_phrase(phrase.as_ref(), Language::English).ok()
+            })
+        })
     }
 
     fn read_mnemonic_passphrase(confirm: bool) -> Zeroizing<String> {
@@ -110,7 +128,8 @@ impl WalletIo for CliWalletUtils {
 
     // The given alias has been selected but conflicts with another alias in
     // the store. Offer the user to either replace existing mapping, alter the
-    // chosen alias to a name of their choosing, or cancel the aliasing.
+    // chosen alias to a name of their choosing, cancel the aliasing or abort
+    // the whole operation.
     fn show_overwrite_confirmation(
         alias: &Alias,
         alias_for: &str,
@@ -118,13 +137,13 @@ impl WalletIo for CliWalletUtils {
         print!(
             "You're trying to create an alias \"{}\" that already exists for \
              {} in your store.\nWould you like to replace it? \
-             s(k)ip/re(p)lace/re(s)elect: ",
+             s(k)ip/re(p)lace/re(s)elect/(a)bort: ",
             alias, alias_for
         );
         io::stdout().flush().unwrap();
 
         let mut buffer = String::new();
-        // Get the user to select between 3 choices
+        // Get the user to select between 4 choices
         match io::stdin().read_line(&mut buffer) {
             Ok(size) if size > 0 => {
                 // Isolate the single character representing the choice
@@ -143,6 +162,7 @@ impl WalletIo for CliWalletUtils {
                         }
                     }
                     'k' | 'K' => return ConfirmationResponse::Skip,
+                    'a' | 'A' => return ConfirmationResponse::Abort,
                     // Input is senseless fall through to repeat prompt
                     _ => {}
                 };
@@ -155,6 +175,47 @@ impl WalletIo for CliWalletUtils {
     }
 }
 
+/// Validate the word count, words and checksum of a BIP39 mnemonic code.
+pub fn validate_mnemonic_phrase(
+    phrase: &str,
+) -> Result<Mnemonic, MnemonicInputError> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    mnemonic_type_for_word_count(words.len())
+        .map_err(|_| MnemonicInputError::InvalidWordCount(words.len()))?;
+    let wordmap = Language::English.wordmap();
+    if let Some(index) = words
+        .iter()
+        .position(|word| wordmap.get_bits(word).is_err())
+    {
+        return Err(MnemonicInputError::UnknownWord(index.saturating_add(1)));
+    }
+    // the word count and words are valid, so the only remaining failure is
+    // an invalid checksum
+    Mnemonic::from_phrase(phrase, Language::English)
+        .map_err(|_| MnemonicInputError::InvalidChecksum)
+}
+
+/// Read a mnemonic code with the given reader until a valid one is provided,
+/// for at most `attempts` times.
+fn read_mnemonic_code_with_retries(
+    attempts: usize,
+    mut read_phrase: impl FnMut() -> Zeroizing<String>,
+) -> Option<Mnemonic> {
+    for attempt in 1..=attempts {
+        let phrase = read_phrase();
+        match validate_mnemonic_phrase(&phrase) {
+            Ok(mnemonic) => return Some(mnemonic),
+            Err(err) if attempt < attempts => {
+                eprintln!("{err}. Please try again.");
+            }
+            Err(err) => {
+                eprintln!("{err}.");
+            }
+        }
+    }
+    None
+}
+
 fn get_secure_user_input<S>(request: S) -> std::io::Result<Zeroizing<String>>
 where
     S: std::fmt::Display,
@@ -197,6 +258,10 @@ pub fn gen_validator_keys<U: WalletIo>(
     protocol_pk: Option<common::PublicKey>,
     protocol_key_scheme: SchemeType,
 ) -> Result<ValidatorKeys, FindKeyError> {
+    validate_validator_key_schemes(
+        eth_bridge_pk.as_ref(),
+        protocol_pk.is_none().then_some(protocol_key_scheme),
+    )?;
     let protocol_keypair = find_secret_key(wallet, protocol_pk, |data| {
         data.keys.protocol_keypair.clone()
     })?;
@@ -207,9 +272,32 @@ pub fn gen_validator_keys<U: WalletIo>(
         eth_bridge_keypair,
         protocol_keypair,
         protocol_key_scheme,
+        &mut OsRng,
     ))
 }
 
+/// Check that the validator keys can be of the given schemes, before looking
+/// them up or generating them. The Ethereum bridge key must be a Secp256k1
+/// key, and a protocol key can only be generated for a concrete scheme.
+fn validate_validator_key_schemes(
+    eth_bridge_pk: Option<&common::PublicKey>,
+    protocol_key_scheme: Option<SchemeType>,
+) -> Result<(), FindKeyError> {
+    if let Some(common::PublicKey::Ed25519(_)) = eth_bridge_pk {
+        return Err(FindKeyError::UnsupportedScheme {
+            role: "Ethereum bridge",
+            scheme: SchemeType::Ed25519,
+        });
+    }
+    if let Some(SchemeType::Common) = protocol_key_scheme {
+        return Err(FindKeyError::UnsupportedScheme {
+            role: "protocol",
+            scheme: SchemeType::Common,
+        });
+    }
+    Ok(())
+}
+
 /// Find a corresponding [`common::SecretKey`] in [`Wallet`], for some
 /// [`common::PublicKey`].
 ///
@@ -287,9 +375,13 @@ pub fn read_and_confirm_encryption_password(
 #[cfg(test)]
 mod tests {
     use namada_sdk::bip39::MnemonicType;
-    use namada_sdk::wallet::WalletIo;
+    use namada_sdk::wallet::{gen_secret_key, WalletIo};
 
-    use super::CliWalletUtils;
+    use super::*;
+
+    const VALID_PHRASE: &str = "abandon abandon abandon abandon abandon \
+                                abandon abandon abandon abandon abandon \
+                                abandon about";
 
     #[test]
     fn test_generate_mnemonic() {
@@ -302,4 +394,109 @@ mod tests {
             CliWalletUtils::generate_mnemonic_code(MNEMONIC_TYPE, &mut rng);
         assert_ne!(mnemonic1.into_phrase(), mnemonic2.into_phrase());
     }
+
+    #[test]
+    fn test_wordmap_get_bits() {
+        let wordmap = Language::English.wordmap();
+        assert_eq!(u16::from(wordmap.get_bits("abandon").unwrap()), 0);
+        assert_eq!(u16::from(wordmap.get_bits("zoo").unwrap()), 2047);
+        assert!(wordmap.get_bits("notaword").is_err());
+        // Words are matched exactly
+        assert!(wordmap.get_bits("Abandon").is_err());
+    }
+
+    #[test]
+    fn test_mnemonic_unknown_word() {
+        let mut words: Vec<_> = VALID_PHRASE.split_whitespace().collect();
+        words[6] = "notaword";
+        let phrase = words.join(" ");
+        assert_eq!(
+            validate_mnemonic_phrase(&phrase).unwrap_err(),
+            MnemonicInputError::UnknownWord(7)
+        );
+    }
+
+    #[test]
+    fn test_mnemonic_invalid_checksum() {
+        let phrase = VALID_PHRASE.replace("about", "abandon");
+        assert_eq!(
+            validate_mnemonic_phrase(&phrase).unwrap_err(),
+            MnemonicInputError::InvalidChecksum
+        );
+    }
+
+    #[test]
+    fn test_mnemonic_invalid_word_count() {
+        let phrase = VALID_PHRASE.replacen("abandon ", "", 1);
+        assert_eq!(
+            validate_mnemonic_phrase(&phrase).unwrap_err(),
+            MnemonicInputError::InvalidWordCount(11)
+        );
+    }
+
+    #[test]
+    fn test_read_mnemonic_code_retries() {
+        let bad_checksum = VALID_PHRASE.replace("about", "abandon");
+        let mut inputs = vec![
+            Zeroizing::new("notaword".to_string()),
+            Zeroizing::new(bad_checksum),
+            Zeroizing::new(VALID_PHRASE.to_string()),
+        ]
+        .into_iter();
+        let mnemonic = read_mnemonic_code_with_retries(3, || {
+            inputs.next().expect("Should not read more than 3 inputs")
+        })
+        .expect("The last input is a valid mnemonic");
+        assert_eq!(
+            mnemonic.phrase().split_whitespace().collect::<Vec<_>>(),
+            VALID_PHRASE.split_whitespace().collect::<Vec<_>>()
+        );
+
+        // give up after running out of attempts
+        let mut reads = 0_u32;
+        let mnemonic = read_mnemonic_code_with_retries(2, || {
+            reads = reads.saturating_add(1);
+            Zeroizing::new("notaword".to_string())
+        });
+        assert!(mnemonic.is_none());
+        assert_eq!(reads, 2);
+    }
+
+    #[test]
+    fn test_gen_validator_keys_schemes() {
+        let mut wallet = CliWalletUtils::new(PathBuf::new());
+        let keys =
+            gen_validator_keys(&mut wallet, None, None, SchemeType::Ed25519)
+                .expect("Ed25519 protocol keys are supported");
+        assert!(matches!(
+            keys.protocol_keypair,
+            common::SecretKey::Ed25519(_)
+        ));
+        assert!(matches!(
+            keys.eth_bridge_keypair,
+            common::SecretKey::Secp256k1(_)
+        ));
+
+        let err =
+            gen_validator_keys(&mut wallet, None, None, SchemeType::Common)
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The protocol key cannot use the Common scheme"
+        );
+
+        let ed25519_pk =
+            gen_secret_key(SchemeType::Ed25519, &mut OsRng).ref_to();
+        let err = gen_validator_keys(
+            &mut wallet,
+            Some(ed25519_pk),
+            None,
+            SchemeType::Ed25519,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The Ethereum bridge key cannot use the Ed25519 scheme"
+        );
+    }
 }
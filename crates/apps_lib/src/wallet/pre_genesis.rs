@@ -8,7 +8,7 @@ use namada_sdk::wallet::pre_genesis::{
     ReadError, ValidatorStore, ValidatorWallet,
 };
 use namada_sdk::wallet::{gen_key_to_store, WalletIo};
-use rand::rngs::OsRng;
+use rand::{CryptoRng, Rng};
 use zeroize::Zeroizing;
 
 use crate::wallet::store::gen_validator_keys;
@@ -22,16 +22,23 @@ pub fn validator_file_name(store_dir: impl AsRef<Path>) -> PathBuf {
     store_dir.as_ref().join(VALIDATOR_FILE_NAME)
 }
 
-/// Generate a new [`ValidatorWallet`] with required pre-genesis keys and
-/// store it as TOML at the given path.
+/// Generate a new [`ValidatorWallet`] with required pre-genesis keys from the
+/// given RNG and store it as TOML at the given path.
 pub fn gen_and_store(
     scheme: SchemeType,
     unsafe_dont_encrypt: bool,
     store_dir: &Path,
+    rng: &mut (impl CryptoRng + Rng),
 ) -> std::io::Result<ValidatorWallet> {
     let password = read_and_confirm_encryption_password(unsafe_dont_encrypt);
-    let validator = gen(scheme, password);
-    let data = validator.store.encode();
+    let validator = gen(scheme, password, rng);
+    store(&validator.store, store_dir)?;
+    Ok(validator)
+}
+
+/// Store the given [`ValidatorStore`] as TOML at the given path.
+pub fn store(store: &ValidatorStore, store_dir: &Path) -> std::io::Result<()> {
+    let data = store.encode();
     let wallet_path = validator_file_name(store_dir);
     // Make sure the dir exists
     let wallet_dir = wallet_path.parent().unwrap();
@@ -42,35 +49,13 @@ pub fn gen_and_store(
     let mut lock = RwLock::new(options.open(wallet_path)?);
     let mut guard = lock.write()?;
     guard.write_all(&data)?;
-    Ok(validator)
+    Ok(())
 }
 
 /// Try to load and decrypt keys, if encrypted, in a [`ValidatorWallet`]
 /// from a TOML file.
 pub fn load(store_dir: &Path) -> Result<ValidatorWallet, ReadError> {
-    let wallet_file = validator_file_name(store_dir);
-    let mut options = fs::OpenOptions::new();
-    options.read(true).write(false);
-    let lock = RwLock::new(options.open(&wallet_file).map_err(|err| {
-        ReadError::ReadWallet(
-            wallet_file.to_string_lossy().into_owned(),
-            err.to_string(),
-        )
-    })?);
-    let guard = lock.read().map_err(|err| {
-        ReadError::ReadWallet(
-            wallet_file.to_string_lossy().into_owned(),
-            err.to_string(),
-        )
-    })?;
-    let mut store = Vec::<u8>::new();
-    (&*guard).read_to_end(&mut store).map_err(|err| {
-        ReadError::ReadWallet(
-            store_dir.to_str().unwrap().into(),
-            err.to_string(),
-        )
-    })?;
-    let store = ValidatorStore::decode(store).map_err(ReadError::Decode)?;
+    let store = load_store(store_dir)?;
 
     let password = if store.consensus_key.is_encrypted() {
         Some(CliWalletUtils::read_password(false))
@@ -98,27 +83,56 @@ pub fn load(store_dir: &Path) -> Result<ValidatorWallet, ReadError> {
     })
 }
 
-/// Generate a new [`ValidatorWallet`] with required pre-genesis keys. Will
-/// prompt for password when `!unsafe_dont_encrypt`.
+/// Try to load the [`ValidatorStore`] of a pre-genesis validator wallet from a
+/// TOML file, without decrypting its keys.
+pub fn load_store(store_dir: &Path) -> Result<ValidatorStore, ReadError> {
+    let wallet_file = validator_file_name(store_dir);
+    let mut options = fs::OpenOptions::new();
+    options.read(true).write(false);
+    let lock = RwLock::new(options.open(&wallet_file).map_err(|err| {
+        ReadError::ReadWallet(
+            wallet_file.to_string_lossy().into_owned(),
+            err.to_string(),
+        )
+    })?);
+    let guard = lock.read().map_err(|err| {
+        ReadError::ReadWallet(
+            wallet_file.to_string_lossy().into_owned(),
+            err.to_string(),
+        )
+    })?;
+    let mut store = Vec::<u8>::new();
+    (&*guard).read_to_end(&mut store).map_err(|err| {
+        ReadError::ReadWallet(
+            store_dir.to_str().unwrap().into(),
+            err.to_string(),
+        )
+    })?;
+    ValidatorStore::decode(store).map_err(ReadError::Decode)
+}
+
+/// Generate a new [`ValidatorWallet`] with required pre-genesis keys from the
+/// given RNG. The keys are encrypted with the `password`, if any.
 fn gen(
     scheme: SchemeType,
     password: Option<Zeroizing<String>>,
+    rng: &mut (impl CryptoRng + Rng),
 ) -> ValidatorWallet {
     let (consensus_key, consensus_sk) = gen_key_to_store(
         // Note that TM only allows ed25519 for consensus key
         SchemeType::Ed25519,
         password.clone(),
-        &mut OsRng,
+        rng,
     );
     let (eth_cold_key, eth_cold_sk) =
-        gen_key_to_store(SchemeType::Secp256k1, password.clone(), &mut OsRng);
+        gen_key_to_store(SchemeType::Secp256k1, password.clone(), rng);
     let (tendermint_node_key, tendermint_node_sk) = gen_key_to_store(
         // Note that TM only allows ed25519 for node IDs
         SchemeType::Ed25519,
         password,
-        &mut OsRng,
+        rng,
     );
-    let validator_keys = gen_validator_keys(None, None, scheme);
+    let validator_keys = gen_validator_keys(None, None, scheme, rng);
     let eth_hot_key = validator_keys.eth_bridge_keypair.clone();
     let store = ValidatorStore {
         consensus_key,
@@ -134,3 +148,36 @@ fn gen(
         tendermint_node_key: tendermint_node_sk,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use namada_sdk::key::RefTo;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_gen_from_seed_is_deterministic() {
+        let gen_from_seed = |seed: [u8; 32]| {
+            gen(SchemeType::Ed25519, None, &mut StdRng::from_seed(seed))
+        };
+        let keys = |wallet: &ValidatorWallet| {
+            (
+                wallet.consensus_key.ref_to(),
+                wallet.eth_cold_key.ref_to(),
+                wallet.eth_hot_key.ref_to(),
+                wallet.tendermint_node_key.ref_to(),
+                wallet.store.validator_keys.protocol_keypair.ref_to(),
+            )
+        };
+
+        let first = gen_from_seed([1; 32]);
+        let second = gen_from_seed([1; 32]);
+        assert_eq!(keys(&first), keys(&second));
+
+        let other = gen_from_seed([2; 32]);
+        assert_ne!(keys(&first).0, keys(&other).0);
+        assert_ne!(keys(&first).4, keys(&other).4);
+    }
+}
@@ -1,12 +1,13 @@
 //! A module for loading WASM files and downloading pre-built WASMs.
-use core::borrow::Borrow;
 use std::fs;
-use std::path::Path;
+use std::future::Future;
+use std::path::{Path, PathBuf};
 
 use data_encoding::HEXLOWER;
 use eyre::{eyre, WrapErr};
-use futures::future::join_all;
+use futures::{StreamExt, TryStreamExt};
 use namada_sdk::collections::HashMap;
+use namada_sdk::hash::Hash;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use thiserror::Error;
@@ -21,12 +22,28 @@ pub enum Error {
     Download(String, reqwest::Error),
     #[error("Error writing to {0}")]
     FileWrite(String),
+    #[error("Can't read {0}: {1}")]
+    FileRead(String, std::io::Error),
     #[error("Cannot download {0}")]
     WasmNotFound(String),
     #[error("Error while downloading {0}: {1}")]
     ServerError(String, String),
     #[error("Checksum mismatch in downloaded wasm: {0}")]
     ChecksumMismatch(String),
+    #[error(
+        "The wasm directory {0} does not exist. Run `namadac utils \
+         join-network` to fetch the wasm files of the chain, or point to an \
+         existing directory with `--wasm-dir` or the `NAMADA_WASM_DIR` \
+         environment variable."
+    )]
+    MissingWasmDir(String),
+    #[error(
+        "The wasm file {0} is missing from the wasm directory {1}. Run \
+         `namadac utils join-network` to fetch the wasm files of the chain, \
+         or point to a directory that contains it with `--wasm-dir` or the \
+         `NAMADA_WASM_DIR` environment variable."
+    )]
+    MissingWasm(String, String),
 }
 
 /// A hash map where keys are simple file names and values their full file name
@@ -35,10 +52,25 @@ pub enum Error {
 #[serde(transparent)]
 pub struct Checksums(pub HashMap<String, String>);
 
+/// The state of a local wasm file checked against its checksum
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WasmStatus {
+    /// The wasm matches its checksum
+    Valid,
+    /// The wasm doesn't match its checksum. Holds the file name derived from
+    /// the hash of its contents.
+    Mismatch(String),
+    /// The wasm is not present in the directory
+    Missing,
+}
+
 /// Github URL prefix of released Namada network configs
 pub const ENV_VAR_WASM_SERVER: &str = "NAMADA_NETWORK_WASM_SERVER";
 const DEFAULT_WASM_SERVER: &str = "https://artifacts.heliax.click/namada-wasm";
 
+/// Maximum number of WASMs downloaded at the same time
+const MAX_CONCURRENT_DOWNLOADS: usize = 8;
+
 impl Checksums {
     /// Read WASM checksums from the given path
     pub fn read_checksums_file(
@@ -147,80 +179,164 @@ pub async fn pre_fetch_wasm(wasm_directory: impl AsRef<Path>) {
     // load json with wasm hashes
     let checksums = Checksums::read_checksums_async(&wasm_directory).await;
 
-    join_all(checksums.0.into_iter().map(|(name, full_name)| {
-        let wasm_directory = wasm_directory.as_ref().to_owned();
+    if let Err(err) = fetch_wasms(
+        wasm_directory.as_ref(),
+        checksums,
+        |full_name| async move { download_wasm(&full_name).await },
+    )
+    .await
+    {
+        eprintln!("Error fetching wasms: {}", err);
+        safe_exit(1);
+    }
+}
 
-        // Async check and download (if needed) each file
-        tokio::spawn(async move {
-            let wasm_path = wasm_directory.join(&full_name);
-            match tokio::fs::read(&wasm_path).await {
-                // if the file exist, first check the hash. If not matching
-                // download it again.
-                Ok(bytes) => {
-                    if let Err(derived_name) =
-                        valid_wasm_checksum(&bytes, &name, &full_name)
-                    {
-                        tracing::info!(
-                            "WASM checksum mismatch: Got {}, expected {}. \
-                             Fetching new version...",
-                            derived_name,
-                            full_name
-                        );
-                    } else {
-                        return;
-                    }
+/// Fetch the wasms that are missing from the given directory or whose
+/// checksum doesn't match, with a bounded number of concurrent downloads.
+/// Every downloaded wasm is verified against its checksum and nothing is
+/// written unless all of them are valid.
+async fn fetch_wasms<F, Fut>(
+    wasm_directory: &Path,
+    checksums: Checksums,
+    download: F,
+) -> Result<(), Error>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<Vec<u8>, Error>>,
+{
+    let mut to_fetch = vec![];
+    for (name, full_name) in checksums.0 {
+        let wasm_path = wasm_directory.join(&full_name);
+        match tokio::fs::read(&wasm_path).await {
+            // if the file exist, first check the hash. If not matching
+            // download it again.
+            Ok(bytes) => {
+                if let Err(derived_name) =
+                    valid_wasm_checksum(&bytes, &name, &full_name)
+                {
+                    tracing::info!(
+                        "WASM checksum mismatch: Got {}, expected {}. \
+                         Fetching new version...",
+                        derived_name,
+                        full_name
+                    );
+                    to_fetch.push((name, full_name, wasm_path));
+                }
+            }
+            // if the doesn't file exist, download it.
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                to_fetch.push((name, full_name, wasm_path));
+            }
+            Err(err) => {
+                return Err(Error::FileRead(
+                    wasm_path.to_string_lossy().into_owned(),
+                    err,
+                ));
+            }
+        }
+    }
+
+    let downloaded: Vec<(PathBuf, Vec<u8>)> = futures::stream::iter(to_fetch)
+        .map(|(name, full_name, wasm_path)| {
+            let download = download(full_name.clone());
+            async move {
+                let bytes = download.await?;
+                valid_wasm_checksum(&bytes, &name, &full_name)
+                    .map_err(Error::ChecksumMismatch)?;
+                Ok((wasm_path, bytes))
+            }
+        })
+        .buffer_unordered(MAX_CONCURRENT_DOWNLOADS)
+        .try_collect()
+        .await?;
 
-                    match download_wasm(&name, &full_name).await {
-                        Ok(bytes) => {
-                            if let Err(e) =
-                                tokio::fs::write(wasm_path, &bytes).await
-                            {
-                                eprintln!(
-                                    "Error while creating file for {}: {}",
-                                    &name, e
-                                );
-                                safe_exit(1);
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!("Error downloading wasm: {}", e);
-                            safe_exit(1);
-                        }
+    for (wasm_path, bytes) in downloaded {
+        tokio::fs::write(&wasm_path, &bytes).await.map_err(|err| {
+            Error::FileWrite(format!(
+                "{}: {}",
+                wasm_path.to_string_lossy(),
+                err
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+/// Check the wasms present in the given directory against their checksums,
+/// without downloading anything. Returns the simple file name, the full file
+/// name and the status of each wasm, sorted by their simple file names.
+pub fn verify_wasms(
+    wasm_directory: impl AsRef<Path>,
+    checksums: &Checksums,
+) -> Result<Vec<(String, String, WasmStatus)>, Error> {
+    let mut wasms = checksums
+        .0
+        .iter()
+        .map(|(name, full_name)| {
+            let wasm_path = wasm_directory.as_ref().join(full_name);
+            let status = match fs::read(&wasm_path) {
+                Ok(bytes) => {
+                    match valid_wasm_checksum(&bytes, name, full_name) {
+                        Ok(()) => WasmStatus::Valid,
+                        Err(derived_name) => WasmStatus::Mismatch(derived_name),
                     }
                 }
-                // if the doesn't file exist, download it.
-                Err(err) => match err.kind() {
-                    std::io::ErrorKind::NotFound => {
-                        match download_wasm(&name, &full_name).await {
-                            Ok(bytes) => {
-                                if let Err(e) =
-                                    tokio::fs::write(wasm_path, &bytes).await
-                                {
-                                    eprintln!(
-                                        "Error while creating file for {}: {}",
-                                        &name, e
-                                    );
-                                    safe_exit(1);
-                                }
-                            }
-                            Err(e) => {
-                                eprintln!("Error downloading wasm: {}", e);
-                                safe_exit(1);
-                            }
-                        }
-                    }
-                    _ => {
-                        eprintln!(
-                            "Can't read {}.",
-                            wasm_path.as_os_str().to_string_lossy()
-                        );
-                        safe_exit(1);
-                    }
-                },
-            }
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                    WasmStatus::Missing
+                }
+                Err(err) => {
+                    return Err(Error::FileRead(
+                        wasm_path.to_string_lossy().into_owned(),
+                        err,
+                    ));
+                }
+            };
+            Ok((name.clone(), full_name.clone(), status))
         })
-    }))
-    .await;
+        .collect::<Result<Vec<_>, _>>()?;
+    wasms.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+    Ok(wasms)
+}
+
+/// Check that the given WASM directory exists and contains the required WASM
+/// files, so that commands can fail early with a helpful message
+pub fn check_wasm_dir(
+    wasm_directory: impl AsRef<Path>,
+    required_wasms: &[&Path],
+) -> Result<(), Error> {
+    let wasm_directory = wasm_directory.as_ref();
+    if !wasm_directory.is_dir() {
+        return Err(Error::MissingWasmDir(
+            wasm_directory.to_string_lossy().into_owned(),
+        ));
+    }
+    // The checksums file is optional here, `read_wasm` falls back to the
+    // given file name when it is missing
+    let checksums = fs::read(wasm_directory.join(DEFAULT_WASM_CHECKSUMS_FILE))
+        .ok()
+        .and_then(|checksums| {
+            serde_json::from_slice::<Checksums>(&checksums).ok()
+        });
+    for wasm in required_wasms {
+        if wasm.is_absolute() {
+            continue;
+        }
+        let full_name = wasm
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| checksums.as_ref()?.0.get(name));
+        let wasm_path = match full_name {
+            Some(full_name) => wasm_directory.join(full_name),
+            None => wasm_directory.join(wasm),
+        };
+        if !wasm_path.is_file() {
+            return Err(Error::MissingWasm(
+                wasm.to_string_lossy().into_owned(),
+                wasm_directory.to_string_lossy().into_owned(),
+            ));
+        }
+    }
+    Ok(())
 }
 
 pub fn read_wasm(
@@ -262,6 +378,10 @@ pub fn read_wasm_or_exit(
     wasm_directory: impl AsRef<Path>,
     file_path: impl AsRef<Path>,
 ) -> Vec<u8> {
+    if let Err(err) = check_wasm_dir(&wasm_directory, &[file_path.as_ref()]) {
+        eprintln!("{}", err);
+        safe_exit(1);
+    }
     match read_wasm(wasm_directory, file_path) {
         Ok(wasm) => wasm,
         Err(err) => {
@@ -271,7 +391,54 @@ pub fn read_wasm_or_exit(
     }
 }
 
-async fn download_wasm(name: &str, full_name: &str) -> Result<Vec<u8>, Error> {
+/// List the WASM files in the given directory, sorted by their file names,
+/// with the SHA256 hash of their contents
+pub fn list_wasms(
+    wasm_directory: impl AsRef<Path>,
+) -> eyre::Result<Vec<(String, Hash)>> {
+    let entries = fs::read_dir(&wasm_directory).wrap_err_with(|| {
+        format!(
+            "Failed to read WASM directory {}",
+            wasm_directory.as_ref().to_string_lossy()
+        )
+    })?;
+    let mut wasms = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        if !path.is_file()
+            || path.extension().and_then(|ext| ext.to_str()) != Some("wasm")
+        {
+            continue;
+        }
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let code = fs::read(&path).wrap_err_with(|| {
+            format!("Failed to read WASM from {}", path.to_string_lossy())
+        })?;
+        wasms.push((name.to_owned(), Hash::sha256(code)));
+    }
+    wasms.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(wasms)
+}
+
+pub fn list_wasms_or_exit(
+    wasm_directory: impl AsRef<Path>,
+) -> Vec<(String, Hash)> {
+    if let Err(err) = check_wasm_dir(&wasm_directory, &[]) {
+        eprintln!("{}", err);
+        safe_exit(1);
+    }
+    match list_wasms(wasm_directory) {
+        Ok(wasms) => wasms,
+        Err(err) => {
+            eprintln!("Error listing wasms: {}", err);
+            safe_exit(1);
+        }
+    }
+}
+
+async fn download_wasm(full_name: &str) -> Result<Vec<u8>, Error> {
     let url = wasm_url(full_name);
 
     tracing::info!("Downloading WASM {url}...");
@@ -283,14 +450,7 @@ async fn download_wasm(name: &str, full_name: &str) -> Result<Vec<u8>, Error> {
             let status = body.status();
             if status.is_success() {
                 let bytes = body.bytes().await.unwrap();
-                let bytes: &[u8] = bytes.borrow();
-
-                valid_wasm_checksum(bytes, name, full_name)
-                    .map_err(Error::ChecksumMismatch)?;
-
-                let bytes: Vec<u8> = bytes.to_owned();
-
-                Ok(bytes)
+                Ok(bytes.to_vec())
             } else if status.is_server_error() {
                 Err(Error::WasmNotFound(url))
             } else {
@@ -300,3 +460,148 @@ async fn download_wasm(name: &str, full_name: &str) -> Result<Vec<u8>, Error> {
         Err(e) => Err(Error::Download(url, e)),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_list_wasms() {
+        let wasm_dir = tempfile::tempdir().unwrap();
+        let tx_code = b"tx code".to_vec();
+        let vp_code = b"vp code".to_vec();
+        fs::write(wasm_dir.path().join("vp_user.wasm"), &vp_code).unwrap();
+        fs::write(wasm_dir.path().join("tx_transfer.wasm"), &tx_code).unwrap();
+        // Files that are not wasm are ignored
+        fs::write(wasm_dir.path().join(DEFAULT_WASM_CHECKSUMS_FILE), "{}")
+            .unwrap();
+
+        let wasms = list_wasms(wasm_dir.path()).unwrap();
+
+        assert_eq!(
+            wasms,
+            vec![
+                ("tx_transfer.wasm".to_string(), Hash::sha256(tx_code)),
+                ("vp_user.wasm".to_string(), Hash::sha256(vp_code)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_wasms_checksum_mismatch() {
+        let wasm_dir = tempfile::tempdir().unwrap();
+        let full_name = |name: &str, code: &[u8]| {
+            let hash = HEXLOWER.encode(&Sha256::digest(code));
+            format!("{}.{}.wasm", name, hash)
+        };
+        let tx_name = full_name("tx_transfer", b"tx code");
+        let vp_name = full_name("vp_user", b"vp code");
+        let checksums = || {
+            Checksums(HashMap::from_iter([
+                ("tx_transfer.wasm".to_string(), tx_name.clone()),
+                ("vp_user.wasm".to_string(), vp_name.clone()),
+            ]))
+        };
+
+        // The mock fetcher serves a tampered vp
+        let err =
+            fetch_wasms(wasm_dir.path(), checksums(), |name| async move {
+                Ok(if name.starts_with("tx_transfer") {
+                    b"tx code".to_vec()
+                } else {
+                    b"tampered vp code".to_vec()
+                })
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::ChecksumMismatch(_)));
+        // Not even the valid wasm got written
+        assert_eq!(fs::read_dir(wasm_dir.path()).unwrap().count(), 0);
+
+        // With valid wasms, all of them are written
+        fetch_wasms(wasm_dir.path(), checksums(), |name| async move {
+            Ok(if name.starts_with("tx_transfer") {
+                b"tx code".to_vec()
+            } else {
+                b"vp code".to_vec()
+            })
+        })
+        .await
+        .unwrap();
+        assert_eq!(
+            fs::read(wasm_dir.path().join(&tx_name)).unwrap(),
+            b"tx code"
+        );
+        assert_eq!(
+            fs::read(wasm_dir.path().join(&vp_name)).unwrap(),
+            b"vp code"
+        );
+    }
+
+    #[test]
+    fn test_verify_wasms() {
+        let wasm_dir = tempfile::tempdir().unwrap();
+        let full_name = |name: &str, code: &[u8]| {
+            let hash = HEXLOWER.encode(&Sha256::digest(code));
+            format!("{}.{}.wasm", name, hash)
+        };
+        let tx_name = full_name("tx_transfer", b"tx code");
+        let vp_name = full_name("vp_user", b"vp code");
+        let bond_name = full_name("tx_bond", b"bond code");
+        let checksums = Checksums(HashMap::from_iter([
+            ("tx_transfer.wasm".to_string(), tx_name.clone()),
+            ("vp_user.wasm".to_string(), vp_name.clone()),
+            ("tx_bond.wasm".to_string(), bond_name.clone()),
+        ]));
+        fs::write(wasm_dir.path().join(&tx_name), b"tx code").unwrap();
+        fs::write(wasm_dir.path().join(&vp_name), b"tampered vp code").unwrap();
+
+        let wasms = verify_wasms(wasm_dir.path(), &checksums).unwrap();
+
+        assert_eq!(
+            wasms,
+            vec![
+                ("tx_bond.wasm".to_string(), bond_name, WasmStatus::Missing),
+                ("tx_transfer.wasm".to_string(), tx_name, WasmStatus::Valid),
+                (
+                    "vp_user.wasm".to_string(),
+                    vp_name,
+                    WasmStatus::Mismatch(full_name(
+                        "vp_user",
+                        b"tampered vp code"
+                    ))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_wasm_dir() {
+        let base_dir = tempfile::tempdir().unwrap();
+        let wasm_dir = base_dir.path().join("wasm");
+        let tx_transfer = Path::new("tx_transfer.wasm");
+
+        let err = check_wasm_dir(&wasm_dir, &[tx_transfer]).unwrap_err();
+        assert!(matches!(err, Error::MissingWasmDir(_)));
+        let msg = err.to_string();
+        assert!(msg.contains(&*wasm_dir.to_string_lossy()));
+        assert!(msg.contains("join-network"));
+        assert!(msg.contains("--wasm-dir"));
+
+        fs::create_dir(&wasm_dir).unwrap();
+        let err = check_wasm_dir(&wasm_dir, &[tx_transfer]).unwrap_err();
+        assert!(matches!(err, Error::MissingWasm(_, _)));
+        let msg = err.to_string();
+        assert!(msg.contains("tx_transfer.wasm"));
+        assert!(msg.contains("--wasm-dir"));
+
+        // The wasm may be named after its hash in the checksums file
+        fs::write(
+            wasm_dir.join(DEFAULT_WASM_CHECKSUMS_FILE),
+            r#"{"tx_transfer.wasm": "tx_transfer.abcd.wasm"}"#,
+        )
+        .unwrap();
+        fs::write(wasm_dir.join("tx_transfer.abcd.wasm"), b"tx code").unwrap();
+        check_wasm_dir(&wasm_dir, &[tx_transfer]).unwrap();
+    }
+}
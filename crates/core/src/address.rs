@@ -331,6 +331,14 @@ impl Address {
     pub fn is_internal(&self) -> bool {
         matches!(self, Address::Internal(_))
     }
+
+    /// Get the kind of an internal address, if this address is internal
+    pub fn as_internal(&self) -> Option<&InternalAddress> {
+        match self {
+            Address::Internal(kind) => Some(kind),
+            Address::Established(_) | Address::Implicit(_) => None,
+        }
+    }
 }
 
 impl string_encoding::Format for Address {
@@ -688,12 +696,50 @@ pub mod tests {
             assert_eq!(address, decoded);
         }
 
+        #[test]
+        /// Check that all the address types round-trip through their string
+        /// representation, as used for CLI arguments and JSON.
+        fn test_address_string_roundtrip(address in testing::arb_address()) {
+            let string = address.to_string();
+            assert_eq!(string, address.encode());
+            assert_eq!(Address::from_str(&string).unwrap(), address);
+            let json = serde_json::to_string(&address).unwrap();
+            assert_eq!(
+                serde_json::from_str::<Address>(&json).unwrap(),
+                address
+            );
+        }
+
         #[test]
         fn test_established_address_bytes_length(address in testing::arb_established_address()) {
             let address = Address::Established(address);
             let bytes = address.serialize_to_vec();
             assert_eq!(bytes.len(), ESTABLISHED_ADDRESS_BYTES_LEN);
         }
+
+        #[test]
+        /// Check that established addresses have no internal kind
+        fn test_established_address_not_internal(address in testing::arb_established_address()) {
+            let address = Address::Established(address);
+            assert!(!address.is_internal());
+            assert_eq!(address.as_internal(), None);
+        }
+
+        #[test]
+        /// Check that implicit addresses have no internal kind
+        fn test_implicit_address_not_internal(address in testing::arb_implicit_address()) {
+            let address = Address::Implicit(address);
+            assert!(!address.is_internal());
+            assert_eq!(address.as_internal(), None);
+        }
+
+        #[test]
+        /// Check that internal addresses expose their internal kind
+        fn test_internal_address_kind(kind in testing::arb_internal_address()) {
+            let address = Address::Internal(kind.clone());
+            assert!(address.is_internal());
+            assert_eq!(address.as_internal(), Some(&kind));
+        }
     }
 }
 
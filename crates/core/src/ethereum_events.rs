@@ -427,6 +427,17 @@ pub mod tests {
             serde_json::from_str(&serialized).expect("Test failed");
         assert_eq!(addr, deserialized);
     }
+
+    /// Test that the hash of an Ethereum event commits to its nonce
+    #[test]
+    fn test_ethereum_event_hash_includes_nonce() {
+        let event = |nonce: u64| EthereumEvent::TransfersToNamada {
+            nonce: nonce.into(),
+            transfers: vec![],
+        };
+
+        assert_ne!(event(1).hash().unwrap(), event(2).hash().unwrap());
+    }
 }
 
 #[allow(missing_docs)]
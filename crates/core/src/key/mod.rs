@@ -684,4 +684,27 @@ mod more_tests {
             core::slice::from_raw_parts(ptr, secp256k1::SECRET_KEY_SIZE)
         });
     }
+
+    #[test]
+    fn tm_consensus_key_raw_hash_of_known_keys() {
+        // ed25519 public key
+        // d2bbc65a45539c4dc73fd03f896616e56ec326ae8e7f9de08bd4efcc3a506cb8
+        let ed25519_pk = testing::keypair_1().ref_to();
+        assert_eq!(
+            tm_consensus_key_raw_hash(&ed25519_pk),
+            "5B066C8835818E3452F143474202A2112323BD6F"
+        );
+        assert_eq!(
+            ed25519_pk.tm_raw_hash(),
+            tm_consensus_key_raw_hash(&ed25519_pk)
+        );
+
+        // Compressed secp256k1 public key
+        // 03b05209c7a4af3b694d08340476c66776448d1a8c1ceb1607135ebf86af1db5a6
+        let secp256k1_pk = testing::keypair_3().ref_to();
+        assert_eq!(
+            tm_consensus_key_raw_hash(&secp256k1_pk),
+            "24EA151067CA4C2387F39A5996D9426E531BA194"
+        );
+    }
 }
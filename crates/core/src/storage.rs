@@ -1950,6 +1950,15 @@ pub mod tests {
         ) {
             test_address_in_storage_key_order_aux(addr1, addr2)
         }
+
+        /// Ensure that addresses round-trip through storage key segments.
+        #[test]
+        fn test_address_key_seg_roundtrip(addr in arb_address()) {
+            assert_eq!(Address::parse(addr.raw()).unwrap(), addr);
+            let key = Key::from(addr.to_db_key());
+            let parsed = Key::parse(key.to_string()).unwrap();
+            assert_eq!(parsed.segments, vec![DbKeySeg::AddressSeg(addr)]);
+        }
     }
 
     #[cfg(test)]
@@ -520,6 +520,17 @@ impl DenominatedAmount {
             .ok_or(AmountParseError::PrecisionOverflow)
     }
 
+    /// Express this amount in the given denomination, e.g. the one of the
+    /// token it is an amount of. Trailing zeros after the decimal place are
+    /// ignored, but fails if the amount holds more significant decimal
+    /// places than the denomination allows.
+    pub fn with_denomination(
+        self,
+        denom: Denomination,
+    ) -> Result<Self, AmountParseError> {
+        self.canonical().increase_precision(denom)
+    }
+
     /// Multiply this number by 10^denom and return the computed integer if
     /// possible. Otherwise error out.
     pub fn scale(
@@ -1211,6 +1222,39 @@ mod tests {
         assert_eq!(amount, Amount::from_uint(340, 0).expect("Test failed"));
     }
 
+    #[test]
+    fn test_denominated_amount_with_denomination() {
+        let denom = Denomination(6);
+        let amount = DenominatedAmount::from_str("1.5")
+            .expect("Test failed")
+            .with_denomination(denom)
+            .expect("Test failed");
+        assert_eq!(amount.denom(), denom);
+        assert_eq!(amount.amount(), Amount::from_u64(1_500_000));
+
+        // Trailing zeros beyond the token's precision are not significant
+        let amount = DenominatedAmount::from_str("0.0000010")
+            .expect("Test failed")
+            .with_denomination(denom)
+            .expect("Test failed");
+        assert_eq!(amount.amount(), Amount::from_u64(1));
+
+        // Integral amounts are scaled to the token's precision
+        let amount = DenominatedAmount::from_str("2")
+            .expect("Test failed")
+            .with_denomination(denom)
+            .expect("Test failed");
+        assert_eq!(amount.amount(), Amount::from_u64(2_000_000));
+
+        // More significant decimal places than the token allows are rejected
+        assert_matches!(
+            DenominatedAmount::from_str("1.1234567")
+                .expect("Test failed")
+                .with_denomination(denom),
+            Err(AmountParseError::PrecisionDecrease)
+        );
+    }
+
     #[test]
     fn test_from_masp_denominated() {
         let uint = Uint([15u64, 16, 17, 18]);
@@ -149,6 +149,26 @@ impl FractionalVotingPower {
         Self::new(Uint::from_u64(numer), Uint::from_u64(denom))
     }
 
+    /// Add with overflow checks. Unlike the [`Add`] impl, the result is not
+    /// capped to one. Use [`Self::is_normalized`] to check if it exceeds one,
+    /// or [`Self::clamp_to_one`] to cap it.
+    pub fn checked_add(&self, v: &Self) -> Option<Self> {
+        use num_traits::CheckedAdd;
+        Some(Self(self.0.checked_add(&v.0)?))
+    }
+
+    /// Check if the voting power is at most one.
+    #[inline]
+    pub fn is_normalized(&self) -> bool {
+        *self <= Self::WHOLE
+    }
+
+    /// Cap the voting power to one.
+    #[inline]
+    pub fn clamp_to_one(self) -> Self {
+        self.min(Self::WHOLE)
+    }
+
     /// Multiple with overflow checks.
     pub fn checked_mul(&self, v: &Self) -> Option<Self> {
         use num_traits::CheckedMul;
@@ -197,16 +217,9 @@ impl Add<FractionalVotingPower> for FractionalVotingPower {
     type Output = Self;
 
     fn add(self, rhs: FractionalVotingPower) -> Self::Output {
-        use num_traits::CheckedAdd;
-
-        self.0
-            .checked_add(&rhs.0)
-            .map(Self)
-            // cap fractional voting power to 1/1
-            .and_then(|power| {
-                (power <= FractionalVotingPower::WHOLE).then_some(power)
-            })
-            .unwrap_or(FractionalVotingPower::WHOLE)
+        // cap fractional voting power to 1/1
+        self.checked_add(&rhs)
+            .map_or(FractionalVotingPower::WHOLE, Self::clamp_to_one)
     }
 }
 
@@ -365,6 +378,35 @@ mod tests {
         assert_eq!(power, FractionalVotingPower::WHOLE);
     }
 
+    /// Test that checked additions of fractional voting powers detect
+    /// when the result exceeds `1/1`.
+    #[test]
+    fn test_fractional_voting_power_checked_add() {
+        let power = FractionalVotingPower::ONE_THIRD
+            .checked_add(&FractionalVotingPower::TWO_THIRDS)
+            .unwrap();
+        assert_eq!(power, FractionalVotingPower::WHOLE);
+        assert!(power.is_normalized());
+
+        let power = FractionalVotingPower::TWO_THIRDS
+            .checked_add(&FractionalVotingPower::TWO_THIRDS)
+            .unwrap();
+        assert_eq!(
+            power,
+            FractionalVotingPower(Ratio::new_raw(
+                Uint::from_u64(4),
+                Uint::from_u64(3)
+            ))
+        );
+        assert!(!power.is_normalized());
+        assert_eq!(power.clamp_to_one(), FractionalVotingPower::WHOLE);
+        assert_eq!(
+            FractionalVotingPower::TWO_THIRDS
+                + FractionalVotingPower::TWO_THIRDS,
+            FractionalVotingPower::WHOLE
+        );
+    }
+
     /// This test is ultimately just exercising the underlying
     /// library we use for fractions, we want to make sure
     /// operators work as expected with our FractionalVotingPower
@@ -32,6 +32,7 @@ use crate::storage::bridge_pool::{
 };
 use crate::storage::eth_bridge_queries::{EthAssetMint, EthBridgeQueries};
 use crate::storage::parameters::read_native_erc20_address;
+use crate::storage::wrapped_erc20s::verify_erc20_supply_invariant;
 use crate::storage::{self as bridge_storage};
 use crate::{token, ADDRESS as BRIDGE_ADDRESS};
 
@@ -232,6 +233,15 @@ where
         _ = changed_keys.insert(supply_key);
     }
 
+    // catch bugs in the minting path early
+    if asset_count.should_mint_erc20s() {
+        debug_assert_eq!(
+            verify_erc20_supply_invariant(state, asset)
+                .map_err(|err| err.to_string()),
+            Ok(())
+        );
+    }
+
     Ok((asset_count, changed_keys))
 }
 
@@ -6,7 +6,7 @@ mod events;
 use std::collections::BTreeSet;
 
 use borsh::BorshDeserialize;
-use eth_msgs::EthMsgUpdate;
+use eth_msgs::{EthMsg, EthMsgUpdate};
 use eyre::Result;
 use namada_core::address::Address;
 use namada_core::collections::{HashMap, HashSet};
@@ -152,9 +152,11 @@ where
     let mut changed_keys = BTreeSet::default();
     let mut tx_events = BTreeSet::default();
     let mut confirmed = vec![];
+    // Updates are applied in a deterministic order, such that the confirmed
+    // events are always acted on in the same order by every node, regardless
+    // of the iteration order of `updates`.
+    let updates: BTreeSet<_> = updates.into_iter().collect();
     for update in updates {
-        // The order in which updates are applied to storage does not matter.
-        // The final storage state will be the same regardless.
         let (mut changed, newly_confirmed) =
             apply_update(state, update.clone(), &voting_powers)?;
         changed_keys.append(&mut changed);
@@ -168,8 +170,7 @@ where
     }
     tracing::debug!(n = confirmed.len(), "Events were newly confirmed",);
 
-    // Right now, the order in which events are acted on does not matter.
-    // For `TransfersToNamada` events, they can happen in any order.
+    // NB: `confirmed` is sorted, since the updates were applied in order
     for event in confirmed {
         let (mut changed, mut new_tx_events) = events::act_on(state, event)?;
         changed_keys.append(&mut changed);
@@ -193,42 +194,43 @@ where
     H: 'static + StorageHasher + Sync,
 {
     let eth_msg_keys = vote_tallies::Keys::from(&update.body);
-    let exists_in_storage = if let Some(seen) =
-        votes::storage::maybe_read_seen(state, &eth_msg_keys)?
-    {
-        if seen {
-            tracing::debug!(?update, "Ethereum event is already seen");
-            return Ok((ChangedKeys::default(), false));
-        }
-        true
-    } else {
-        false
-    };
+    let stored = read_eth_msg(state, &eth_msg_keys)?;
+    if stored.as_ref().is_some_and(|eth_msg| eth_msg.votes.seen) {
+        tracing::debug!(?update, "Ethereum event is already seen");
+        return Ok((ChangedKeys::default(), false));
+    }
 
-    let (vote_tracking, changed, confirmed, already_present) =
-        if !exists_in_storage {
+    let (vote_tracking, changed, confirmed, already_present) = match stored {
+        None => {
             tracing::debug!(%eth_msg_keys.prefix, "Ethereum event not seen before by any validator");
             let vote_tracking =
                 calculate_new(state, update.seen_by, voting_powers)?;
             let changed = eth_msg_keys.into_iter().collect();
             let confirmed = vote_tracking.seen;
             (vote_tracking, changed, confirmed, false)
-        } else {
+        }
+        Some(eth_msg) => {
             tracing::debug!(
                 %eth_msg_keys.prefix,
                 "Ethereum event already exists in storage",
             );
             let new_votes =
                 NewVotes::new(update.seen_by.clone(), voting_powers)?;
-            let (vote_tracking, changed) =
-                votes::update::calculate(state, &eth_msg_keys, new_votes)?;
+            // The tally was just read, so don't read it again
+            let (vote_tracking, changed) = votes::update::calculate_from(
+                state,
+                &eth_msg_keys,
+                eth_msg.votes,
+                new_votes,
+            )?;
             if changed.is_empty() {
                 return Ok((changed, false));
             }
             let confirmed =
                 vote_tracking.seen && changed.contains(&eth_msg_keys.seen());
             (vote_tracking, changed, confirmed, true)
-        };
+        }
+    };
 
     votes::storage::write(
         state,
@@ -241,6 +243,24 @@ where
     Ok((changed, confirmed))
 }
 
+/// Read the [`EthMsg`] stored under `eth_msg_keys`. Returns `None` if the
+/// event has not been voted on by any validator yet.
+fn read_eth_msg<D, H>(
+    state: &WlState<D, H>,
+    eth_msg_keys: &Keys<EthereumEvent>,
+) -> Result<Option<EthMsg>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    if votes::storage::maybe_read_seen(state, eth_msg_keys)?.is_none() {
+        return Ok(None);
+    }
+    let body = votes::storage::read_body(state, eth_msg_keys)?;
+    let votes = votes::storage::read(state, eth_msg_keys)?;
+    Ok(Some(EthMsg { body, votes }))
+}
+
 fn timeout_events<D, H>(state: &mut WlState<D, H>) -> Result<ChangedKeys>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
@@ -355,7 +375,7 @@ mod tests {
     use super::*;
     use crate::protocol::transactions::utils::GetVoters;
     use crate::protocol::transactions::votes::{
-        EpochedVotingPower, EpochedVotingPowerExt, Votes,
+        EpochedVotingPower, EpochedVotingPowerExt, Tally, Votes,
     };
     use crate::storage::wrapped_erc20s;
     use crate::test_utils;
@@ -461,6 +481,100 @@ mod tests {
         Ok(())
     }
 
+    /// Test reading an [`EthMsg`] from storage, both before and after it
+    /// has been voted on.
+    #[test]
+    fn test_read_eth_msg() -> Result<()> {
+        let (mut state, _) = test_utils::setup_default_storage();
+        let (validator, validator_voting_power) =
+            test_utils::default_validator();
+        let body = arbitrary_single_transfer(
+            arbitrary_nonce(),
+            address::testing::established_address_2(),
+        );
+        let eth_msg_keys = vote_tallies::Keys::from(&body);
+
+        assert_eq!(read_eth_msg(&state, &eth_msg_keys)?, None);
+
+        let tally = Tally {
+            voting_power: EpochedVotingPower::from([(
+                0.into(),
+                validator_voting_power,
+            )]),
+            seen_by: Votes::from([(validator, BlockHeight(10))]),
+            seen: false,
+        };
+        votes::storage::write(&mut state, &eth_msg_keys, &body, &tally, false)?;
+
+        assert_eq!(
+            read_eth_msg(&state, &eth_msg_keys)?,
+            Some(EthMsg { body, votes: tally })
+        );
+        Ok(())
+    }
+
+    /// Test that applying the same set of updates always produces the same
+    /// storage writes, regardless of the order in which they are passed in.
+    #[test]
+    fn test_apply_updates_is_deterministic() -> Result<()> {
+        let (sole_validator, validator_stake) = test_utils::default_validator();
+        let updates: Vec<_> = [
+            (0u64, address::testing::established_address_2()),
+            (1u64, address::testing::established_address_3()),
+        ]
+        .into_iter()
+        .map(|(nonce, receiver)| EthMsgUpdate {
+            body: EthereumEvent::TransfersToNamada {
+                nonce: nonce.into(),
+                transfers: vec![TransferToNamada {
+                    amount: arbitrary_amount(),
+                    asset: DAI_ERC20_ETH_ADDRESS,
+                    receiver,
+                }],
+            },
+            seen_by: Votes::from([(sole_validator.clone(), BlockHeight(100))]),
+        })
+        .collect();
+        let voting_powers = HashMap::from_iter(vec![(
+            (sole_validator, BlockHeight(100)),
+            validator_stake,
+        )]);
+
+        let apply = |updates: Vec<EthMsgUpdate>| -> Result<_> {
+            let (mut state, _) = test_utils::setup_default_storage();
+            // The cap only fits one of the transfers, so the other one is
+            // minted as NUTs and the outcome depends on which is acted on
+            // first
+            test_utils::whitelist_tokens(
+                &mut state,
+                [(
+                    DAI_ERC20_ETH_ADDRESS,
+                    test_utils::WhitelistMeta {
+                        cap: arbitrary_amount(),
+                        denom: 18,
+                    },
+                )],
+            );
+            let (changed_keys, _) = apply_updates(
+                &mut state,
+                HashSet::from_iter(updates),
+                voting_powers.clone(),
+            )?;
+            let writes = changed_keys
+                .iter()
+                .map(|key| Ok((key.clone(), state.read_bytes(key)?)))
+                .collect::<namada_storage::Result<Vec<_>>>()?;
+            Ok((changed_keys, writes))
+        };
+
+        let (forward_keys, forward_writes) = apply(updates.clone())?;
+        let (backward_keys, backward_writes) =
+            apply(updates.into_iter().rev().collect())?;
+        assert_eq!(forward_keys, backward_keys);
+        assert_eq!(forward_writes, backward_writes);
+        Ok(())
+    }
+
     #[test]
     /// Test applying a single transfer via `apply_derived_tx`, where an event
     /// has enough voting power behind it for it to be applied at the same time
@@ -576,6 +690,117 @@ mod tests {
         );
     }
 
+    /// Test that a vote for an Ethereum event that already exists in storage
+    /// accrues voting power, such that the event is confirmed and acted on
+    /// once enough voting power is behind it
+    #[test]
+    fn test_apply_derived_tx_existing_event_becomes_seen() -> Result<()> {
+        let validator_a = address::testing::established_address_2();
+        let validator_b = address::testing::established_address_3();
+        let (mut state, _) = test_utils::setup_storage_with_validators(
+            HashMap::from_iter(vec![
+                (validator_a.clone(), Amount::native_whole(100)),
+                (validator_b.clone(), Amount::native_whole(100)),
+            ]),
+        );
+        test_utils::whitelist_tokens(
+            &mut state,
+            [(
+                DAI_ERC20_ETH_ADDRESS,
+                test_utils::WhitelistMeta {
+                    cap: Amount::max(),
+                    denom: 18,
+                },
+            )],
+        );
+        let receiver = address::testing::established_address_1();
+        let amount = Amount::from(100);
+
+        let event = EthereumEvent::TransfersToNamada {
+            nonce: 0.into(),
+            transfers: vec![TransferToNamada {
+                amount,
+                asset: DAI_ERC20_ETH_ADDRESS,
+                receiver: receiver.clone(),
+            }],
+        };
+        let eth_msg_keys = vote_tallies::Keys::from(&event);
+        let dai_token = wrapped_erc20s::token(&DAI_ERC20_ETH_ADDRESS);
+
+        // validator A records the event, with only half of the voting power
+        apply_derived_tx(
+            &mut state,
+            vec![MultiSignedEthEvent {
+                event: event.clone(),
+                signers: BTreeSet::from([(
+                    validator_a.clone(),
+                    BlockHeight(100),
+                )]),
+            }],
+        )?;
+        let seen: bool =
+            state.read(&eth_msg_keys.seen())?.expect("Test failed");
+        assert!(!seen);
+        assert!(state
+            .read::<Amount>(&balance_key(&dai_token, &receiver))?
+            .is_none());
+
+        // validator B's later vote pushes the event over the threshold
+        let tx_result = apply_derived_tx(
+            &mut state,
+            vec![MultiSignedEthEvent {
+                event: event.clone(),
+                signers: BTreeSet::from([(
+                    validator_b.clone(),
+                    BlockHeight(101),
+                )]),
+            }],
+        )?;
+        assert_eq!(
+            tx_result.changed_keys,
+            BTreeSet::from_iter(vec![
+                eth_msg_keys.seen(),
+                eth_msg_keys.seen_by(),
+                eth_msg_keys.voting_power(),
+                balance_key(&dai_token, &receiver),
+                minted_balance_key(&dai_token),
+            ]),
+            "The body of the event should stay unchanged, while its vote \
+             tally is updated and the transfer is minted"
+        );
+
+        let seen: bool =
+            state.read(&eth_msg_keys.seen())?.expect("Test failed");
+        assert!(seen);
+
+        let seen_by: Votes =
+            state.read(&eth_msg_keys.seen_by())?.expect("Test failed");
+        assert_eq!(
+            seen_by,
+            Votes::from([
+                (validator_a, BlockHeight(100)),
+                (validator_b, BlockHeight(101)),
+            ])
+        );
+
+        let voting_power = state
+            .read::<EpochedVotingPower>(&eth_msg_keys.voting_power())?
+            .expect("Test failed")
+            .fractional_stake(&state);
+        assert_eq!(voting_power, FractionalVotingPower::WHOLE);
+
+        let balance: Amount = state
+            .read(&balance_key(&dai_token, &receiver))?
+            .expect("Test failed");
+        assert_eq!(balance, amount);
+        let supply: Amount = state
+            .read(&minted_balance_key(&dai_token))?
+            .expect("Test failed");
+        assert_eq!(supply, amount);
+
+        Ok(())
+    }
+
     #[test]
     /// Test that attempts made to apply duplicate
     /// [`MultiSignedEthEvent`]s in a single [`apply_derived_tx`] call don't
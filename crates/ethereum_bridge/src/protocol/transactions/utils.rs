@@ -4,7 +4,7 @@ use eyre::eyre;
 use itertools::Itertools;
 use namada_core::address::Address;
 use namada_core::collections::{HashMap, HashSet};
-use namada_core::storage::BlockHeight;
+use namada_core::storage::{BlockHeight, Epoch};
 use namada_core::token;
 use namada_proof_of_stake::pos_queries::PosQueries;
 use namada_proof_of_stake::types::WeightedValidator;
@@ -37,7 +37,7 @@ where
     let consensus_validators = get_consensus_validators(
         state,
         voters.iter().map(|(_, h)| h.to_owned()).collect(),
-    );
+    )?;
     tracing::debug!(
         n = consensus_validators.len(),
         ?consensus_validators,
@@ -54,29 +54,55 @@ where
     Ok(voting_powers)
 }
 
+/// Returns the set of consensus validators at each of the given
+/// `block_heights`. Errors if the epoch of any of the `block_heights` is not
+/// known, e.g. on a pruned node or during a rollback.
 pub(super) fn get_consensus_validators<D, H>(
     state: &WlState<D, H>,
     block_heights: HashSet<BlockHeight>,
-) -> BTreeMap<BlockHeight, BTreeSet<WeightedValidator>>
+) -> eyre::Result<BTreeMap<BlockHeight, BTreeSet<WeightedValidator>>>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    let mut consensus_validators = BTreeMap::default();
-    for height in block_heights.into_iter() {
-        let epoch = state.pos_queries().get_epoch(height).expect(
-            "The epoch of the last block height should always be known",
-        );
-        _ = consensus_validators.insert(
-            height,
+    get_consensus_validators_by_epoch(
+        block_heights,
+        |height| state.pos_queries().get_epoch(height),
+        |epoch| {
             state
                 .pos_queries()
                 .get_consensus_validators(Some(epoch))
                 .iter()
-                .collect(),
-        );
+                .collect()
+        },
+    )
+}
+
+/// Returns the set of consensus validators at each of the given
+/// `block_heights`. Votes are usually cast at adjacent heights, which tend to
+/// fall in the same epoch, so the consensus validator set of each epoch is
+/// only read once with `read_validators`.
+fn get_consensus_validators_by_epoch(
+    block_heights: HashSet<BlockHeight>,
+    get_epoch: impl Fn(BlockHeight) -> Option<Epoch>,
+    mut read_validators: impl FnMut(Epoch) -> BTreeSet<WeightedValidator>,
+) -> eyre::Result<BTreeMap<BlockHeight, BTreeSet<WeightedValidator>>> {
+    let mut validators_by_epoch = BTreeMap::default();
+    let mut consensus_validators = BTreeMap::default();
+    for height in block_heights.into_iter() {
+        let epoch = get_epoch(height).ok_or_else(|| {
+            eyre!(
+                "Unable to get the consensus validators at block height \
+                 {height}, since its epoch is not known"
+            )
+        })?;
+        let validators = validators_by_epoch
+            .entry(epoch)
+            .or_insert_with(|| read_validators(epoch))
+            .clone();
+        _ = consensus_validators.insert(height, validators);
     }
-    consensus_validators
+    Ok(consensus_validators)
 }
 
 /// Gets the voting power of `selected` from `all_consensus`. Errors if a
@@ -124,6 +150,7 @@ mod tests {
     use namada_core::address;
     use namada_core::ethereum_events::testing::arbitrary_bonded_stake;
     use namada_core::voting_power::FractionalVotingPower;
+    use namada_state::testing::TestState;
 
     use super::*;
 
@@ -252,4 +279,62 @@ mod tests {
             Some(v) if *v == expected_stake
         );
     }
+
+    #[test]
+    /// Test that getting the consensus validators at a block height whose
+    /// epoch is not known results in an error, rather than a panic
+    fn test_get_consensus_validators_unknown_epoch() {
+        let state = TestState::default();
+        assert!(
+            state
+                .in_mem()
+                .block
+                .pred_epochs
+                .first_block_heights
+                .is_empty()
+        );
+
+        let result = get_consensus_validators(
+            &state,
+            HashSet::from_iter(vec![BlockHeight(100)]),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    /// Test that the consensus validator set of an epoch is only read once,
+    /// when several of the given block heights fall in that epoch
+    fn test_get_consensus_validators_reads_epoch_once() {
+        let validators = BTreeSet::from_iter(vec![WeightedValidator {
+            bonded_stake: arbitrary_bonded_stake(),
+            address: address::testing::established_address_1(),
+        }]);
+        let mut reads = 0;
+
+        let result = get_consensus_validators_by_epoch(
+            HashSet::from_iter(vec![BlockHeight(100), BlockHeight(101)]),
+            |_| Some(Epoch(1)),
+            |epoch| {
+                assert_eq!(epoch, Epoch(1));
+                reads += 1;
+                validators.clone()
+            },
+        );
+
+        let consensus_validators = match result {
+            Ok(consensus_validators) => consensus_validators,
+            Err(error) => panic!("error: {:?}", error),
+        };
+        assert_eq!(reads, 1);
+        assert_eq!(consensus_validators.len(), 2);
+        assert_eq!(
+            consensus_validators.get(&BlockHeight(100)),
+            Some(&validators)
+        );
+        assert_eq!(
+            consensus_validators.get(&BlockHeight(101)),
+            Some(&validators)
+        );
+    }
 }
@@ -17,6 +17,7 @@ use namada_proof_of_stake::pos_queries::PosQueries;
 use namada_state::{DBIter, StorageHasher, WlState, DB};
 
 use super::{read, ChangedKeys};
+use crate::storage::parameters::read_confirmation_threshold;
 
 pub(super) mod storage;
 pub(super) mod update;
@@ -72,15 +73,16 @@ pub trait EpochedVotingPowerExt {
     }
 
     /// Check if the [`Tally`] associated with an [`EpochedVotingPower`]
-    /// can be considered `seen`.
+    /// can be considered `seen`, i.e. if it exceeds the confirmation
+    /// threshold of the Ethereum bridge parameters.
     #[inline]
-    fn has_majority_quorum<D, H>(&self, state: &WlState<D, H>) -> bool
+    fn has_majority_quorum<D, H>(&self, state: &WlState<D, H>) -> Result<bool>
     where
         D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
         H: 'static + StorageHasher + Sync,
     {
         let Some(max_voting_power) = self.epoch_max_voting_power(state) else {
-            return false;
+            return Ok(false);
         };
         // NB: Preserve the safety property of the Tendermint protocol across
         // all the epochs we vote on.
@@ -92,10 +94,13 @@ pub trait EpochedVotingPowerExt {
         // arbitrarily faulty nodes. Therefore, we can consider a tally secure
         // if has accumulated an amount of stake greater than the threshold
         // stake of S_max - F = 2/3 S_max.
-        let threshold = FractionalVotingPower::TWO_THIRDS
+        //
+        // The threshold defaults to 2/3, but it can be changed through the
+        // Ethereum bridge parameters.
+        let threshold = read_confirmation_threshold(state)?
             .checked_mul_amount(max_voting_power)
-            .expect("Cannot overflow");
-        self.tallied_stake() > threshold
+            .ok_or_else(|| eyre!("Invalid confirmation threshold"))?;
+        Ok(self.tallied_stake() > threshold)
     }
 }
 
@@ -159,6 +164,17 @@ where
     H: 'static + StorageHasher + Sync,
 {
     let mut seen_by_voting_power = EpochedVotingPower::new();
+    // The total voting power of each epoch voted on, queried once per epoch
+    let mut total_voting_powers = BTreeMap::<Epoch, token::Amount>::new();
+    // The fraction of the total voting power of each epoch that was tallied.
+    //
+    // NB: The voting powers are read from the consensus validator set of
+    // the epoch the votes were cast in, and each validator is counted at
+    // most once in `seen_by`. Hence, every voting power is part of a
+    // non-zero total, and the tallied fraction of an epoch can never exceed
+    // one. Otherwise, the voting powers were miscounted.
+    let mut seen_by_fractional_power =
+        BTreeMap::<Epoch, FractionalVotingPower>::new();
     for (validator, block_height) in seen_by.iter() {
         match voting_powers
             .get(&(validator.to_owned(), block_height.to_owned()))
@@ -174,6 +190,28 @@ where
                 *aggregated = aggregated
                     .checked_add(voting_power)
                     .ok_or_else(|| eyre!("Aggregated voting power overflow"))?;
+
+                let total_voting_power =
+                    *total_voting_powers.entry(epoch).or_insert_with(|| {
+                        state.pos_queries().get_total_voting_power(Some(epoch))
+                    });
+                let exceeds_total = || {
+                    eyre!(
+                        "Aggregated voting power exceeds the total voting \
+                         power of epoch {epoch}"
+                    )
+                };
+                let fractional_power = FractionalVotingPower::new(
+                    voting_power.into(),
+                    total_voting_power.into(),
+                )
+                .map_err(|_| exceeds_total())?;
+                let aggregated =
+                    seen_by_fractional_power.entry(epoch).or_default();
+                *aggregated = aggregated
+                    .checked_add(&fractional_power)
+                    .filter(FractionalVotingPower::is_normalized)
+                    .ok_or_else(exceeds_total)?;
             }
             None => {
                 return Err(eyre!(
@@ -184,7 +222,7 @@ where
         };
     }
 
-    let newly_confirmed = seen_by_voting_power.has_majority_quorum(state);
+    let newly_confirmed = seen_by_voting_power.has_majority_quorum(state)?;
     Ok(Tally {
         voting_power: seen_by_voting_power,
         seen_by,
@@ -204,8 +242,10 @@ mod tests {
     use namada_core::address;
     use namada_proof_of_stake::parameters::OwnedPosParams;
     use namada_proof_of_stake::storage::write_pos_params;
+    use namada_state::StorageWrite;
 
     use super::*;
+    use crate::storage::confirmation_threshold_key;
     use crate::test_utils;
 
     #[test]
@@ -311,6 +351,44 @@ mod tests {
         );
     }
 
+    /// Test that a tally is confirmed once it exceeds the confirmation
+    /// threshold stored in the Ethereum bridge parameters.
+    #[test]
+    fn test_confirmation_threshold() -> Result<()> {
+        let (_, dummy_validator_stake) = test_utils::default_validator();
+        let (mut state, _) = test_utils::setup_default_storage();
+
+        let tally = |fraction: FractionalVotingPower| {
+            EpochedVotingPower::from([(
+                0.into(),
+                fraction * dummy_validator_stake,
+            )])
+        };
+        let three_quarters = FractionalVotingPower::new_u64(3, 4)?;
+        let four_fifths = FractionalVotingPower::new_u64(4, 5)?;
+
+        // the default threshold is two thirds
+        assert!(tally(three_quarters).has_majority_quorum(&state)?);
+        assert!(
+            !tally(FractionalVotingPower::TWO_THIRDS)
+                .has_majority_quorum(&state)?
+        );
+
+        // raise the threshold to three quarters
+        state.write(&confirmation_threshold_key(), three_quarters)?;
+        assert!(tally(four_fifths).has_majority_quorum(&state)?);
+        assert!(!tally(three_quarters).has_majority_quorum(&state)?);
+
+        // a threshold below two thirds is rejected
+        state.write(
+            &confirmation_threshold_key(),
+            FractionalVotingPower::HALF,
+        )?;
+        assert!(tally(four_fifths).has_majority_quorum(&state).is_err());
+
+        Ok(())
+    }
+
     /// Test that voting on a tally across epoch boundaries accounts
     /// for the maximum voting power attained along those epochs.
     #[test]
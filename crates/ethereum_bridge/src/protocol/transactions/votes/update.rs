@@ -99,13 +99,28 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
     T: BorshDeserialize,
+{
+    let tally_pre = super::storage::read(state, keys)?;
+    calculate_from(state, keys, tally_pre, vote_info)
+}
+
+/// Like [`calculate`], for a `tally_pre` which was already read from storage
+/// under `keys`.
+pub(in super::super) fn calculate_from<D, H, T>(
+    state: &mut WlState<D, H>,
+    keys: &vote_tallies::Keys<T>,
+    tally_pre: Tally,
+    vote_info: NewVotes,
+) -> Result<(Tally, ChangedKeys)>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
 {
     tracing::info!(
         ?keys.prefix,
         validators = ?vote_info.voters(),
         "Calculating validators' votes applied to an existing tally"
     );
-    let tally_pre = super::storage::read(state, keys)?;
     if tally_pre.seen {
         return Ok((tally_pre, ChangedKeys::default()));
     }
@@ -180,7 +195,7 @@ where
             .ok_or_else(|| eyre!("Aggregated voting power overflow"))?;
     }
 
-    let seen_post = voting_power_post.has_majority_quorum(state);
+    let seen_post = voting_power_post.has_majority_quorum(state)?;
 
     Ok(Tally {
         voting_power: voting_power_post,
@@ -8,7 +8,7 @@ use namada_core::ethereum_events::{
     EthAddress, EthereumEvent, GetEventNonce, TransferToEthereum, Uint,
 };
 use namada_core::keccak::KeccakHash;
-use namada_core::storage::{BlockHeight, Epoch, Key as StorageKey};
+use namada_core::storage::{BlockHeight, DbKeySeg, Epoch, Key as StorageKey};
 use namada_core::voting_power::{EthBridgeVotingPower, FractionalVotingPower};
 use namada_core::{hints, token};
 use namada_macros::BorshDeserializer;
@@ -19,11 +19,14 @@ use namada_proof_of_stake::storage::{
     validator_eth_cold_key_handle, validator_eth_hot_key_handle,
 };
 use namada_state::{DBIter, StorageHasher, StoreType, WlState, DB};
-use namada_storage::StorageRead;
+use namada_storage::{ResultExt, StorageRead};
 use namada_vote_ext::validator_set_update::{
     EthAddrBook, ValidatorSetArgs, VotingPowersMap, VotingPowersMapExt,
 };
 
+use crate::protocol::transactions::votes::{
+    EpochedVotingPower, EpochedVotingPowerExt, Votes,
+};
 use crate::storage::proof::BridgePoolRootProof;
 use crate::storage::{active_key, bridge_pool, vote_tallies, whitelist};
 
@@ -62,6 +65,50 @@ pub fn is_bridge_active_at<S: StorageRead>(
     })
 }
 
+/// Read the Ethereum events in storage which have not yet been seen
+/// by validators with more than 2/3 of the voting power, along with
+/// the voting power backing each event and the validators that have
+/// voted on it.
+pub fn read_pending_eth_msgs<D, H>(
+    state: &WlState<D, H>,
+) -> namada_storage::Result<
+    Vec<(EthereumEvent, FractionalVotingPower, Vec<Address>)>,
+>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut pending_msgs = vec![];
+    for entry in namada_storage::iter_prefix_bytes(
+        state,
+        &vote_tallies::eth_msgs_prefix(),
+    )? {
+        let (key, body) = entry?;
+        let is_body_key = matches!(
+            key.segments.last(),
+            Some(DbKeySeg::StringSeg(seg))
+                if seg == vote_tallies::Keys::segments().body
+        );
+        let keys = match vote_tallies::eth_event_keys(&key) {
+            Some(keys) if is_body_key => keys,
+            _ => continue,
+        };
+        let seen: bool = state.read(&keys.seen())?.unwrap_or_default();
+        if seen {
+            continue;
+        }
+        let event =
+            EthereumEvent::try_from_slice(&body).into_storage_result()?;
+        let voting_power = state
+            .read::<EpochedVotingPower>(&keys.voting_power())?
+            .unwrap_or_default()
+            .fractional_stake(state);
+        let seen_by: Votes = state.read(&keys.seen_by())?.unwrap_or_default();
+        pending_msgs.push((event, voting_power, seen_by.into_keys().collect()));
+    }
+    Ok(pending_msgs)
+}
+
 /// This enum is used as a parameter to
 /// [`EthBridgeQueriesHook::must_send_valset_upd`].
 pub enum SendValsetUpd {
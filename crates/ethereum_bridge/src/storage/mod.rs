@@ -9,7 +9,7 @@ pub mod vp;
 pub mod whitelist;
 pub mod wrapped_erc20s;
 
-use namada_core::address::Address;
+use namada_core::address::{Address, InternalAddress};
 use namada_core::storage::{DbKeySeg, Key, KeySeg};
 pub use namada_parameters::native_erc20_key;
 use namada_parameters::storage::*;
@@ -46,7 +46,11 @@ pub fn has_eth_addr_segment(key: &Key) -> bool {
 /// Returns whether a key belongs to this account or not
 pub fn is_eth_bridge_key(nam_addr: &Address, key: &Key) -> bool {
     key == &escrow_key(nam_addr)
-        || matches!(key.segments.first(), Some(first_segment) if first_segment == &ADDRESS.to_db_key())
+        || matches!(
+            key.segments.first(),
+            Some(DbKeySeg::AddressSeg(addr))
+                if addr.as_internal() == Some(&InternalAddress::EthBridge)
+        )
         || wrapped_erc20s::has_erc20_segment(key)
 }
 
@@ -61,6 +65,11 @@ pub fn min_confirmations_key() -> Key {
     get_min_confirmations_key_at_addr(PARAM_ADDRESS)
 }
 
+/// Storage key for the confirmation threshold parameter.
+pub fn confirmation_threshold_key() -> Key {
+    get_confirmation_threshold_key_at_addr(PARAM_ADDRESS)
+}
+
 /// Storage key for the Ethereum address of the bridge contract.
 pub fn bridge_contract_key() -> Key {
     get_bridge_contract_address_key_at_addr(PARAM_ADDRESS)
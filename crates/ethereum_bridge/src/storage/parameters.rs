@@ -6,6 +6,8 @@ use namada_core::ethereum_events::EthAddress;
 use namada_core::ethereum_structs;
 use namada_core::storage::Key;
 use namada_core::token::{DenominatedAmount, NATIVE_MAX_DECIMAL_PLACES};
+use namada_core::uint::Uint;
+use namada_core::voting_power::FractionalVotingPower;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
@@ -337,6 +339,41 @@ where
     })
 }
 
+/// Read the fraction of the voting power that a tally of Ethereum bridge
+/// votes must exceed to be confirmed. Defaults to two thirds, if the
+/// parameter is not present in storage.
+///
+/// An error is returned if the stored threshold is not between two thirds
+/// and one inclusive, as confirming a tally with less than two thirds of the
+/// voting power is not secure against byzantine validators.
+pub fn read_confirmation_threshold<S>(
+    storage: &S,
+) -> Result<FractionalVotingPower>
+where
+    S: StorageRead,
+{
+    // NB: read the raw fraction, since decoding a `FractionalVotingPower`
+    // with a zero denominator panics
+    let Some((numer, denom)) = storage
+        .read::<(Uint, Uint)>(&bridge_storage::confirmation_threshold_key())?
+    else {
+        return Ok(FractionalVotingPower::TWO_THIRDS);
+    };
+    let threshold =
+        FractionalVotingPower::new(numer, denom).map_err(|err| {
+            Error::AllocMessage(format!(
+                "Invalid Ethereum bridge confirmation threshold: {err}"
+            ))
+        })?;
+    if threshold < FractionalVotingPower::TWO_THIRDS {
+        return Err(Error::AllocMessage(format!(
+            "The Ethereum bridge confirmation threshold {threshold} is below \
+             two thirds"
+        )));
+    }
+    Ok(threshold)
+}
+
 /// Reads the value of `key` from `storage` and deserializes it, or panics
 /// otherwise.
 fn must_read_key<D, H, T: BorshDeserialize>(
@@ -481,4 +518,42 @@ mod tests {
         // This should panic as the other config values are not written
         EthereumOracleConfig::read(&state);
     }
+
+    #[test]
+    fn test_read_confirmation_threshold() {
+        let mut state = TestState::default();
+        let key = bridge_storage::confirmation_threshold_key();
+        assert_eq!(
+            read_confirmation_threshold(&state).unwrap(),
+            FractionalVotingPower::TWO_THIRDS
+        );
+
+        let three_quarters = FractionalVotingPower::new_u64(3, 4).unwrap();
+        state.write(&key, three_quarters).unwrap();
+        assert_eq!(
+            read_confirmation_threshold(&state).unwrap(),
+            three_quarters
+        );
+
+        state.write(&key, FractionalVotingPower::WHOLE).unwrap();
+        assert_eq!(
+            read_confirmation_threshold(&state).unwrap(),
+            FractionalVotingPower::WHOLE
+        );
+    }
+
+    #[test]
+    fn test_read_invalid_confirmation_threshold() {
+        let mut state = TestState::default();
+        let key = bridge_storage::confirmation_threshold_key();
+        for (numer, denom) in [(0, 1), (1, 2), (3, 2), (1, 0), (0, 0)] {
+            state
+                .write(&key, (Uint::from_u64(numer), Uint::from_u64(denom)))
+                .unwrap();
+            assert!(
+                read_confirmation_threshold(&state).is_err(),
+                "{numer}/{denom} should be rejected"
+            );
+        }
+    }
 }
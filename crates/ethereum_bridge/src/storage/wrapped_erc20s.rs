@@ -1,5 +1,7 @@
 //! Functionality for accessing the multitoken subspace
 
+use std::collections::BTreeMap;
+
 use eyre::eyre;
 use namada_core::address::{Address, InternalAddress};
 pub use namada_core::eth_bridge_pool::{
@@ -7,8 +9,11 @@ pub use namada_core::eth_bridge_pool::{
 };
 use namada_core::ethereum_events::EthAddress;
 use namada_core::storage::{self, DbKeySeg};
+use namada_core::token::Amount;
+use namada_storage::StorageRead;
 use namada_trans_token::storage_key::{
-    balance_key, minted_balance_key, MINTED_STORAGE_KEY,
+    balance_key, balance_prefix, is_balance_key, minted_balance_key,
+    MINTED_STORAGE_KEY,
 };
 
 /// Represents the type of a key relating to a wrapped ERC20
@@ -52,6 +57,53 @@ pub fn has_erc20_segment(key: &storage::Key) -> bool {
     )
 }
 
+/// Read the balances of all the holders of the wrapped ERC20 `asset`.
+pub fn read_all_balances<S>(
+    storage: &S,
+    asset: &EthAddress,
+) -> namada_storage::Result<BTreeMap<Address, Amount>>
+where
+    S: StorageRead,
+{
+    let token = token(asset);
+    namada_storage::iter_prefix::<Amount>(storage, &balance_prefix(&token))?
+        .filter_map(|result| match result {
+            // skip the supply key, which shares the balance prefix
+            Ok((key, balance)) => is_balance_key(&token, &key)
+                .map(|owner| Ok((owner.clone(), balance))),
+            Err(err) => Some(Err(err)),
+        })
+        .collect()
+}
+
+/// Check that the minted supply of the wrapped ERC20 `asset` is equal to the
+/// sum of the balances of all its holders.
+pub fn verify_erc20_supply_invariant<S>(
+    storage: &S,
+    asset: &EthAddress,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead,
+{
+    let supply: Amount = storage
+        .read(&minted_balance_key(&token(asset)))?
+        .unwrap_or_default();
+    let balances = read_all_balances(storage, asset)?;
+    let total_balance =
+        Amount::sum(balances.into_values()).ok_or_else(|| {
+            namada_storage::Error::new_alloc(format!(
+                "The sum of the balances of wrapped ERC20 {asset} overflows"
+            ))
+        })?;
+    if supply != total_balance {
+        return Err(namada_storage::Error::new_alloc(format!(
+            "The supply of wrapped ERC20 {asset} is {supply}, but the sum of \
+             its balances is {total_balance}"
+        )));
+    }
+    Ok(())
+}
+
 impl TryFrom<(&Address, &storage::Key)> for Key {
     type Error = eyre::Error;
 
@@ -109,10 +161,15 @@ mod test {
     use std::str::FromStr;
 
     use assert_matches::assert_matches;
-    use namada_core::address::testing::nam;
+    use namada_core::address::testing::{
+        established_address_1, established_address_2, nam,
+    };
     use namada_core::ethereum_events::testing::DAI_ERC20_ETH_ADDRESS;
+    use namada_state::testing::TestState;
+    use namada_storage::StorageWrite;
 
     use super::*;
+    use crate::token::credit_tokens;
     use crate::token::storage_key::BALANCE_STORAGE_KEY;
     use crate::ADDRESS;
 
@@ -323,4 +380,55 @@ mod test {
 
         assert!(has_erc20_segment(&key));
     }
+
+    #[test]
+    fn test_read_all_balances() -> namada_storage::Result<()> {
+        let mut state = TestState::default();
+        let wdai = token(&DAI_ERC20_ETH_ADDRESS);
+        let receiver_1 = established_address_1();
+        let receiver_2 = established_address_2();
+        credit_tokens(&mut state, &wdai, &receiver_1, Amount::from(100))?;
+        credit_tokens(&mut state, &wdai, &receiver_2, Amount::from(200))?;
+        // balances of other wrapped ERC20s are not included
+        credit_tokens(
+            &mut state,
+            &token(&EthAddress([1; 20])),
+            &receiver_1,
+            Amount::from(300),
+        )?;
+
+        let balances = read_all_balances(&state, &DAI_ERC20_ETH_ADDRESS)?;
+        assert_eq!(
+            balances,
+            BTreeMap::from([
+                (receiver_1, Amount::from(100)),
+                (receiver_2, Amount::from(200)),
+            ])
+        );
+
+        let supply: Option<Amount> = state.read(&storage::Key::from(&Key {
+            asset: DAI_ERC20_ETH_ADDRESS,
+            suffix: KeyType::Supply,
+        }))?;
+        assert_eq!(Amount::sum(balances.into_values()), supply);
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_erc20_supply_invariant() -> namada_storage::Result<()> {
+        let mut state = TestState::default();
+        let wdai = token(&DAI_ERC20_ETH_ADDRESS);
+        let receiver = established_address_1();
+        verify_erc20_supply_invariant(&state, &DAI_ERC20_ETH_ADDRESS)?;
+
+        credit_tokens(&mut state, &wdai, &receiver, Amount::from(100))?;
+        verify_erc20_supply_invariant(&state, &DAI_ERC20_ETH_ADDRESS)?;
+
+        // corrupt the balance of the receiver
+        state.write(&balance_key(&wdai, &receiver), Amount::from(101))?;
+        let err = verify_erc20_supply_invariant(&state, &DAI_ERC20_ETH_ADDRESS)
+            .unwrap_err();
+        assert!(err.to_string().contains("the sum of its balances is"));
+        Ok(())
+    }
 }
@@ -13,8 +13,9 @@ use serde::{Deserialize, Serialize};
 use super::validation::{
     is_valid_activation_epoch, is_valid_author_balance, is_valid_content,
     is_valid_default_proposal_data, is_valid_end_epoch,
-    is_valid_pgf_funding_data, is_valid_pgf_stewards_data,
-    is_valid_proposal_period, is_valid_start_epoch, ProposalValidation,
+    is_valid_epoch_ordering, is_valid_pgf_funding_data,
+    is_valid_pgf_stewards_data, is_valid_proposal_period, is_valid_start_epoch,
+    ProposalValidation,
 };
 use crate::parameters::GovernanceParameters;
 use crate::storage::proposal::PGFTarget;
@@ -42,6 +43,48 @@ pub struct OnChainProposal {
     pub activation_epoch: Epoch,
 }
 
+impl OnChainProposal {
+    /// Validate the epochs and the content shared by all the proposal types
+    pub fn validate(
+        &self,
+        governance_parameters: &GovernanceParameters,
+        current_epoch: Epoch,
+    ) -> Result<(), ProposalValidation> {
+        is_valid_epoch_ordering(
+            self.voting_start_epoch,
+            self.voting_end_epoch,
+            self.activation_epoch,
+        )?;
+        is_valid_start_epoch(
+            self.voting_start_epoch,
+            current_epoch,
+            governance_parameters.min_proposal_voting_period,
+        )?;
+        is_valid_end_epoch(
+            self.voting_start_epoch,
+            self.voting_end_epoch,
+            current_epoch,
+            governance_parameters.min_proposal_voting_period,
+            governance_parameters.min_proposal_voting_period,
+            governance_parameters.max_proposal_period,
+        )?;
+        is_valid_activation_epoch(
+            self.activation_epoch,
+            self.voting_end_epoch,
+            governance_parameters.min_proposal_grace_epochs,
+        )?;
+        is_valid_proposal_period(
+            self.voting_start_epoch,
+            self.activation_epoch,
+            governance_parameters.max_proposal_period,
+        )?;
+        is_valid_content(
+            &self.content,
+            governance_parameters.max_proposal_content_size,
+        )
+    }
+}
+
 /// PGF default proposal
 #[derive(
     Debug,
@@ -71,37 +114,12 @@ impl DefaultProposal {
         if force {
             return Ok(self);
         }
-        is_valid_start_epoch(
-            self.proposal.voting_start_epoch,
-            current_epoch,
-            governance_parameters.min_proposal_voting_period,
-        )?;
-        is_valid_end_epoch(
-            self.proposal.voting_start_epoch,
-            self.proposal.voting_end_epoch,
-            current_epoch,
-            governance_parameters.min_proposal_voting_period,
-            governance_parameters.min_proposal_voting_period,
-            governance_parameters.max_proposal_period,
-        )?;
-        is_valid_activation_epoch(
-            self.proposal.activation_epoch,
-            self.proposal.voting_end_epoch,
-            governance_parameters.min_proposal_grace_epochs,
-        )?;
-        is_valid_proposal_period(
-            self.proposal.voting_start_epoch,
-            self.proposal.activation_epoch,
-            governance_parameters.max_proposal_period,
-        )?;
+        self.proposal
+            .validate(governance_parameters, current_epoch)?;
         is_valid_author_balance(
             balance,
             governance_parameters.min_proposal_fund,
         )?;
-        is_valid_content(
-            &self.proposal.content,
-            governance_parameters.max_proposal_content_size,
-        )?;
         is_valid_default_proposal_data(
             &self.data,
             governance_parameters.max_proposal_code_size,
@@ -149,37 +167,12 @@ impl PgfStewardProposal {
         if force {
             return Ok(self);
         }
-        is_valid_start_epoch(
-            self.proposal.voting_start_epoch,
-            current_epoch,
-            governance_parameters.min_proposal_voting_period,
-        )?;
-        is_valid_end_epoch(
-            self.proposal.voting_start_epoch,
-            self.proposal.voting_end_epoch,
-            current_epoch,
-            governance_parameters.min_proposal_voting_period,
-            governance_parameters.min_proposal_voting_period,
-            governance_parameters.max_proposal_period,
-        )?;
-        is_valid_activation_epoch(
-            self.proposal.activation_epoch,
-            self.proposal.voting_end_epoch,
-            governance_parameters.min_proposal_grace_epochs,
-        )?;
-        is_valid_proposal_period(
-            self.proposal.voting_start_epoch,
-            self.proposal.activation_epoch,
-            governance_parameters.max_proposal_period,
-        )?;
+        self.proposal
+            .validate(governance_parameters, current_epoch)?;
         is_valid_author_balance(
             balance,
             governance_parameters.min_proposal_fund,
         )?;
-        is_valid_content(
-            &self.proposal.content,
-            governance_parameters.max_proposal_content_size,
-        )?;
         is_valid_pgf_stewards_data(&self.data, &self.proposal.author)?;
 
         Ok(self)
@@ -222,33 +215,8 @@ impl PgfFundingProposal {
         if force {
             return Ok(self);
         }
-        is_valid_start_epoch(
-            self.proposal.voting_start_epoch,
-            current_epoch,
-            governance_parameters.min_proposal_voting_period,
-        )?;
-        is_valid_end_epoch(
-            self.proposal.voting_start_epoch,
-            self.proposal.voting_end_epoch,
-            current_epoch,
-            governance_parameters.min_proposal_voting_period,
-            governance_parameters.min_proposal_voting_period,
-            governance_parameters.max_proposal_period,
-        )?;
-        is_valid_activation_epoch(
-            self.proposal.activation_epoch,
-            self.proposal.voting_end_epoch,
-            governance_parameters.min_proposal_grace_epochs,
-        )?;
-        is_valid_proposal_period(
-            self.proposal.voting_start_epoch,
-            self.proposal.activation_epoch,
-            governance_parameters.max_proposal_period,
-        )?;
-        is_valid_content(
-            &self.proposal.content,
-            governance_parameters.max_proposal_content_size,
-        )?;
+        self.proposal
+            .validate(governance_parameters, current_epoch)?;
         is_valid_pgf_funding_data(&self.data)?;
 
         Ok(self)
@@ -370,3 +338,131 @@ pub struct PgfRetro {
     /// PGF retro target
     pub target: PGFTarget,
 }
+
+#[cfg(test)]
+mod test {
+    use assert_matches::assert_matches;
+    use namada_core::address::testing::{
+        established_address_1, established_address_2,
+    };
+
+    use super::*;
+
+    fn proposal() -> OnChainProposal {
+        OnChainProposal {
+            content: BTreeMap::from([(
+                "title".to_string(),
+                "Proposal".to_string(),
+            )]),
+            author: established_address_1(),
+            voting_start_epoch: Epoch(3),
+            voting_end_epoch: Epoch(6),
+            activation_epoch: Epoch(12),
+        }
+    }
+
+    #[test]
+    fn test_validate_default_proposal() {
+        let params = GovernanceParameters::default();
+        let balance = params.min_proposal_fund;
+        let validate = |proposal: OnChainProposal| {
+            DefaultProposal {
+                proposal,
+                data: None,
+            }
+            .validate(&params, Epoch(0), balance, false)
+        };
+
+        assert!(validate(proposal()).is_ok());
+
+        // the epochs must be in chronological order
+        let mut unordered = proposal();
+        unordered.voting_end_epoch = Epoch(15);
+        assert_matches!(
+            validate(unordered),
+            Err(ProposalValidation::InvalidEpochOrdering(..))
+        );
+
+        let mut empty_content = proposal();
+        empty_content.content.clear();
+        assert_matches!(
+            validate(empty_content),
+            Err(ProposalValidation::EmptyContent)
+        );
+
+        // the start epoch must be in the future
+        assert_matches!(
+            DefaultProposal {
+                proposal: proposal(),
+                data: None,
+            }
+            .validate(&params, Epoch(3), balance, false),
+            Err(ProposalValidation::InvalidStartEpoch(..))
+        );
+
+        // larger than the default max proposal code size
+        let data = vec![0; 300_001];
+        assert_matches!(
+            DefaultProposal {
+                proposal: proposal(),
+                data: Some(data.clone()),
+            }
+            .validate(&params, Epoch(0), balance, false),
+            Err(ProposalValidation::InvalidDefaultProposalExtraData(..))
+        );
+        // forcing the proposal skips the validation
+        assert!(
+            DefaultProposal {
+                proposal: proposal(),
+                data: Some(data),
+            }
+            .validate(&params, Epoch(0), balance, true)
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_pgf_proposals() {
+        let params = GovernanceParameters::default();
+        let balance = params.min_proposal_fund;
+        let stewards = |add| PgfStewardProposal {
+            proposal: proposal(),
+            data: StewardsUpdate {
+                add,
+                remove: vec![],
+            },
+        };
+
+        assert!(
+            stewards(Some(established_address_1()))
+                .validate(&params, Epoch(0), balance, false)
+                .is_ok()
+        );
+        // only the author can be added as a steward
+        assert_matches!(
+            stewards(Some(established_address_2())).validate(
+                &params,
+                Epoch(0),
+                balance,
+                false
+            ),
+            Err(ProposalValidation::InvalidPgfStewardsExtraData)
+        );
+        assert_matches!(
+            stewards(None).validate(&params, Epoch(0), balance, false),
+            Err(ProposalValidation::InvalidPgfStewardsExtraData)
+        );
+
+        let funding = PgfFundingProposal {
+            proposal: proposal(),
+            data: PgfFunding {
+                continuous: vec![],
+                retro: vec![],
+            },
+        };
+        assert_matches!(
+            funding.validate(&params, Epoch(0), false),
+            Err(ProposalValidation::InvalidPgfFundingExtraData)
+        );
+    }
+}
@@ -20,6 +20,13 @@ pub enum ProposalValidation {
          {1} and a multiple of {2}"
     )]
     InvalidStartEpoch(Epoch, Epoch, u64),
+    /// The proposal epochs are not in chronological order
+    #[error(
+        "Invalid proposal epochs: the voting start epoch {0} must come before \
+         the voting end epoch {1}, which must come before the activation \
+         epoch {2}"
+    )]
+    InvalidEpochOrdering(Epoch, Epoch, Epoch),
     /// The proposal difference between start and end epoch is invalid
     #[error(
         "Invalid proposal end epoch: difference between proposal start and \
@@ -52,6 +59,9 @@ pub enum ProposalValidation {
          but maximum is {1}"
     )]
     InvalidContentLength(u64, u64),
+    /// The proposal content is empty
+    #[error("Invalid proposal content: cannot be empty.")]
+    EmptyContent,
     /// The proposal wasm code is not valid
     #[error(
         "Invalid proposal extra data: file doesn't exist or content size \
@@ -82,6 +92,24 @@ pub fn is_valid_author_balance(
     }
 }
 
+pub fn is_valid_epoch_ordering(
+    proposal_start_epoch: Epoch,
+    proposal_end_epoch: Epoch,
+    proposal_activation_epoch: Epoch,
+) -> Result<(), ProposalValidation> {
+    if proposal_start_epoch < proposal_end_epoch
+        && proposal_end_epoch < proposal_activation_epoch
+    {
+        Ok(())
+    } else {
+        Err(ProposalValidation::InvalidEpochOrdering(
+            proposal_start_epoch,
+            proposal_end_epoch,
+            proposal_activation_epoch,
+        ))
+    }
+}
+
 pub fn is_valid_start_epoch(
     proposal_start_epoch: Epoch,
     current_epoch: Epoch,
@@ -170,6 +198,9 @@ pub fn is_valid_content(
     proposal_content: &BTreeMap<String, String>,
     max_content_length: u64,
 ) -> Result<(), ProposalValidation> {
+    if proposal_content.is_empty() {
+        return Err(ProposalValidation::EmptyContent);
+    }
     let proposal_content_keys_length: u64 =
         proposal_content.keys().map(|key| key.len() as u64).sum();
     let proposal_content_values_length: u64 = proposal_content
@@ -96,3 +96,30 @@ pub mod testing {
         ]
     }
 }
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::testing::arb_proposal_vote;
+    use super::*;
+
+    proptest! {
+        /// Check that every vote, including abstain, parses back from its
+        /// displayed form
+        #[test]
+        fn test_proposal_vote_roundtrip(vote in arb_proposal_vote()) {
+            let parsed = ProposalVote::try_from(vote.to_string()).unwrap();
+            prop_assert_eq!(parsed, vote);
+        }
+    }
+
+    #[test]
+    fn test_parse_proposal_vote() {
+        assert_eq!(
+            ProposalVote::try_from(" Abstain ".to_string()),
+            Ok(ProposalVote::Abstain)
+        );
+        assert!(ProposalVote::try_from("maybe".to_string()).is_err());
+    }
+}
@@ -2,19 +2,18 @@ use namada_sdk::account::Account;
 use namada_sdk::key::common;
 
 use super::*;
+use crate::reading::AccountInfo;
 
 /// Query token amount of owner.
 pub async fn get_token_balance(
     tendermint_addr: &str,
     token: &Address,
     owner: &Address,
-) -> Result<token::Amount, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::get_token_balance(&client, token, owner).await
+) -> Result<token::Amount, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::get_token_balance(&client, token, owner)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Check if the address exists on chain. Established address exists if it
@@ -23,39 +22,33 @@ pub async fn get_token_balance(
 pub async fn known_address(
     tendermint_addr: &str,
     address: &Address,
-) -> Result<bool, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::known_address(&client, address).await
+) -> Result<bool, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::known_address(&client, address)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query the accunt substorage space of an address
 pub async fn get_account_info(
     tendermint_addr: &str,
     owner: &Address,
-) -> Result<Option<Account>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::get_account_info(&client, owner).await
+) -> Result<Option<Account>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::get_account_info(&client, owner)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query if the public_key is revealed
 pub async fn is_public_key_revealed(
     tendermint_addr: &str,
     owner: &Address,
-) -> Result<bool, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::is_public_key_revealed(&client, owner).await
+) -> Result<bool, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::is_public_key_revealed(&client, owner)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query an account substorage at a specific index
@@ -63,11 +56,19 @@ pub async fn get_public_key_at(
     tendermint_addr: &str,
     owner: &Address,
     index: u8,
-) -> Result<Option<common::PublicKey>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::get_public_key_at(&client, owner, index).await
+) -> Result<Option<common::PublicKey>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::get_public_key_at(&client, owner, index)
+        .await
+        .map_err(ReadingError::Rpc)
+}
+
+/// Query the validity predicate code hash and the revealed public keys of an
+/// account
+pub async fn query_account(
+    tendermint_addr: &str,
+    address: &Address,
+) -> Result<AccountInfo, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    AccountInfo::query(&client, address).await
 }
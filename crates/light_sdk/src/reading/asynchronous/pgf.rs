@@ -1,14 +1,30 @@
+use namada_sdk::governance::pgf::parameters::PgfParameters;
+use namada_sdk::governance::pgf::storage::steward::StewardDetail;
+
 use super::*;
+use crate::reading::{pgf_parameters, pgf_stewards};
 
 /// Check if the given address is a pgf steward.
 pub async fn is_steward(
     tendermint_addr: &str,
     address: &Address,
-) -> Result<bool, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<bool, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     Ok(rpc::is_steward(&client, address).await)
 }
+
+/// Query the current pgf stewards and their reward distributions
+pub async fn query_pgf_stewards(
+    tendermint_addr: &str,
+) -> Result<Vec<StewardDetail>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    pgf_stewards(&client).await
+}
+
+/// Get the pgf parameters
+pub async fn query_pgf_parameters(
+    tendermint_addr: &str,
+) -> Result<PgfParameters, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    pgf_parameters(&client).await
+}
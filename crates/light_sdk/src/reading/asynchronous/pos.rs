@@ -13,13 +13,9 @@ use namada_sdk::storage::{BlockHeight, Epoch};
 use super::*;
 
 /// Query the epoch of the last committed block
-pub async fn query_epoch(tendermint_addr: &str) -> Result<Epoch, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::query_epoch(&client).await
+pub async fn query_epoch(tendermint_addr: &str) -> Result<Epoch, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::query_epoch(&client).await.map_err(ReadingError::Rpc)
 }
 
 /// Query the epoch of the given block height, if it exists.
@@ -28,39 +24,33 @@ pub async fn query_epoch(tendermint_addr: &str) -> Result<Epoch, Error> {
 pub async fn query_epoch_at_height(
     tendermint_addr: &str,
     height: BlockHeight,
-) -> Result<Option<Epoch>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::query_epoch_at_height(&client, height).await
+) -> Result<Option<Epoch>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::query_epoch_at_height(&client, height)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Check if the given address is a known validator.
 pub async fn is_validator(
     tendermint_addr: &str,
     address: &Address,
-) -> Result<bool, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::is_validator(&client, address).await
+) -> Result<bool, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::is_validator(&client, address)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Check if a given address is a known delegator
 pub async fn is_delegator(
     tendermint_addr: &str,
     address: &Address,
-) -> Result<bool, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::is_delegator(&client, address).await
+) -> Result<bool, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::is_delegator(&client, address)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Check if a given address is a known delegator at the given epoch
@@ -68,61 +58,53 @@ pub async fn is_delegator_at(
     tendermint_addr: &str,
     address: &Address,
     epoch: Epoch,
-) -> Result<bool, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::is_delegator_at(&client, address, epoch).await
+) -> Result<bool, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::is_delegator_at(&client, address, epoch)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the set of consensus keys registered in the network
 pub async fn get_consensus_keys(
     tendermint_addr: &str,
-) -> Result<BTreeSet<common::PublicKey>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::get_consensus_keys(&client).await
+) -> Result<BTreeSet<common::PublicKey>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::get_consensus_keys(&client)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the PoS parameters
-pub async fn get_pos_params(tendermint_addr: &str) -> Result<PosParams, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::get_pos_params(&client).await
+pub async fn get_pos_params(
+    tendermint_addr: &str,
+) -> Result<PosParams, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::get_pos_params(&client)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get all validators in the given epoch
 pub async fn get_all_validators(
     tendermint_addr: &str,
     epoch: Epoch,
-) -> Result<HashSet<Address>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::get_all_validators(&client, epoch).await
+) -> Result<HashSet<Address>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::get_all_validators(&client, epoch)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the total staked tokens in the given epoch
 pub async fn get_total_staked_tokens(
     tendermint_addr: &str,
     epoch: Epoch,
-) -> Result<token::Amount, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::get_total_staked_tokens(&client, epoch).await
+) -> Result<token::Amount, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::get_total_staked_tokens(&client, epoch)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the given validator's stake at the given epoch
@@ -130,13 +112,11 @@ pub async fn get_validator_stake(
     tendermint_addr: &str,
     epoch: Epoch,
     validator: &Address,
-) -> Result<token::Amount, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::get_validator_stake(&client, epoch, validator).await
+) -> Result<token::Amount, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::get_validator_stake(&client, epoch, validator)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query and return a validator's state
@@ -144,27 +124,23 @@ pub async fn get_validator_state(
     tendermint_addr: &str,
     validator: &Address,
     epoch: Option<Epoch>,
-) -> Result<ValidatorStateInfo, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::get_validator_state(&client, validator, epoch).await
+) -> Result<ValidatorStateInfo, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::get_validator_state(&client, validator, epoch)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the delegator's delegation
 pub async fn get_delegation_validators(
     tendermint_addr: &str,
     address: &Address,
-) -> Result<HashSet<Address>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    let epoch = rpc::query_epoch(&client).await?;
-    rpc::get_delegation_validators(&client, address, epoch).await
+) -> Result<HashSet<Address>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    let epoch = rpc::query_epoch(&client).await.map_err(ReadingError::Rpc)?;
+    rpc::get_delegation_validators(&client, address, epoch)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the delegator's delegation at some epoh
@@ -172,13 +148,11 @@ pub async fn get_delegations_of_delegator_at(
     tendermint_addr: &str,
     address: &Address,
     epoch: Epoch,
-) -> Result<HashMap<Address, token::Amount>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::get_delegations_of_delegator_at(&client, address, epoch).await
+) -> Result<HashMap<Address, token::Amount>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::get_delegations_of_delegator_at(&client, address, epoch)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query and return validator's commission rate and max commission rate
@@ -187,13 +161,11 @@ pub async fn query_commission_rate(
     tendermint_addr: &str,
     validator: &Address,
     epoch: Option<Epoch>,
-) -> Result<CommissionPair, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::query_commission_rate(&client, validator, epoch).await
+) -> Result<CommissionPair, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::query_commission_rate(&client, validator, epoch)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query and return validator's metadata, including the commission rate and
@@ -202,13 +174,11 @@ pub async fn query_metadata(
     tendermint_addr: &str,
     validator: &Address,
     epoch: Option<Epoch>,
-) -> Result<(Option<ValidatorMetaData>, CommissionPair), Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::query_metadata(&client, validator, epoch).await
+) -> Result<(Option<ValidatorMetaData>, CommissionPair), ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::query_metadata(&client, validator, epoch)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query and return the incoming redelegation epoch for a given pair of
@@ -217,13 +187,11 @@ pub async fn query_incoming_redelegations(
     tendermint_addr: &str,
     src_validator: &Address,
     delegator: &Address,
-) -> Result<Option<Epoch>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::query_incoming_redelegations(&client, src_validator, delegator).await
+) -> Result<Option<Epoch>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::query_incoming_redelegations(&client, src_validator, delegator)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query a validator's bonds for a given epoch
@@ -232,13 +200,11 @@ pub async fn query_bond(
     source: &Address,
     validator: &Address,
     epoch: Option<Epoch>,
-) -> Result<token::Amount, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::query_bond(&client, source, validator, epoch).await
+) -> Result<token::Amount, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::query_bond(&client, source, validator, epoch)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query withdrawable tokens in a validator account for a given epoch
@@ -247,13 +213,11 @@ pub async fn query_withdrawable_tokens(
     bond_source: &Address,
     validator: &Address,
     epoch: Option<Epoch>,
-) -> Result<token::Amount, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::query_withdrawable_tokens(&client, bond_source, validator, epoch).await
+) -> Result<token::Amount, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::query_withdrawable_tokens(&client, bond_source, validator, epoch)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query all unbonds for a validator, applying slashes
@@ -261,13 +225,11 @@ pub async fn query_unbond_with_slashing(
     tendermint_addr: &str,
     source: &Address,
     validator: &Address,
-) -> Result<HashMap<(Epoch, Epoch), token::Amount>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::query_unbond_with_slashing(&client, source, validator).await
+) -> Result<HashMap<(Epoch, Epoch), token::Amount>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::query_unbond_with_slashing(&client, source, validator)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the bond amount at the given epoch
@@ -276,13 +238,11 @@ pub async fn get_bond_amount_at(
     delegator: &Address,
     validator: &Address,
     epoch: Epoch,
-) -> Result<token::Amount, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::get_bond_amount_at(&client, delegator, validator, epoch).await
+) -> Result<token::Amount, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::get_bond_amount_at(&client, delegator, validator, epoch)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get bonds and unbonds with all details (slashes and rewards, if any)
@@ -291,13 +251,11 @@ pub async fn bonds_and_unbonds(
     tendermint_addr: &str,
     source: &Option<Address>,
     validator: &Option<Address>,
-) -> Result<BondsAndUnbondsDetails, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
-    rpc::bonds_and_unbonds(&client, source, validator).await
+) -> Result<BondsAndUnbondsDetails, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    rpc::bonds_and_unbonds(&client, source, validator)
+        .await
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get bonds and unbonds with all details (slashes and rewards, if any)
@@ -308,12 +266,9 @@ pub async fn enriched_bonds_and_unbonds(
     current_epoch: Epoch,
     source: &Option<Address>,
     validator: &Option<Address>,
-) -> Result<EnrichedBondsAndUnbondsDetails, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<EnrichedBondsAndUnbondsDetails, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     rpc::enriched_bonds_and_unbonds(&client, current_epoch, source, validator)
         .await
+        .map_err(ReadingError::Rpc)
 }
@@ -1,46 +1,41 @@
+use std::time::Duration;
+
+use namada_sdk::error::TxSubmitError;
 use namada_sdk::events::Event;
+use namada_sdk::hash::Hash;
 use namada_sdk::rpc::{TxEventQuery, TxResponse};
+use namada_sdk::tendermint::Hash as TmHash;
 use namada_sdk::tx::data::DryRunResult;
+use tendermint_rpc::Client;
 
 use super::*;
+use crate::reading::{await_applied, no_response, DecodedTx};
 
 /// Call the corresponding `tx_event_query` RPC method, to fetch
 /// the current status of a transation.
 pub async fn query_tx_events(
     tendermint_addr: &str,
     tx_hash: &str,
-) -> Result<Option<Event>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<Option<Event>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let tx_event_query = TxEventQuery::Applied(tx_hash);
     rpc::query_tx_events(&client, tx_event_query)
         .await
-        .map_err(|e| Error::Other(e.to_string()))
+        .map_err(no_response)
 }
 
 /// Dry run a transaction
 pub async fn dry_run_tx(
     tendermint_addr: &str,
     tx_bytes: Vec<u8>,
-) -> Result<DryRunResult, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<DryRunResult, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let (data, height, prove) = (Some(tx_bytes), None, false);
     let result = RPC
         .shell()
         .dry_run_tx(&client, data, height, prove)
         .await
-        .map_err(|err| {
-            Error::from(namada_sdk::error::QueryError::NoResponse(
-                err.to_string(),
-            ))
-        })?
+        .map_err(no_response)?
         .data;
     Ok(result)
 }
@@ -49,20 +44,51 @@ pub async fn dry_run_tx(
 pub async fn query_tx_response(
     tendermint_addr: &str,
     tx_hash: &str,
-) -> Result<TxResponse, Error> {
+) -> Result<TxResponse, ReadingError> {
     let event = query_tx_status(tendermint_addr, tx_hash).await?;
-    event.try_into().map_err(Error::Other)
+    event.try_into().map_err(ReadingError::Parse)
 }
 
 /// Query the status of a given transaction.
 pub async fn query_tx_status(
     tendermint_addr: &str,
     tx_hash: &str,
-) -> Result<Event, Error> {
+) -> Result<Event, ReadingError> {
     let maybe_event = query_tx_events(tendermint_addr, tx_hash).await?;
     if let Some(e) = maybe_event {
         Ok(e)
     } else {
-        Err(Error::Tx(namada_sdk::error::TxSubmitError::AppliedTimeout))
+        Err(ReadingError::Rpc(Error::Tx(TxSubmitError::AppliedTimeout)))
     }
 }
+
+/// Fetch a transaction by its CometBFT hash and decode a summary of it
+pub async fn query_tx(
+    tendermint_addr: &str,
+    tx_hash: &str,
+) -> Result<DecodedTx, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    let hash = TmHash::from_str(tx_hash)
+        .map_err(|e| ReadingError::Parse(e.to_string()))?;
+    let response = client.tx(hash, false).await.map_err(no_response)?;
+    DecodedTx::try_from_bytes(&response.tx)
+}
+
+/// Wait for a transaction to be applied, polling its status until the given
+/// timeout elapses. The delay between polls starts at `poll_interval` and
+/// doubles after each unsuccessful poll.
+pub async fn await_tx(
+    tendermint_addr: &str,
+    tx_hash: &str,
+    timeout: Duration,
+    poll_interval: Duration,
+) -> Result<TxResponse, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    Hash::from_str(tx_hash).map_err(|e| ReadingError::Parse(e.to_string()))?;
+    await_applied(
+        || rpc::query_tx_events(&client, TxEventQuery::Applied(tx_hash)),
+        timeout,
+        poll_interval,
+    )
+    .await
+}
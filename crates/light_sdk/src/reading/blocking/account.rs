@@ -2,20 +2,18 @@ use namada_sdk::account::Account;
 use namada_sdk::key::common;
 
 use super::*;
+use crate::reading::AccountInfo;
 
 /// Query token amount of owner.
 pub fn get_token_balance(
     tendermint_addr: &str,
     token: &Address,
     owner: &Address,
-) -> Result<token::Amount, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<token::Amount, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::get_token_balance(&client, token, owner))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Check if the address exists on chain. Established address exists if it
@@ -24,42 +22,33 @@ pub fn get_token_balance(
 pub fn known_address(
     tendermint_addr: &str,
     address: &Address,
-) -> Result<bool, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<bool, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::known_address(&client, address))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query the accunt substorage space of an address
 pub fn get_account_info(
     tendermint_addr: &str,
     owner: &Address,
-) -> Result<Option<Account>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<Option<Account>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::get_account_info(&client, owner))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query if the public_key is revealed
 pub fn is_public_key_revealed(
     tendermint_addr: &str,
     owner: &Address,
-) -> Result<bool, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<bool, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::is_public_key_revealed(&client, owner))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query an account substorage at a specific index
@@ -67,12 +56,20 @@ pub fn get_public_key_at(
     tendermint_addr: &str,
     owner: &Address,
     index: u8,
-) -> Result<Option<common::PublicKey>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<Option<common::PublicKey>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::get_public_key_at(&client, owner, index))
+        .map_err(ReadingError::Rpc)
+}
+
+/// Query the validity predicate code hash and the revealed public keys of an
+/// account
+pub fn query_account(
+    tendermint_addr: &str,
+    address: &Address,
+) -> Result<AccountInfo, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    let rt = Runtime::new().unwrap();
+    rt.block_on(AccountInfo::query(&client, address))
 }
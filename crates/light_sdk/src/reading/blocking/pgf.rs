@@ -1,15 +1,33 @@
+use namada_sdk::governance::pgf::parameters::PgfParameters;
+use namada_sdk::governance::pgf::storage::steward::StewardDetail;
+
 use super::*;
+use crate::reading::{pgf_parameters, pgf_stewards};
 
 /// Check if the given address is a pgf steward.
 pub fn is_steward(
     tendermint_addr: &str,
     address: &Address,
-) -> Result<bool, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<bool, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     Ok(rt.block_on(rpc::is_steward(&client, address)))
 }
+
+/// Query the current pgf stewards and their reward distributions
+pub fn query_pgf_stewards(
+    tendermint_addr: &str,
+) -> Result<Vec<StewardDetail>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    let rt = Runtime::new().unwrap();
+    rt.block_on(pgf_stewards(&client))
+}
+
+/// Get the pgf parameters
+pub fn query_pgf_parameters(
+    tendermint_addr: &str,
+) -> Result<PgfParameters, ReadingError> {
+    let client = http_client(tendermint_addr)?;
+    let rt = Runtime::new().unwrap();
+    rt.block_on(pgf_parameters(&client))
+}
@@ -4,7 +4,8 @@ use namada_sdk::address::Address;
 use namada_sdk::collections::{HashMap, HashSet};
 use namada_sdk::key::common;
 use namada_sdk::proof_of_stake::types::{
-    BondsAndUnbondsDetails, CommissionPair, ValidatorMetaData, ValidatorState,
+    BondsAndUnbondsDetails, CommissionPair, ValidatorMetaData,
+    ValidatorStateInfo,
 };
 use namada_sdk::proof_of_stake::PosParams;
 use namada_sdk::queries::vp::pos::EnrichedBondsAndUnbondsDetails;
@@ -13,14 +14,11 @@ use namada_sdk::storage::{BlockHeight, Epoch};
 use super::*;
 
 /// Query the epoch of the last committed block
-pub fn query_epoch(tendermint_addr: &str) -> Result<Epoch, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+pub fn query_epoch(tendermint_addr: &str) -> Result<Epoch, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::query_epoch(&client))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query the epoch of the given block height, if it exists.
@@ -29,42 +27,33 @@ pub fn query_epoch(tendermint_addr: &str) -> Result<Epoch, Error> {
 pub fn query_epoch_at_height(
     tendermint_addr: &str,
     height: BlockHeight,
-) -> Result<Option<Epoch>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<Option<Epoch>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::query_epoch_at_height(&client, height))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Check if the given address is a known validator.
 pub fn is_validator(
     tendermint_addr: &str,
     address: &Address,
-) -> Result<bool, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<bool, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::is_validator(&client, address))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Check if a given address is a known delegator
 pub fn is_delegator(
     tendermint_addr: &str,
     address: &Address,
-) -> Result<bool, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<bool, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::is_delegator(&client, address))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Check if a given address is a known delegator at the given epoch
@@ -72,66 +61,53 @@ pub fn is_delegator_at(
     tendermint_addr: &str,
     address: &Address,
     epoch: Epoch,
-) -> Result<bool, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<bool, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::is_delegator_at(&client, address, epoch))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the set of consensus keys registered in the network
 pub fn get_consensus_keys(
     tendermint_addr: &str,
-) -> Result<BTreeSet<common::PublicKey>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<BTreeSet<common::PublicKey>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::get_consensus_keys(&client))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the PoS parameters
-pub fn get_pos_params(tendermint_addr: &str) -> Result<PosParams, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+pub fn get_pos_params(
+    tendermint_addr: &str,
+) -> Result<PosParams, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::get_pos_params(&client))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get all validators in the given epoch
 pub fn get_all_validators(
     tendermint_addr: &str,
     epoch: Epoch,
-) -> Result<HashSet<Address>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<HashSet<Address>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::get_all_validators(&client, epoch))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the total staked tokens in the given epoch
 pub fn get_total_staked_tokens(
     tendermint_addr: &str,
     epoch: Epoch,
-) -> Result<token::Amount, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<token::Amount, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::get_total_staked_tokens(&client, epoch))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the given validator's stake at the given epoch
@@ -139,14 +115,11 @@ pub fn get_validator_stake(
     tendermint_addr: &str,
     epoch: Epoch,
     validator: &Address,
-) -> Result<token::Amount, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<token::Amount, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::get_validator_stake(&client, epoch, validator))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query and return a validator's state
@@ -154,29 +127,25 @@ pub fn get_validator_state(
     tendermint_addr: &str,
     validator: &Address,
     epoch: Option<Epoch>,
-) -> Result<Option<ValidatorState>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<ValidatorStateInfo, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::get_validator_state(&client, validator, epoch))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the delegator's delegation
 pub fn get_delegation_validators(
     tendermint_addr: &str,
     address: &Address,
-) -> Result<HashSet<Address>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<HashSet<Address>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
-    let epoch = rpc::query_epoch(&client).await?;
+    let epoch = rt
+        .block_on(rpc::query_epoch(&client))
+        .map_err(ReadingError::Rpc)?;
     rt.block_on(rpc::get_delegation_validators(&client, address, epoch))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the delegator's delegation at some epoh
@@ -184,16 +153,13 @@ pub fn get_delegations_of_delegator_at(
     tendermint_addr: &str,
     address: &Address,
     epoch: Epoch,
-) -> Result<HashMap<Address, token::Amount>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<HashMap<Address, token::Amount>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::get_delegations_of_delegator_at(
         &client, address, epoch,
     ))
+    .map_err(ReadingError::Rpc)
 }
 
 /// Query and return validator's commission rate and max commission rate
@@ -202,14 +168,11 @@ pub fn query_commission_rate(
     tendermint_addr: &str,
     validator: &Address,
     epoch: Option<Epoch>,
-) -> Result<Option<CommissionPair>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<CommissionPair, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::query_commission_rate(&client, validator, epoch))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query and return validator's metadata, including the commission rate and
@@ -218,14 +181,11 @@ pub fn query_metadata(
     tendermint_addr: &str,
     validator: &Address,
     epoch: Option<Epoch>,
-) -> Result<(Option<ValidatorMetaData>, Option<CommissionPair>), Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<(Option<ValidatorMetaData>, CommissionPair), ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::query_metadata(&client, validator, epoch))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query and return the incoming redelegation epoch for a given pair of
@@ -234,18 +194,15 @@ pub fn query_incoming_redelegations(
     tendermint_addr: &str,
     src_validator: &Address,
     delegator: &Address,
-) -> Result<Option<Epoch>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<Option<Epoch>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::query_incoming_redelegations(
         &client,
         src_validator,
         delegator,
     ))
+    .map_err(ReadingError::Rpc)
 }
 
 /// Query a validator's bonds for a given epoch
@@ -254,14 +211,11 @@ pub fn query_bond(
     source: &Address,
     validator: &Address,
     epoch: Option<Epoch>,
-) -> Result<token::Amount, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<token::Amount, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::query_bond(&client, source, validator, epoch))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Query withdrawable tokens in a validator account for a given epoch
@@ -270,12 +224,8 @@ pub fn query_withdrawable_tokens(
     bond_source: &Address,
     validator: &Address,
     epoch: Option<Epoch>,
-) -> Result<token::Amount, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<token::Amount, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::query_withdrawable_tokens(
         &client,
@@ -283,6 +233,7 @@ pub fn query_withdrawable_tokens(
         validator,
         epoch,
     ))
+    .map_err(ReadingError::Rpc)
 }
 
 /// Query all unbonds for a validator, applying slashes
@@ -290,14 +241,11 @@ pub fn query_unbond_with_slashing(
     tendermint_addr: &str,
     source: &Address,
     validator: &Address,
-) -> Result<HashMap<(Epoch, Epoch), token::Amount>, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<HashMap<(Epoch, Epoch), token::Amount>, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::query_unbond_with_slashing(&client, source, validator))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get the bond amount at the given epoch
@@ -306,16 +254,13 @@ pub fn get_bond_amount_at(
     delegator: &Address,
     validator: &Address,
     epoch: Epoch,
-) -> Result<token::Amount, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<token::Amount, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::get_bond_amount_at(
         &client, delegator, validator, epoch,
     ))
+    .map_err(ReadingError::Rpc)
 }
 
 /// Get bonds and unbonds with all details (slashes and rewards, if any)
@@ -324,14 +269,11 @@ pub fn bonds_and_unbonds(
     tendermint_addr: &str,
     source: &Option<Address>,
     validator: &Option<Address>,
-) -> Result<BondsAndUnbondsDetails, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<BondsAndUnbondsDetails, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::bonds_and_unbonds(&client, source, validator))
+        .map_err(ReadingError::Rpc)
 }
 
 /// Get bonds and unbonds with all details (slashes and rewards, if any)
@@ -342,12 +284,8 @@ pub fn enriched_bonds_and_unbonds(
     current_epoch: Epoch,
     source: &Option<Address>,
     validator: &Option<Address>,
-) -> Result<EnrichedBondsAndUnbondsDetails, Error> {
-    let client = HttpClient::new(
-        TendermintAddress::from_str(tendermint_addr)
-            .map_err(|e| Error::Other(e.to_string()))?,
-    )
-    .map_err(|e| Error::Other(e.to_string()))?;
+) -> Result<EnrichedBondsAndUnbondsDetails, ReadingError> {
+    let client = http_client(tendermint_addr)?;
     let rt = Runtime::new().unwrap();
     rt.block_on(rpc::enriched_bonds_and_unbonds(
         &client,
@@ -355,4 +293,5 @@ pub fn enriched_bonds_and_unbonds(
         source,
         validator,
     ))
+    .map_err(ReadingError::Rpc)
 }
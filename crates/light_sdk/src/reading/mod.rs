@@ -1,4 +1,497 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::ops::ControlFlow;
+use std::str::FromStr;
+use std::time::Duration;
+
+use namada_sdk::address::Address;
+use namada_sdk::control_flow::time;
+use namada_sdk::error::{EncodingError, Error, QueryError, TxSubmitError};
+use namada_sdk::events::Event;
+use namada_sdk::governance::parameters::GovernanceParameters;
+use namada_sdk::governance::pgf::parameters::PgfParameters;
+use namada_sdk::governance::pgf::storage::steward::StewardDetail;
+use namada_sdk::hash::Hash;
+use namada_sdk::key::common;
+use namada_sdk::queries::{Client, Error as ClientError, RPC};
+use namada_sdk::rpc::{self, TxResponse};
+use namada_sdk::tx::data::GasLimit;
+use namada_sdk::tx::Tx;
+use tendermint_config::net::Address as TendermintAddress;
+use tendermint_rpc::HttpClient;
+
 #[cfg(not(feature = "blocking"))]
 pub mod asynchronous;
 #[cfg(feature = "blocking")]
 pub mod blocking;
+
+/// Errors that can occur when reading data from a Namada node
+#[derive(Debug, thiserror::Error)]
+pub enum ReadingError {
+    /// The address of the node could not be parsed
+    #[error("Invalid Tendermint address: {0}")]
+    InvalidTendermintAddress(String),
+    /// The RPC client for the node could not be initialized
+    #[error("Failed to initialize the RPC client: {0}")]
+    ClientInit(String),
+    /// A query to the node failed
+    #[error("RPC error: {0}")]
+    Rpc(Error),
+    /// A given input or a queried value could not be parsed
+    #[error("Parsing error: {0}")]
+    Parse(String),
+}
+
+impl From<ReadingError> for Error {
+    fn from(err: ReadingError) -> Self {
+        match err {
+            ReadingError::Rpc(err) => err,
+            ReadingError::Parse(msg) => {
+                Error::Encode(EncodingError::Decoding(msg))
+            }
+            ReadingError::InvalidTendermintAddress(_)
+            | ReadingError::ClientInit(_) => Error::Other(err.to_string()),
+        }
+    }
+}
+
+/// Build an RPC client for the node at the given address
+pub(crate) fn http_client(
+    tendermint_addr: &str,
+) -> Result<HttpClient, ReadingError> {
+    let addr = TendermintAddress::from_str(tendermint_addr)
+        .map_err(|e| ReadingError::InvalidTendermintAddress(e.to_string()))?;
+    HttpClient::new(addr).map_err(|e| ReadingError::ClientInit(e.to_string()))
+}
+
+/// Wrap the error of a query to which the node gave no response
+pub(crate) fn no_response(err: impl std::fmt::Display) -> ReadingError {
+    ReadingError::Rpc(Error::from(QueryError::NoResponse(err.to_string())))
+}
+
+/// Query the governance parameters with the given client
+pub(crate) async fn governance_parameters<C: Client + Sync>(
+    client: &C,
+) -> Result<GovernanceParameters, ReadingError> {
+    RPC.vp().gov().parameters(client).await.map_err(no_response)
+}
+
+/// Query the current PGF stewards with the given client
+pub(crate) async fn pgf_stewards<C: Client + Sync>(
+    client: &C,
+) -> Result<Vec<StewardDetail>, ReadingError> {
+    rpc::query_pgf_stewards(client)
+        .await
+        .map_err(ReadingError::Rpc)
+}
+
+/// Query the PGF parameters with the given client
+pub(crate) async fn pgf_parameters<C: Client + Sync>(
+    client: &C,
+) -> Result<PgfParameters, ReadingError> {
+    RPC.vp().pgf().parameters(client).await.map_err(no_response)
+}
+
+/// The validity predicate and revealed public keys of an account
+#[derive(Clone, Debug)]
+pub struct AccountInfo {
+    /// The code hash of the account's validity predicate, if it has one
+    pub vp_code_hash: Option<Hash>,
+    /// The public keys revealed for the account
+    pub public_keys: Vec<common::PublicKey>,
+}
+
+impl AccountInfo {
+    /// Query the account info of an address with the given client
+    pub(crate) async fn query<C: Client + Sync>(
+        client: &C,
+        address: &Address,
+    ) -> Result<Self, ReadingError> {
+        let vp_code_hash = rpc::get_vp_code_hash(client, address)
+            .await
+            .map_err(ReadingError::Rpc)?;
+        let account = rpc::get_account_info(client, address)
+            .await
+            .map_err(ReadingError::Rpc)?;
+        Ok(Self {
+            vp_code_hash,
+            public_keys: account
+                .map(|account| account.get_all_public_keys())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// Poll for the applied event of a transaction until the given timeout
+/// elapses. The delay between polls starts at `poll_interval` and doubles
+/// after each unsuccessful poll. Transport errors are retried, but errors
+/// returned by the node are returned right away.
+pub(crate) async fn await_applied<F, Fut>(
+    mut query: F,
+    timeout: Duration,
+    poll_interval: Duration,
+) -> Result<TxResponse, ReadingError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Option<Event>, ClientError>>,
+{
+    // The transport error of the last poll, reported if we time out
+    let transport_error = RefCell::new(None);
+    #[allow(clippy::disallowed_methods)]
+    let deadline = time::Instant::now() + timeout;
+    let result = time::Sleep {
+        strategy: time::ExponentialBackoff {
+            base: 2,
+            as_duration: |factor: u64| {
+                let factor = u32::try_from(factor).unwrap_or(u32::MAX);
+                poll_interval.saturating_mul(factor)
+            },
+        },
+    }
+    .timeout(deadline, || {
+        let poll = query();
+        let transport_error = &transport_error;
+        async move {
+            let result = poll.await;
+            *transport_error.borrow_mut() = None;
+            match result {
+                Ok(Some(event)) => ControlFlow::Break(Ok(event)),
+                Ok(None) => ControlFlow::Continue(()),
+                Err(err @ ClientError::Tendermint(_)) => {
+                    *transport_error.borrow_mut() = Some(err);
+                    ControlFlow::Continue(())
+                }
+                Err(err) => ControlFlow::Break(Err(err)),
+            }
+        }
+    })
+    .await;
+    let event = match result {
+        Ok(result) => result.map_err(no_response)?,
+        Err(_) => {
+            return Err(match transport_error.into_inner() {
+                Some(err) => no_response(err),
+                None => {
+                    ReadingError::Rpc(Error::Tx(TxSubmitError::AppliedTimeout))
+                }
+            });
+        }
+    };
+    event.try_into().map_err(ReadingError::Parse)
+}
+
+/// A summary of a transaction decoded from its bytes
+#[derive(Clone, Debug)]
+pub struct DecodedTx {
+    /// The hash of the transaction header
+    pub header_hash: Hash,
+    /// The inner transactions of the batch
+    pub inner_txs: Vec<DecodedInnerTx>,
+    /// The public key of the fee payer that signed the wrapper, if any
+    pub signer: Option<common::PublicKey>,
+    /// The gas limit of the wrapper, if any
+    pub gas_limit: Option<GasLimit>,
+    /// The token paying for the fees of the wrapper, if any
+    pub fee_token: Option<Address>,
+}
+
+/// The code of an inner transaction
+#[derive(Clone, Debug)]
+pub struct DecodedInnerTx {
+    /// The hash of the transaction code, if its section is present
+    pub code_hash: Option<Hash>,
+    /// The tag of the transaction code, usually the name of its wasm file
+    pub code_tag: Option<String>,
+}
+
+impl DecodedTx {
+    /// Decode a summary of a transaction from its bytes
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, ReadingError> {
+        let tx = Tx::try_from(bytes)
+            .map_err(|e| ReadingError::Parse(e.to_string()))?;
+        let inner_txs = tx
+            .commitments()
+            .iter()
+            .map(|cmt| {
+                let code = tx
+                    .get_section(cmt.code_sechash())
+                    .and_then(|section| section.code_sec());
+                DecodedInnerTx {
+                    code_hash: code.as_ref().map(|code| code.code.hash()),
+                    code_tag: code.and_then(|code| code.tag),
+                }
+            })
+            .collect();
+        let wrapper = tx.header().wrapper();
+        Ok(Self {
+            header_hash: tx.header_hash(),
+            inner_txs,
+            signer: wrapper.as_ref().map(|wrapper| wrapper.pk.clone()),
+            gas_limit: wrapper.as_ref().map(|wrapper| wrapper.gas_limit),
+            fee_token: wrapper.map(|wrapper| wrapper.fee.token),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use namada_sdk::account::init_account_storage;
+    use namada_sdk::address::testing::{
+        established_address_1, established_address_2, nam,
+    };
+    use namada_sdk::chain::ChainId;
+    use namada_sdk::dec::Dec;
+    use namada_sdk::events::extend::{ComposeEvent, Info};
+    use namada_sdk::gas::event::GasUsed;
+    use namada_sdk::gas::WholeGas;
+    use namada_sdk::key::testing::keypair_1;
+    use namada_sdk::key::RefTo;
+    use namada_sdk::queries::testing::TestClient;
+    use namada_sdk::state::StorageWrite;
+    use namada_sdk::storage::Key;
+    use namada_sdk::token::{Amount, DenominatedAmount};
+    use namada_sdk::tx::data::{Fee, ResultCode};
+    use namada_sdk::tx::event::Code;
+    use namada_sdk::tx::new_tx_event;
+
+    use super::*;
+
+    /// Nothing listens on this port, so queries sent to it fail
+    const UNREACHABLE_NODE: &str = "tcp://127.0.0.1:1";
+
+    #[test]
+    fn test_invalid_tendermint_address() {
+        assert!(matches!(
+            http_client("not a valid address"),
+            Err(ReadingError::InvalidTendermintAddress(_))
+        ));
+        assert!(http_client(UNREACHABLE_NODE).is_ok());
+    }
+
+    #[cfg(not(feature = "blocking"))]
+    #[tokio::test]
+    async fn test_unreachable_node() {
+        let err = asynchronous::pos::query_epoch(UNREACHABLE_NODE)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ReadingError::Rpc(_)));
+        let err = asynchronous::governance::query_governance_parameters(
+            UNREACHABLE_NODE,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, ReadingError::Rpc(_)));
+        let err = asynchronous::pgf::query_pgf_stewards(UNREACHABLE_NODE)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ReadingError::Rpc(_)));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_unreachable_node() {
+        let err = blocking::pos::query_epoch(UNREACHABLE_NODE).unwrap_err();
+        assert!(matches!(err, ReadingError::Rpc(_)));
+        let err =
+            blocking::governance::query_governance_parameters(UNREACHABLE_NODE)
+                .unwrap_err();
+        assert!(matches!(err, ReadingError::Rpc(_)));
+        let err =
+            blocking::pgf::query_pgf_stewards(UNREACHABLE_NODE).unwrap_err();
+        assert!(matches!(err, ReadingError::Rpc(_)));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_blocking_invalid_tendermint_address() {
+        let err =
+            blocking::query_native_token("not a valid address").unwrap_err();
+        assert!(matches!(err, ReadingError::InvalidTendermintAddress(_)));
+        let err = blocking::pos::get_validator_state(
+            "not a valid address",
+            &established_address_1(),
+            None,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ReadingError::InvalidTendermintAddress(_)));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_blocking_query_account() {
+        // The account info itself is read by `AccountInfo::query`, which
+        // `test_query_account_info` runs against a mock client
+        let owner = established_address_1();
+        let err =
+            blocking::account::query_account("not a valid address", &owner)
+                .unwrap_err();
+        assert!(matches!(err, ReadingError::InvalidTendermintAddress(_)));
+        let err = blocking::account::query_account(UNREACHABLE_NODE, &owner)
+            .unwrap_err();
+        assert!(matches!(err, ReadingError::Rpc(_)));
+    }
+
+    #[tokio::test]
+    async fn test_query_account_info() {
+        let mut client = TestClient::new(RPC);
+        let owner = established_address_1();
+
+        // An unknown account has neither a VP nor public keys
+        let info = AccountInfo::query(&client, &owner).await.unwrap();
+        assert_eq!(info.vp_code_hash, None);
+        assert!(info.public_keys.is_empty());
+
+        let vp_code_hash = Hash::sha256(b"vp_user.wasm");
+        let public_key = keypair_1().ref_to();
+        client
+            .state
+            .write(&Key::validity_predicate(&owner), vp_code_hash)
+            .unwrap();
+        init_account_storage(
+            &mut client.state,
+            &owner,
+            &[public_key.clone()],
+            1,
+        )
+        .unwrap();
+        client.state.commit_block().unwrap();
+
+        let info = AccountInfo::query(&client, &owner).await.unwrap();
+        assert_eq!(info.vp_code_hash, Some(vp_code_hash));
+        assert_eq!(info.public_keys, vec![public_key]);
+    }
+
+    #[tokio::test]
+    async fn test_query_governance_parameters() {
+        let mut client = TestClient::new(RPC);
+        let params = GovernanceParameters {
+            min_proposal_fund: Amount::native_whole(100),
+            max_proposal_period: 30,
+            max_proposal_latency: 12,
+            ..Default::default()
+        };
+        params.init_storage(&mut client.state).unwrap();
+        client.state.commit_block().unwrap();
+
+        let queried = governance_parameters(&client).await.unwrap();
+        assert_eq!(queried, params);
+    }
+
+    #[tokio::test]
+    async fn test_query_pgf() {
+        let mut client = TestClient::new(RPC);
+        let stewards = [established_address_1(), established_address_2()];
+        let params = PgfParameters {
+            stewards: stewards.iter().cloned().collect(),
+            pgf_inflation_rate: Dec::new(5, 2).unwrap(),
+            stewards_inflation_rate: Dec::new(1, 2).unwrap(),
+            ..Default::default()
+        };
+        params.init_storage(&mut client.state).unwrap();
+        client.state.commit_block().unwrap();
+
+        let queried = pgf_stewards(&client).await.unwrap();
+        assert_eq!(queried.len(), stewards.len());
+        for steward in stewards {
+            assert!(queried.contains(&StewardDetail::base(steward)));
+        }
+
+        let queried = pgf_parameters(&client).await.unwrap();
+        assert_eq!(queried.pgf_inflation_rate, params.pgf_inflation_rate);
+        assert_eq!(
+            queried.stewards_inflation_rate,
+            params.stewards_inflation_rate
+        );
+    }
+
+    /// A wrapper tx paying its fees in NAM
+    fn wrapper_tx() -> Tx {
+        let mut tx = Tx::new(ChainId::default(), None);
+        tx.add_code_from_hash(
+            Hash::sha256(b"tx_transfer.wasm"),
+            Some("tx_transfer.wasm".to_string()),
+        )
+        .add_serialized_data(vec![1, 2, 3])
+        .add_wrapper(
+            Fee {
+                amount_per_gas_unit: DenominatedAmount::native(
+                    Amount::from_u64(1),
+                ),
+                token: nam(),
+            },
+            keypair_1().ref_to(),
+            GasLimit::from(50_000),
+        );
+        tx
+    }
+
+    #[tokio::test]
+    async fn test_await_applied() {
+        let tx = wrapper_tx();
+        let event: Event = new_tx_event(&tx, 1)
+            .with(Info(String::new()))
+            .with(Code(ResultCode::Ok))
+            .with(GasUsed(WholeGas::from(10)))
+            .into();
+        let timeout = Duration::from_secs(10);
+        let poll_interval = Duration::from_millis(1);
+
+        // The tx isn't found by the first two polls, then it's applied
+        let polls = Cell::new(0);
+        let response = await_applied(
+            || {
+                polls.set(polls.get() + 1);
+                let applied = (polls.get() > 2).then(|| event.clone());
+                async move { Ok(applied) }
+            },
+            timeout,
+            poll_interval,
+        )
+        .await
+        .unwrap();
+        assert_eq!(polls.get(), 3);
+        assert_eq!(response.hash, tx.header_hash());
+        assert_eq!(response.code, ResultCode::Ok);
+
+        // An error returned by the node isn't retried
+        let polls = Cell::new(0);
+        let result = await_applied(
+            || {
+                polls.set(polls.get() + 1);
+                async { Err(ClientError::Query("invalid query".into(), 1)) }
+            },
+            timeout,
+            poll_interval,
+        )
+        .await;
+        assert!(matches!(result, Err(ReadingError::Rpc(_))));
+        assert_eq!(polls.get(), 1);
+    }
+
+    #[test]
+    fn test_decode_tx_round_trip() {
+        let code_hash = Hash::sha256(b"tx_transfer.wasm");
+        let fee_payer = keypair_1().ref_to();
+        let tx = wrapper_tx();
+
+        let decoded = DecodedTx::try_from_bytes(&tx.to_bytes()).unwrap();
+        assert_eq!(decoded.header_hash, tx.header_hash());
+        assert_eq!(decoded.inner_txs.len(), 1);
+        assert_eq!(decoded.inner_txs[0].code_hash, Some(code_hash));
+        assert_eq!(
+            decoded.inner_txs[0].code_tag.as_deref(),
+            Some("tx_transfer.wasm")
+        );
+        assert_eq!(decoded.signer, Some(fee_payer));
+        assert_eq!(decoded.gas_limit, Some(GasLimit::from(50_000)));
+        assert_eq!(decoded.fee_token, Some(nam()));
+
+        // Bytes that don't encode a tx can't be decoded
+        assert!(matches!(
+            DecodedTx::try_from_bytes(&[1, 2, 3]),
+            Err(ReadingError::Parse(_))
+        ));
+    }
+}
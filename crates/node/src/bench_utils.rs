@@ -7,6 +7,7 @@ use std::cell::RefCell;
 use std::collections::BTreeSet;
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Write};
+use std::num::NonZeroUsize;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -1089,6 +1090,7 @@ impl BenchShieldedCtx {
                 None,
                 &[spending_key.into()],
                 &[],
+                NonZeroUsize::MIN,
             ))
             .unwrap();
         let native_token = self.shell.state.in_mem().native_token.clone();
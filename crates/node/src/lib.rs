@@ -43,7 +43,7 @@ use namada_apps_lib::{config, wasm_loader};
 use namada_sdk::eth_bridge::ethers::providers::{Http, Provider};
 use namada_sdk::migrations::ScheduledMigration;
 use namada_sdk::state::{ProcessProposalCachedResult, StateRead, DB};
-use namada_sdk::storage::{BlockHeight, DbColFam};
+use namada_sdk::storage::{BlockHeight, DbColFam, DbError};
 use namada_sdk::tendermint::abci::request::CheckTxKind;
 use namada_sdk::tendermint::abci::response::ProcessProposal;
 use namada_sdk::time::DateTimeUtc;
@@ -342,12 +342,43 @@ pub fn dump_db(
         out_file_path,
         historic,
     }: args::LedgerDumpDb,
-) {
+) -> Result<(), DbError> {
     let chain_id = config.chain_id;
     let db_path = config.shell.db_dir(&chain_id);
 
     let db = storage::PersistentDB::open(db_path, None);
-    db.dump_block(out_file_path, historic, block_height);
+    db.dump_block(out_file_path, historic, block_height)
+}
+
+/// Check the consistency of the Namada ledger node's DB at the last committed
+/// height. Returns `true` if no inconsistency was found.
+pub fn verify_db(config: config::Ledger) -> bool {
+    let chain_id = config.chain_id;
+    let db_path = config.shell.db_dir(&chain_id);
+    let chain_dir = config.shell.base_dir.join(chain_id.as_str());
+    let native_token =
+        config::genesis::chain::Finalized::read_toml_files(&chain_dir)
+            .expect("Missing genesis files")
+            .get_native_token()
+            .clone();
+
+    let db = storage::open(db_path, true, None)
+        .expect("Could not open DB in read-only mode");
+    let state = storage::PersistentState::from_db(
+        db,
+        chain_id,
+        native_token,
+        None,
+        shell::is_key_diff_storable,
+    );
+    let inconsistencies = storage::verify_state(&state);
+    for inconsistency in &inconsistencies {
+        tracing::error!("{inconsistency}");
+    }
+    if inconsistencies.is_empty() {
+        tracing::info!("No inconsistency found in the DB");
+    }
+    inconsistencies.is_empty()
 }
 
 #[cfg(feature = "migrations")]
@@ -142,7 +142,7 @@ mod test_vote_extensions {
     use namada_sdk::eth_bridge::storage::eth_bridge_queries::is_bridge_comptime_enabled;
     use namada_sdk::eth_bridge::EthBridgeQueries;
     use namada_sdk::ethereum_events::{
-        EthAddress, EthereumEvent, TransferToEthereum, Uint,
+        EthAddress, EthereumEvent, TransferToEthereum, TransferToNamada, Uint,
     };
     use namada_sdk::hash::Hash;
     use namada_sdk::key::*;
@@ -155,7 +155,7 @@ mod test_vote_extensions {
     use namada_sdk::state::collections::lazy_map::{NestedSubKey, SubKey};
     use namada_sdk::storage::{Epoch, InnerEthEventsQueue, StorageWrite};
     use namada_sdk::tendermint::abci::types::VoteInfo;
-    use namada_vote_ext::ethereum_events;
+    use namada_vote_ext::ethereum_events::{self, MultiSignedEthEvent};
 
     use super::validate_eth_events_vext;
     use crate::shell::test_utils::*;
@@ -585,4 +585,68 @@ mod test_vote_extensions {
             .is_err()
         )
     }
+
+    /// Test that compressing vote extensions keeps events which only differ
+    /// by their nonce apart, each with its own set of signers
+    #[test]
+    fn test_compress_eth_events_with_different_nonces() {
+        if !is_bridge_comptime_enabled() {
+            // NOTE: this test doesn't work if the ethereum bridge
+            // is disabled at compile time.
+            return;
+        }
+        let (shell, _, _, _) = setup_at_height(3u64);
+        let address = shell.mode.get_validator_address().unwrap().clone();
+        let protocol_key = shell.mode.get_protocol_key().expect("Test failed");
+        let transfer = TransferToNamada {
+            amount: 100.into(),
+            asset: EthAddress([1; 20]),
+            receiver: gen_established_address(),
+        };
+        let event = |nonce: u64| EthereumEvent::TransfersToNamada {
+            nonce: nonce.into(),
+            transfers: vec![transfer.clone()],
+        };
+        let (event_1, event_2) = (event(0), event(1));
+        let last_height = shell.state.in_mem().get_last_block_height();
+        let prev_height = last_height.prev_height().expect("Test failed");
+
+        // the first event is voted on at two heights, the second one only
+        // at the last height
+        let vote_extensions = vec![
+            ethereum_events::Vext {
+                ethereum_events: vec![event_1.clone()],
+                block_height: prev_height,
+                validator_addr: address.clone(),
+            }
+            .sign(protocol_key),
+            ethereum_events::Vext {
+                ethereum_events: vec![event_1.clone(), event_2.clone()],
+                block_height: last_height,
+                validator_addr: address.clone(),
+            }
+            .sign(protocol_key),
+        ];
+        let digest = shell
+            .compress_ethereum_events(vote_extensions)
+            .expect("Test failed");
+
+        assert_eq!(
+            digest.events,
+            vec![
+                MultiSignedEthEvent {
+                    event: event_1,
+                    signers: [
+                        (address.clone(), prev_height),
+                        (address.clone(), last_height),
+                    ]
+                    .into(),
+                },
+                MultiSignedEthEvent {
+                    event: event_2,
+                    signers: [(address, last_height)].into(),
+                },
+            ]
+        );
+    }
 }
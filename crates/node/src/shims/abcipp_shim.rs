@@ -87,6 +87,7 @@ impl AbcippShim {
                 shutdown: server_shutdown.clone(),
                 action_at_height,
                 suspended: false,
+                block_time: None,
             },
             server_shutdown,
         )
@@ -321,8 +322,8 @@ impl AbcippShim {
 enum CheckAction {
     /// No check necessary.
     NoAction,
-    /// Check a given block height.
-    Check(i64),
+    /// Check a given block height and block time.
+    Check(i64, Option<DateTimeUtc>),
     /// The action been taken.
     AlreadySuspended,
 }
@@ -339,35 +340,57 @@ pub struct AbciService {
     /// This resolves the non-completing futures returned to tower-abci
     /// during suspension.
     shutdown: broadcast::Sender<()>,
-    /// An action to be taken at a specified block height.
+    /// An action to be taken at a specified block height or block time.
     action_at_height: Option<ActionAtHeight>,
+    /// The time of the block currently being finalized.
+    block_time: Option<DateTimeUtc>,
 }
 
 impl AbciService {
-    /// Check if we are at a block height with a scheduled action.
-    /// If so, perform the action.
+    /// Check if we are at a block height or block time with a scheduled
+    /// action. If so, perform the action.
     fn maybe_take_action(
         action_at_height: Option<ActionAtHeight>,
         check: CheckAction,
         mut shutdown_recv: broadcast::Receiver<()>,
     ) -> (bool, Option<<Self as Service<Req>>::Future>) {
-        let hght = match check {
-            CheckAction::AlreadySuspended => BlockHeight::from(u64::MAX),
-            CheckAction::Check(hght) => BlockHeight::from(
-                u64::try_from(hght).expect("Height cannot be negative"),
+        let (hght, block_time) = match check {
+            CheckAction::AlreadySuspended => {
+                (BlockHeight::from(u64::MAX), None)
+            }
+            CheckAction::Check(hght, block_time) => (
+                BlockHeight::from(
+                    u64::try_from(hght).expect("Height cannot be negative"),
+                ),
+                block_time,
             ),
-            CheckAction::NoAction => BlockHeight::default(),
+            CheckAction::NoAction => (BlockHeight::default(), None),
+        };
+        let Some(ActionAtHeight {
+            height,
+            time,
+            action,
+        }) = action_at_height
+        else {
+            return (false, None);
+        };
+        // The target that was reached by the current block, if any
+        let reached = if height == Some(hght) {
+            Some(format!("block height {}", hght))
+        } else {
+            block_time
+                .filter(|block_time| {
+                    time.is_some_and(|time| time <= *block_time)
+                })
+                .map(|block_time| format!("block time {}", block_time))
         };
-        match action_at_height {
-            Some(ActionAtHeight {
-                height,
-                action: Action::Suspend,
-            }) if height <= hght => {
-                if height == hght {
-                    tracing::info!(
-                        "Reached block height {}, suspending.",
-                        height
-                    );
+        // Whether we're already suspended or past the target height
+        let past_target = hght == BlockHeight::from(u64::MAX)
+            || height.is_some_and(|height| height <= hght);
+        match action {
+            Action::Suspend if past_target || reached.is_some() => {
+                if let Some(reached) = reached {
+                    tracing::info!("Reached {}, suspending.", reached);
                     tracing::warn!(
                         "\x1b[93mThis feature is intended for debugging \
                          purposes. Note that on shutdown a spurious panic \
@@ -389,28 +412,25 @@ impl AbciService {
                     ),
                 )
             }
-            Some(ActionAtHeight {
-                height,
-                action: Action::Halt,
-            }) if height == hght => {
-                tracing::info!(
-                    "Reached block height {}, halting the chain.",
-                    height
-                );
-                (
-                    false,
-                    Some(
-                        async move {
-                            Err(BoxError::from(format!(
-                                "Reached block height {}, halting the chain.",
-                                height
-                            )))
-                        }
-                        .boxed(),
-                    ),
-                )
-            }
-            _ => (false, None),
+            Action::Halt => match reached {
+                Some(reached) => {
+                    tracing::info!("Reached {}, halting the chain.", reached);
+                    (
+                        false,
+                        Some(
+                            async move {
+                                Err(BoxError::from(format!(
+                                    "Reached {}, halting the chain.",
+                                    reached
+                                )))
+                            }
+                            .boxed(),
+                        ),
+                    )
+                }
+                None => (false, None),
+            },
+            Action::Suspend => (false, None),
         }
     }
 
@@ -464,13 +484,17 @@ impl AbciService {
     /// to possibly take an action.
     fn get_action(&self, req: &Req) -> Option<CheckAction> {
         match req {
-            Req::PrepareProposal(req) => {
-                Some(CheckAction::Check(req.height.into()))
+            Req::PrepareProposal(req) => Some(CheckAction::Check(
+                req.height.into(),
+                DateTimeUtc::try_from(req.time).ok(),
+            )),
+            Req::ProcessProposal(req) => Some(CheckAction::Check(
+                req.height.into(),
+                DateTimeUtc::try_from(req.time).ok(),
+            )),
+            Req::EndBlock(req) => {
+                Some(CheckAction::Check(req.height, self.block_time))
             }
-            Req::ProcessProposal(req) => {
-                Some(CheckAction::Check(req.height.into()))
-            }
-            Req::EndBlock(req) => Some(CheckAction::Check(req.height)),
             Req::BeginBlock(_)
             | Req::DeliverTx(_)
             | Req::InitChain(_)
@@ -504,6 +528,9 @@ impl Service<Req> for AbciService {
     }
 
     fn call(&mut self, req: Req) -> Self::Future {
+        if let Req::BeginBlock(req) = &req {
+            self.block_time = DateTimeUtc::try_from(req.header.time).ok();
+        }
         let action = self.get_action(&req);
         if let Some(action) = action {
             let (suspended, fut) = Self::maybe_take_action(
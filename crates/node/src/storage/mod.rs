@@ -9,7 +9,10 @@ use arse_merkle_tree::blake2b::Blake2bHasher;
 use arse_merkle_tree::traits::Hasher;
 use arse_merkle_tree::H256;
 use blake2b_rs::{Blake2b, Blake2bBuilder};
-use namada_sdk::state::{FullAccessState, StorageHasher};
+use namada_sdk::hash::Hash;
+use namada_sdk::state::merkle_tree::NO_DIFF_KEY_PREFIX;
+use namada_sdk::state::{DBIter, FullAccessState, StorageHasher, StoreType, DB};
+use namada_sdk::storage::{Key, KeySeg};
 pub use rocksdb::{open, DbSnapshot, RocksDBUpdateVisitor, SnapshotMetadata};
 
 #[derive(Default)]
@@ -50,6 +53,85 @@ fn new_blake2b() -> Blake2b {
     Blake2bBuilder::new(32).personal(b"namada storage").build()
 }
 
+/// Check the consistency of the state committed in the DB at the last block
+/// height. The merkle tree is restored from the DB and validated against its
+/// stored roots, then every value in the subspace is checked against its leaf
+/// in the tree. Returns a description of each inconsistency found.
+pub fn verify_state<D, H>(state: &FullAccessState<D, H>) -> Vec<String>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter>,
+    H: 'static + StorageHasher,
+{
+    let mut inconsistencies = vec![];
+    if state.in_mem().last_block.is_none() {
+        inconsistencies.push("No committed block found in the DB".to_string());
+        return inconsistencies;
+    }
+    let height = state.in_mem().get_last_block_height();
+
+    let tree = match state.get_merkle_tree(height, None) {
+        Ok(tree) => tree,
+        Err(e) => {
+            inconsistencies.push(format!(
+                "Failed to restore the merkle tree at height {height}: {e}"
+            ));
+            return inconsistencies;
+        }
+    };
+    if let Err(e) = tree.validate() {
+        inconsistencies.push(format!(
+            "The merkle tree at height {height} doesn't match its stored \
+             roots: {e}"
+        ));
+    }
+
+    for (key, value, _gas) in state.db().iter_prefix(None) {
+        let key = match Key::parse(&key) {
+            Ok(key) => key,
+            Err(e) => {
+                inconsistencies
+                    .push(format!("Invalid storage key {key} in the DB: {e}"));
+                continue;
+            }
+        };
+        // Keys without diffs are merklized under the `NoDiff` subtree
+        let tree_key = if (state.diff_key_filter)(&key) {
+            key.clone()
+        } else {
+            Key::from(NO_DIFF_KEY_PREFIX.to_string().to_db_key()).join(&key)
+        };
+        let store_type = match StoreType::sub_key(&tree_key) {
+            Ok((store_type, _)) => store_type,
+            Err(e) => {
+                inconsistencies.push(format!(
+                    "Failed to find the subtree of key {key}: {e}"
+                ));
+                continue;
+            }
+        };
+        let expected = match store_type {
+            // The bridge pool tree stores the heights at which the keys were
+            // inserted, not the values
+            StoreType::BridgePool => continue,
+            // The IBC tree stores the raw values
+            StoreType::Ibc => value,
+            _ => Hash::from(H::hash(&value)).0.to_vec(),
+        };
+        match tree.get(&tree_key) {
+            Ok(leaf) if leaf == expected => {}
+            Ok(_) => inconsistencies.push(format!(
+                "The value of key {key} doesn't match its merkle tree leaf at \
+                 height {height}"
+            )),
+            Err(e) => inconsistencies.push(format!(
+                "Failed to read the merkle tree leaf of key {key} at height \
+                 {height}: {e}"
+            )),
+        }
+    }
+    inconsistencies
+}
+
 #[allow(clippy::arithmetic_side_effects, clippy::cast_sign_loss)]
 #[cfg(test)]
 mod tests {
@@ -594,6 +676,63 @@ mod tests {
         Ok(())
     }
 
+    /// Test that the committed state is consistent and that a value modified
+    /// without updating the merkle tree is detected
+    #[test]
+    fn test_verify_state() -> namada_sdk::state::Result<()> {
+        let db_path =
+            TempDir::new().expect("Unable to create a temporary DB directory");
+        let mut state = PersistentState::open(
+            db_path.path(),
+            None,
+            ChainId::default(),
+            address::testing::nam(),
+            None,
+            is_key_diff_storable,
+        );
+        // Nothing has been committed yet
+        assert_eq!(verify_state(&state).len(), 1);
+
+        let height = BlockHeight(1);
+        state.in_mem_mut().begin_block(height)?;
+        state.in_mem_mut().block.pred_epochs.new_epoch(height);
+        // Write non-provable data, provable data (IBC), and no diffed data
+        let key = Key::parse("account_key").unwrap();
+        let mut batch = PersistentState::batch();
+        for key in [key.clone(), ibc_key("key").unwrap(), client_counter_key()]
+        {
+            state.batch_write_subspace_val(&mut batch, &key, encode(&1_u64))?;
+        }
+        state.commit_block_from_batch(batch)?;
+        assert!(verify_state(&state).is_empty());
+
+        // The same state can be verified from a read-only DB handle
+        let read_only = PersistentState::from_db(
+            open(db_path.path(), true, None).expect("Could not open DB"),
+            ChainId::default(),
+            address::testing::nam(),
+            None,
+            is_key_diff_storable,
+        );
+        assert!(verify_state(&read_only).is_empty());
+
+        // Overwrite a value without updating the merkle tree
+        let mut batch = PersistentState::batch();
+        state.db().batch_write_subspace_val(
+            &mut batch,
+            height,
+            &key,
+            encode(&2_u64),
+            false,
+        )?;
+        state.exec_batch(batch)?;
+        let inconsistencies = verify_state(&state);
+        assert_eq!(inconsistencies.len(), 1);
+        assert!(inconsistencies[0].contains(&key.to_string()));
+
+        Ok(())
+    }
+
     /// Test the restore of the merkle tree
     #[test]
     fn test_prune_merkle_tree_stores() {
@@ -364,7 +364,7 @@ impl RocksDB {
         out_file_path: std::path::PathBuf,
         historic: bool,
         height: Option<BlockHeight>,
-    ) {
+    ) -> Result<()> {
         // Find the last block height
         let state_cf = self
             .get_column_family(STATE_CF)
@@ -376,6 +376,17 @@ impl RocksDB {
             .expect("No block height found");
 
         let height = height.unwrap_or(last_height);
+        // Every committed block has its time written under its height
+        let block_cf = self.get_column_family(BLOCK_CF)?;
+        let time_key = format!("{}/{BLOCK_TIME_KEY_SEGMENT}", height.raw());
+        if height > last_height
+            || self.read_value_bytes(block_cf, time_key)?.is_none()
+        {
+            return Err(Error::DBError(format!(
+                "The block at height {height} is not present in the DB, the \
+                 last committed height is {last_height}"
+            )));
+        }
 
         let full_path = out_file_path
             .with_file_name(format!(
@@ -465,6 +476,7 @@ impl RocksDB {
         }
 
         println!("Done writing to {}", full_path.to_string_lossy());
+        Ok(())
     }
 
     /// Dump data
@@ -2647,6 +2659,64 @@ mod test {
         }
     }
 
+    /// Test that a block can be dumped at a given height.
+    #[test]
+    fn test_dump_block_at_height() {
+        let dir = tempdir().unwrap();
+        let db = RocksDB::open(dir.path(), None);
+        let key = Key::parse("test").unwrap();
+
+        for height in 1..=3_u8 {
+            let mut batch = RocksDB::batch();
+            db.batch_write_subspace_val(
+                &mut batch,
+                BlockHeight(height.into()),
+                &key,
+                vec![height],
+                true,
+            )
+            .unwrap();
+            add_block_to_batch(
+                &db,
+                &mut batch,
+                BlockHeight(height.into()),
+                Epoch::default(),
+                Epochs::default(),
+                &ConversionState::default(),
+            )
+            .unwrap();
+            db.exec_batch(batch).unwrap();
+        }
+
+        let out_file_path = dir.path().join("dump");
+        let dumped = |height: u64| {
+            std::fs::read_to_string(
+                dir.path().join(format!("dump_{height}.toml")),
+            )
+            .unwrap()
+        };
+
+        db.dump_block(out_file_path.clone(), false, Some(BlockHeight(2)))
+            .unwrap();
+        assert_eq!(dumped(2), "\"test\" = \"02\"\n");
+
+        // defaults to the last committed height
+        db.dump_block(out_file_path.clone(), false, None).unwrap();
+        assert_eq!(dumped(3), "\"test\" = \"03\"\n");
+
+        // heights without a committed block are rejected
+        for height in [0, 4] {
+            assert!(
+                db.dump_block(
+                    out_file_path.clone(),
+                    false,
+                    Some(BlockHeight(height))
+                )
+                .is_err()
+            );
+        }
+    }
+
     /// A test helper to write a block
     fn add_block_to_batch(
         db: &RocksDB,
@@ -21,6 +21,9 @@ struct Keys {
     active_status: &'static str,
     /// Sub-key for storing the minimum confirmations parameter
     min_confirmations: &'static str,
+    /// Sub-key for storing the fraction of the voting power needed to
+    /// confirm a tally of Ethereum bridge votes.
+    confirmation_threshold: &'static str,
     /// Sub-key for storing the Ethereum address for wNam.
     native_erc20: &'static str,
     /// Sub-lkey for storing the Ethereum address of the bridge contract.
@@ -1,6 +1,6 @@
 //! Proof-of-Stake storage keys and storage integration.
 
-use namada_core::address::Address;
+use namada_core::address::{Address, InternalAddress};
 use namada_core::storage::{DbKeySeg, Epoch, Key, KeySeg};
 use namada_storage::collections::{lazy_map, lazy_vec};
 
@@ -67,7 +67,9 @@ const DELEGATION_TARGETS_PREFIX: &str = "delegation_targets";
 /// Is the given key a PoS storage key?
 pub fn is_pos_key(key: &Key) -> bool {
     match &key.segments.first() {
-        Some(DbKeySeg::AddressSeg(addr)) => addr == &ADDRESS,
+        Some(DbKeySeg::AddressSeg(addr)) => {
+            addr.as_internal() == Some(&InternalAddress::PoS)
+        }
         _ => false,
     }
 }
@@ -1,6 +1,7 @@
 //! Structures encapsulating SDK arguments
 
 use std::fmt::Display;
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::time::Duration as StdDuration;
@@ -25,6 +26,7 @@ use namada_tx::Memo;
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroizing;
 
+use crate::bip39::MnemonicType;
 use crate::eth_bridge::bridge_pool;
 use crate::ibc::core::host::types::identifiers::{ChannelId, PortId};
 use crate::signing::SigningTxData;
@@ -125,6 +127,39 @@ pub struct Query<C: NamadaTypes = SdkTypes> {
     pub ledger_address: C::ConfigRpcTendermintAddress,
 }
 
+/// The format in which query results are printed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// Machine-readable JSON
+    Json,
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Text => write!(f, "text"),
+            OutputFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!(
+                "invalid output format: {s}, expected text or json"
+            )),
+        }
+    }
+}
+
 /// Common query arguments
 #[derive(Clone, Debug)]
 pub struct QueryWithoutCtx<C: NamadaTypes = SdkTypes> {
@@ -742,7 +777,7 @@ pub struct VoteProposal<C: NamadaTypes = SdkTypes> {
     pub tx: Tx<C>,
     /// Proposal id
     pub proposal_id: u64,
-    /// The vote
+    /// The vote, one of yay, nay or abstain
     pub vote: String,
     /// The address of the voter
     pub voter_address: C::Address,
@@ -1344,6 +1379,15 @@ impl RevealPk {
     }
 }
 
+/// Reveal several public keys, with one transaction per key
+#[derive(Clone, Debug)]
+pub struct RevealPks<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The public keys to be revealed on-chain
+    pub public_keys: Vec<C::PublicKey>,
+}
+
 /// Generate shell completions
 #[derive(Clone, Debug)]
 pub struct Complete {
@@ -1433,6 +1477,8 @@ pub struct QueryProposal<C: NamadaTypes = SdkTypes> {
     pub query: Query<C>,
     /// Proposal id
     pub proposal_id: Option<u64>,
+    /// Print the votes cast on the proposal with their voting power
+    pub votes: bool,
 }
 
 /// Query protocol parameters
@@ -1449,6 +1495,13 @@ pub struct QueryPgf<C: NamadaTypes = SdkTypes> {
     pub query: Query<C>,
 }
 
+/// Query the contents of the Ethereum bridge pool
+#[derive(Clone, Debug)]
+pub struct QueryEthBridgePool<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+}
+
 /// Withdraw arguments
 #[derive(Clone, Debug)]
 pub struct Withdraw<C: NamadaTypes = SdkTypes> {
@@ -1554,6 +1607,8 @@ pub struct QueryConversions<C: NamadaTypes = SdkTypes> {
     pub token: Option<C::Address>,
     /// Epoch of the asset
     pub epoch: Option<MaspEpoch>,
+    /// The format in which to print the conversions
+    pub output_format: OutputFormat,
 }
 
 /// Query token balance(s)
@@ -1572,10 +1627,25 @@ pub struct QueryBalance<C: NamadaTypes = SdkTypes> {
     pub query: Query<C>,
     /// Address of an owner
     pub owner: C::BalanceOwner,
-    /// Address of a token
-    pub token: C::Address,
+    /// Addresses of the tokens, or all the tokens known to the wallet if
+    /// empty
+    pub tokens: Vec<C::Address>,
     /// Whether not to convert balances
     pub no_conversions: bool,
+    /// The epoch at the end of which to query the balances, if not the
+    /// latest
+    pub epoch: Option<Epoch>,
+    /// The format in which to print the balances
+    pub output_format: OutputFormat,
+}
+
+/// Query the total supply of a token
+#[derive(Clone, Debug)]
+pub struct QueryTotalSupply<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of a token
+    pub token: C::Address,
 }
 
 /// Query historical transfer(s)
@@ -1598,6 +1668,12 @@ pub struct QueryBonds<C: NamadaTypes = SdkTypes> {
     pub owner: Option<C::Address>,
     /// Address of a validator
     pub validator: Option<C::Address>,
+    /// Only include bonds and unbonds from this epoch onwards
+    pub from_epoch: Option<Epoch>,
+    /// Only include bonds and unbonds up to this epoch (inclusive)
+    pub to_epoch: Option<Epoch>,
+    /// The format in which to print the bonds
+    pub output_format: OutputFormat,
 }
 
 /// Query PoS bonded stake
@@ -1609,6 +1685,33 @@ pub struct QueryBondedStake<C: NamadaTypes = SdkTypes> {
     pub validator: Option<C::Address>,
     /// Epoch in which to find bonded stake
     pub epoch: Option<Epoch>,
+    /// How to sort the listed validators, if at all
+    pub sort: Option<ValidatorSortBy>,
+    /// Sort the listed validators in descending order
+    pub desc: bool,
+}
+
+/// The key by which to sort a list of validators
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidatorSortBy {
+    /// Sort by bonded stake
+    Stake,
+    /// Sort by address
+    Address,
+}
+
+impl FromStr for ValidatorSortBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "stake" => Ok(ValidatorSortBy::Stake),
+            "address" => Ok(ValidatorSortBy::Address),
+            _ => Err(format!(
+                "invalid validator sort key: {s}, expected stake or address"
+            )),
+        }
+    }
 }
 
 /// Query the state of a validator (its validator set or if it is jailed)
@@ -2111,6 +2214,24 @@ pub struct SignTx<C: NamadaTypes = SdkTypes> {
     pub tx_data: C::Data,
     /// The account address
     pub owner: C::Address,
+    /// The public keys of the account, in the order they were registered.
+    /// When given, they aren't queried from the node.
+    pub account_public_keys: Vec<C::PublicKey>,
+    /// The threshold of the account, required with the account's public
+    /// keys for an established account
+    pub threshold: Option<u8>,
+    /// Write the signed tx to this file instead of writing the signatures
+    /// to separate files
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug)]
+/// Broadcast a signed transaction
+pub struct BroadcastTx<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Transaction data
+    pub tx_data: C::Data,
 }
 
 #[derive(Clone, Debug)]
@@ -2133,6 +2254,8 @@ pub struct ShieldedSync<C: NamadaTypes = SdkTypes> {
     /// If present, the shielded sync will be performed
     /// using data retrieved from the given indexer
     pub with_indexer: Option<C::MaspIndexerAddress>,
+    /// Number of threads used to trial-decrypt notes with the viewing keys
+    pub scan_jobs: NonZeroUsize,
 }
 
 /// Query PoS commission rate
@@ -2144,6 +2267,10 @@ pub struct QueryCommissionRate<C: NamadaTypes = SdkTypes> {
     pub validator: C::Address,
     /// Epoch in which to find commission rate
     pub epoch: Option<Epoch>,
+    /// Find the commission rate in every epoch from this one onwards
+    pub from_epoch: Option<Epoch>,
+    /// Find the commission rate in every epoch up to this one (inclusive)
+    pub to_epoch: Option<Epoch>,
 }
 
 /// Query validator metadata
@@ -2162,6 +2289,8 @@ pub struct QuerySlashes<C: NamadaTypes = SdkTypes> {
     pub query: Query<C>,
     /// Address of a validator
     pub validator: Option<C::Address>,
+    /// Address of a bond owner whose slashed amounts to show
+    pub owner: Option<C::Address>,
 }
 
 /// Query PoS rewards
@@ -2184,6 +2313,15 @@ pub struct QueryDelegations<C: NamadaTypes = SdkTypes> {
     pub owner: C::Address,
 }
 
+/// Query the epoch of the last committed block
+#[derive(Clone, Debug)]
+pub struct QueryEpoch<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Keep printing the epoch each time it advances
+    pub watch: bool,
+}
+
 /// Query PoS to find a validator
 #[derive(Clone, Debug)]
 pub struct QueryFindValidator<C: NamadaTypes = SdkTypes> {
@@ -2200,10 +2338,63 @@ pub struct QueryFindValidator<C: NamadaTypes = SdkTypes> {
 pub struct QueryRawBytes<C: NamadaTypes = SdkTypes> {
     /// The storage key to query
     pub storage_key: storage::Key,
+    /// Query all the keys under the storage key used as a prefix
+    pub prefix: bool,
+    /// The type to decode the raw bytes as, if any
+    pub decode_as: Option<RawValueType>,
     /// Common query args
     pub query: Query<C>,
 }
 
+/// The known types that raw storage values can be decoded as
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RawValueType {
+    /// A token amount
+    Amount,
+    /// An epoch
+    Epoch,
+    /// An address
+    Address,
+    /// An unsigned 64-bit integer
+    U64,
+    /// A boolean
+    Bool,
+    /// A public key
+    PublicKey,
+}
+
+impl Display for RawValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RawValueType::Amount => write!(f, "amount"),
+            RawValueType::Epoch => write!(f, "epoch"),
+            RawValueType::Address => write!(f, "address"),
+            RawValueType::U64 => write!(f, "u64"),
+            RawValueType::Bool => write!(f, "bool"),
+            RawValueType::PublicKey => write!(f, "public-key"),
+        }
+    }
+}
+
+impl FromStr for RawValueType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "amount" => Ok(RawValueType::Amount),
+            "epoch" => Ok(RawValueType::Epoch),
+            "address" => Ok(RawValueType::Address),
+            "u64" => Ok(RawValueType::U64),
+            "bool" => Ok(RawValueType::Bool),
+            "public-key" => Ok(RawValueType::PublicKey),
+            _ => Err(format!(
+                "invalid value type: {s}, expected one of amount, epoch, \
+                 address, u64, bool or public-key"
+            )),
+        }
+    }
+}
+
 /// The possible values for the tx expiration
 #[derive(Clone, Debug, Default)]
 pub enum TxExpiration {
@@ -2232,6 +2423,46 @@ impl TxExpiration {
     }
 }
 
+impl FromStr for TxExpiration {
+    type Err = String;
+
+    /// Parses either an absolute RFC3339 datetime or a duration relative to
+    /// the current time, written as `+<number><unit>` with the unit being one
+    /// of `s` (seconds), `m` (minutes) or `h` (hours), e.g. `+10m`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(duration) = s.strip_prefix('+') else {
+            return DateTimeUtc::from_str(s)
+                .map(TxExpiration::Custom)
+                .map_err(|err| format!("Invalid expiration datetime: {err}"));
+        };
+        let unit_secs: u64 = match duration.chars().last() {
+            Some('s') => 1,
+            Some('m') => 60,
+            Some('h') => 60 * 60,
+            _ => {
+                return Err(format!(
+                    "Invalid relative expiration {s}, expected a duration \
+                     in seconds, minutes or hours, e.g. +30s, +5m or +1h"
+                ));
+            }
+        };
+        let secs = duration[..duration.len() - 1]
+            .parse::<u64>()
+            .ok()
+            .and_then(|value| value.checked_mul(unit_secs))
+            .ok_or_else(|| format!("Invalid relative expiration {s}"))?;
+        let duration =
+            namada_core::time::Duration::from_std(StdDuration::from_secs(secs))
+                .map_err(|_| format!("Relative expiration {s} is too large"))?;
+        #[allow(clippy::disallowed_methods)]
+        let now = DateTimeUtc::now();
+        now.0
+            .checked_add_signed(duration)
+            .map(|exp| TxExpiration::Custom(exp.into()))
+            .ok_or_else(|| format!("Relative expiration {s} is too large"))
+    }
+}
+
 /// Common transaction arguments
 #[derive(Clone, Debug)]
 pub struct Tx<C: NamadaTypes = SdkTypes> {
@@ -2243,6 +2474,8 @@ pub struct Tx<C: NamadaTypes = SdkTypes> {
     pub dump_tx: bool,
     /// The output directory path to where serialize the data
     pub output_folder: Option<PathBuf>,
+    /// The file to which to dump the transaction, `-` for stdout
+    pub dump_tx_path: Option<PathBuf>,
     /// Submit the transaction even if it doesn't pass client checks
     pub force: bool,
     /// Do not wait for the transaction to be added to the blockchain
@@ -2312,6 +2545,13 @@ pub trait TxBuilder<C: NamadaTypes>: Sized {
             ..x
         })
     }
+    /// The file to which to dump the transaction, `-` for stdout
+    fn dump_tx_path(self, dump_tx_path: PathBuf) -> Self {
+        self.tx(|x| Tx {
+            dump_tx_path: Some(dump_tx_path),
+            ..x
+        })
+    }
     /// Submit the transaction even if it doesn't pass client checks
     fn force(self, force: bool) -> Self {
         self.tx(|x| Tx { force, ..x })
@@ -2447,6 +2687,10 @@ pub struct KeyGen {
     pub prompt_bip39_passphrase: bool,
     /// Allow non-compliant derivation path
     pub allow_non_compliant: bool,
+    /// The type of the generated BIP39 mnemonic code
+    pub mnemonic_type: MnemonicType,
+    /// Store the mnemonic code in the wallet to derive further keys from it
+    pub store_mnemonic: bool,
 }
 
 /// Wallet restore key and implicit address arguments
@@ -2470,6 +2714,21 @@ pub struct KeyDerive {
     pub prompt_bip39_passphrase: bool,
     /// Use device to generate key and address
     pub use_device: bool,
+    /// Derive the next key from the mnemonic code stored for the alias
+    pub from_stored_mnemonic: bool,
+}
+
+/// Wallet mnemonic check arguments
+#[derive(Clone, Debug)]
+pub struct KeyMnemonicCheck {
+    /// Scheme type
+    pub scheme: SchemeType,
+    /// BIP44 derivation path
+    pub derivation_path: String,
+    /// Allow non-compliant derivation path
+    pub allow_non_compliant: bool,
+    /// Prompt for BIP39 passphrase
+    pub prompt_bip39_passphrase: bool,
 }
 
 /// Wallet list arguments
@@ -2487,6 +2746,10 @@ pub struct KeyAddressList {
     pub decrypt: bool,
     /// Show secret keys to user
     pub unsafe_show_secret: bool,
+    /// Only list transparent keys of the given scheme
+    pub scheme: Option<SchemeType>,
+    /// The format in which to print the keys and addresses
+    pub output_format: OutputFormat,
 }
 
 /// Wallet key / address lookup arguments
@@ -2560,6 +2823,17 @@ pub struct KeyAddressRemove {
     pub do_it: bool,
 }
 
+/// Wallet key / address rename arguments
+#[derive(Clone, Debug)]
+pub struct KeyAddressRename {
+    /// The alias to rename
+    pub alias: String,
+    /// The new alias
+    pub new_alias: String,
+    /// Whether to force overwrite the new alias
+    pub alias_force: bool,
+}
+
 /// Generate payment address arguments
 #[derive(Clone, Debug)]
 pub struct PayAddressGen<C: NamadaTypes = SdkTypes> {
@@ -2569,6 +2843,8 @@ pub struct PayAddressGen<C: NamadaTypes = SdkTypes> {
     pub alias_force: bool,
     /// Viewing key
     pub viewing_key: C::ViewingKey,
+    /// Diversifier index to derive the payment address from
+    pub diversifier_index: Option<u64>,
 }
 
 /// Bridge pool batch recommendation.
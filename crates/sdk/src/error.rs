@@ -1,6 +1,7 @@
 //! Generic Error Type for all of the Shared Crate
 
 use namada_core::address::Address;
+use namada_core::chain::ChainId;
 use namada_core::dec::Dec;
 use namada_core::ethereum_events::EthAddress;
 use namada_core::storage::Epoch;
@@ -58,6 +59,9 @@ pub enum QueryError {
     /// Error that corresponds to not receiving any response
     #[error("No response given in the query: {0}")]
     NoResponse(String),
+    /// Error that occurs when querying an epoch that has not started yet
+    #[error("The epoch {0} is in the future, the current epoch is {1}")]
+    FutureEpoch(Epoch, Epoch),
     /// Error that corresponds to a general error
     #[error("Error in the query: {0}")]
     General(String),
@@ -111,6 +115,12 @@ pub enum TxSubmitError {
     /// Error during broadcasting a transaction
     #[error("Encountered error while broadcasting transaction: {0}")]
     TxBroadcast(RpcError),
+    /// The transaction targets another chain than the node's
+    #[error(
+        "The transaction's chain ID {0} doesn't match the chain ID {1} of the \
+         node it would be broadcast to"
+    )]
+    ChainIdMismatch(ChainId, ChainId),
     /// Invalid commission rate set
     #[error("Invalid new commission rate, received {0}")]
     InvalidCommissionRate(Dec),
@@ -238,7 +248,7 @@ pub enum TxSubmitError {
     #[error("Proposal data are invalid: {0}")]
     InvalidProposal(String),
     /// The proposal vote is not valid
-    #[error("Proposal vote is invalid")]
+    #[error("Proposal vote is invalid, expected yay, nay or abstain")]
     InvalidProposalVote,
     /// The proposal can't be voted
     #[error("Proposal {0} can't be voted")]
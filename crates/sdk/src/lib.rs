@@ -147,6 +147,7 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             dry_run_wrapper: false,
             dump_tx: false,
             output_folder: None,
+            dump_tx_path: None,
             force: false,
             broadcast_only: false,
             ledger_address: tendermint_rpc::Url::from_str(
@@ -718,6 +719,7 @@ where
                 dry_run_wrapper: false,
                 dump_tx: false,
                 output_folder: None,
+                dump_tx_path: None,
                 force: false,
                 broadcast_only: false,
                 ledger_address: tendermint_rpc::Url::from_str(
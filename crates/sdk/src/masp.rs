@@ -7,6 +7,7 @@ use std::cmp::Ordering;
 use std::collections::{btree_map, BTreeMap, BTreeSet};
 use std::fmt::Debug;
 use std::io::{Read, Write};
+use std::num::NonZeroUsize;
 use std::sync::{Arc, Mutex};
 
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -268,6 +269,150 @@ pub fn find_valid_diversifier<R: RngCore + CryptoRng>(
     (diversifier, g_d)
 }
 
+/// A note decrypted from a shielded output, along with the index of that
+/// output among all the outputs of the scanned transactions, the payment
+/// address it was sent to and its memo
+type DecryptedNote = (
+    usize,
+    Note,
+    masp_primitives::sapling::PaymentAddress,
+    MemoBytes,
+);
+
+/// Try to decrypt the outputs of the given transactions with the given viewing
+/// key. Returns the notes that could be decrypted, in the order of the outputs.
+fn trial_decrypt(
+    shielded: &[Transaction],
+    vk: &ViewingKey,
+) -> Vec<DecryptedNote> {
+    type Proof = OutputDescription<
+        <
+            <Authorized as Authorization>::SaplingAuth
+            as masp_primitives::transaction::components::sapling::Authorization
+        >::Proof
+    >;
+
+    let ivk = PreparedIncomingViewingKey::new(&vk.ivk());
+    shielded
+        .iter()
+        .flat_map(|tx| {
+            tx.sapling_bundle()
+                .into_iter()
+                .flat_map(|x| x.shielded_outputs.iter())
+        })
+        .enumerate()
+        .filter_map(|(output_index, so)| {
+            let (note, pa, memo) = try_sapling_note_decryption::<_, Proof>(
+                &NETWORK,
+                1.into(),
+                &ivk,
+                so,
+            )?;
+            Some((output_index, note, pa, memo))
+        })
+        .collect()
+}
+
+/// The notes that each viewing key decrypted from the outputs of a transaction
+#[cfg(not(target_family = "wasm"))]
+type DecryptedTx = BTreeMap<ViewingKey, Vec<DecryptedNote>>;
+
+/// The number of fetched transactions that are trial-decrypted together
+#[cfg(not(target_family = "wasm"))]
+const TRIAL_DECRYPTION_BATCH_SIZE: usize = 64;
+
+/// The viewing keys that have not been synced up to the given transaction yet
+#[cfg(not(target_family = "wasm"))]
+fn vks_to_scan<'a>(
+    vk_heights: &'a BTreeMap<ViewingKey, Option<IndexedTx>>,
+    indexed_tx: &'a IndexedTx,
+) -> impl Iterator<Item = ViewingKey> + 'a {
+    vk_heights
+        .iter()
+        .filter(move |(_vk, h)| h.as_ref() < Some(indexed_tx))
+        .map(|(vk, _h)| *vk)
+}
+
+/// Trial-decrypts batches of fetched transactions with the viewing keys that
+/// need to scan them. With the `multicore` feature and more than one scan job,
+/// the work is spread over a pool of threads that lives for the whole shielded
+/// sync, off the async executor. Otherwise, the batches are decrypted
+/// sequentially.
+#[cfg(not(target_family = "wasm"))]
+struct TrialDecryptor {
+    #[cfg(any(feature = "multicore", test))]
+    pool: Option<rayon::ThreadPool>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl TrialDecryptor {
+    /// Start a trial decryptor running up to `scan_jobs` threads
+    fn new(scan_jobs: NonZeroUsize) -> Result<Self, Error> {
+        #[cfg(any(feature = "multicore", test))]
+        {
+            let pool = if scan_jobs.get() > 1 {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(scan_jobs.get())
+                    .build()
+                    .map_err(|e| {
+                        Error::Other(format!(
+                            "Failed to start the trial decryption threads: {e}"
+                        ))
+                    })?;
+                Some(pool)
+            } else {
+                None
+            };
+            Ok(Self { pool })
+        }
+        #[cfg(not(any(feature = "multicore", test)))]
+        {
+            let _ = scan_jobs;
+            Ok(Self {})
+        }
+    }
+
+    /// Trial-decrypt each transaction of `batch` with the viewing keys of
+    /// `vk_heights` that have not been synced up to it yet. Returns the batch
+    /// along with the notes decrypted from each of its transactions, in the
+    /// same order.
+    async fn decrypt(
+        &self,
+        batch: Vec<IndexedNoteEntry>,
+        vk_heights: BTreeMap<ViewingKey, Option<IndexedTx>>,
+    ) -> (Vec<IndexedNoteEntry>, Vec<DecryptedTx>) {
+        #[cfg(any(feature = "multicore", test))]
+        if let Some(pool) = &self.pool {
+            use rayon::prelude::*;
+
+            let (send, recv) = futures::channel::oneshot::channel();
+            pool.spawn(move || {
+                let decrypted: Vec<DecryptedTx> = batch
+                    .par_iter()
+                    .map(|(indexed_tx, stx)| {
+                        vks_to_scan(&vk_heights, indexed_tx)
+                            .collect::<Vec<_>>()
+                            .into_par_iter()
+                            .map(|vk| (vk, trial_decrypt(stx, &vk)))
+                            .collect::<DecryptedTx>()
+                    })
+                    .collect();
+                let _ = send.send((batch, decrypted));
+            });
+            return recv.await.expect("Trial decryption should not panic");
+        }
+        let decrypted = batch
+            .iter()
+            .map(|(indexed_tx, stx)| {
+                vks_to_scan(&vk_heights, indexed_tx)
+                    .map(|vk| (vk, trial_decrypt(stx, &vk)))
+                    .collect()
+            })
+            .collect();
+        (batch, decrypted)
+    }
+}
+
 /// Determine if using the current note would actually bring us closer to our
 /// target. Returns the unused amounts (change) of delta if any
 pub fn is_amount_required(
@@ -539,6 +684,16 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         self.utils.clone().load(self, false).await
     }
 
+    /// Try to load the last saved shielded context like [`Self::load`], but
+    /// consider a context that was never saved as empty rather than failing.
+    /// Other errors, e.g. from a corrupt context file, are still returned.
+    pub async fn load_if_saved(&mut self) -> std::io::Result<()> {
+        match self.load().await {
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            res => res,
+        }
+    }
+
     /// Try to load the last saved confirmed shielded context from the given
     /// context directory. If this fails, then leave the current context
     /// unchanged.
@@ -604,6 +759,7 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         retry: RetryStrategy,
         sks: &[MaspExtendedSpendingKey],
         fvks: &[ViewingKey],
+        scan_jobs: NonZeroUsize,
     ) -> Result<(), Error>
     where
         IO: Io,
@@ -618,6 +774,7 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
             retry,
             sks,
             fvks,
+            scan_jobs,
             shutdown_signal,
         )
         .await
@@ -700,6 +857,7 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         retry: RetryStrategy,
         sks: &[MaspExtendedSpendingKey],
         fvks: &[ViewingKey],
+        scan_jobs: NonZeroUsize,
         mut shutdown_signal: ShutdownSignal,
     ) -> Result<(), Error>
     where
@@ -764,6 +922,7 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         self.update_with_pre_built_data(&client, last_query_height)
             .await?;
 
+        let trial_decryptor = TrialDecryptor::new(scan_jobs)?;
         for _ in retry {
             debug_assert!(start_height <= last_query_height);
 
@@ -794,30 +953,52 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
                 ),
                 _ => {}
             }
-            let txs = progress.scan(fetch_recv);
-            for (ref indexed_tx, ref stx) in txs {
-                if client.capabilities().needs_witness_map_update()
-                    && Some(indexed_tx) > last_witnessed_tx.as_ref()
-                {
-                    self.update_witness_map(indexed_tx.to_owned(), stx)?;
+            let mut txs = progress.scan(fetch_recv);
+            loop {
+                let batch: Vec<_> =
+                    txs.by_ref().take(TRIAL_DECRYPTION_BATCH_SIZE).collect();
+                if batch.is_empty() {
+                    break;
                 }
-                let mut vk_heights = BTreeMap::new();
-                std::mem::swap(&mut vk_heights, &mut self.vk_heights);
-                for (vk, h) in vk_heights
-                    .iter_mut()
-                    .filter(|(_vk, h)| h.as_ref() < Some(indexed_tx))
+                // Trial-decrypt the whole batch at once, then apply the results
+                // in order, to keep the resulting context independent of the
+                // number of jobs. NB: the keys to scan a tx with don't change
+                // within a batch, since they only get synced up to earlier txs.
+                let (batch, decrypted) = trial_decryptor
+                    .decrypt(batch, self.vk_heights.clone())
+                    .await;
+                for ((ref indexed_tx, ref stx), mut decrypted) in
+                    batch.into_iter().zip(decrypted)
                 {
-                    self.scan_tx(indexed_tx.to_owned(), stx, vk)?;
-                    *h = Some(indexed_tx.to_owned());
-                }
-                // possibly remove unneeded elements from the cache.
-                self.unscanned.scanned(indexed_tx);
-                std::mem::swap(&mut vk_heights, &mut self.vk_heights);
-                if shutdown_signal.received() {
-                    let _ = self.save().await;
-                    return Err(Error::Interrupt(
-                        "[ShieldedSync::Scanning]".to_string(),
-                    ));
+                    if client.capabilities().needs_witness_map_update()
+                        && Some(indexed_tx) > last_witnessed_tx.as_ref()
+                    {
+                        self.update_witness_map(indexed_tx.to_owned(), stx)?;
+                    }
+                    let mut vk_heights = BTreeMap::new();
+                    std::mem::swap(&mut vk_heights, &mut self.vk_heights);
+                    for (vk, h) in vk_heights
+                        .iter_mut()
+                        .filter(|(_vk, h)| h.as_ref() < Some(indexed_tx))
+                    {
+                        let notes = decrypted.remove(vk).unwrap_or_default();
+                        self.scan_decrypted_tx(
+                            indexed_tx.to_owned(),
+                            stx,
+                            vk,
+                            notes,
+                        )?;
+                        *h = Some(indexed_tx.to_owned());
+                    }
+                    // possibly remove unneeded elements from the cache.
+                    self.unscanned.scanned(indexed_tx);
+                    std::mem::swap(&mut vk_heights, &mut self.vk_heights);
+                    if shutdown_signal.received() {
+                        let _ = self.save().await;
+                        return Err(Error::Interrupt(
+                            "[ShieldedSync::Scanning]".to_string(),
+                        ));
+                    }
                 }
             }
 
@@ -885,72 +1066,65 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
         shielded: &[Transaction],
         vk: &ViewingKey,
     ) -> Result<(), Error> {
-        type Proof = OutputDescription<
-            <
-                <Authorized as Authorization>::SaplingAuth
-                as masp_primitives::transaction::components::sapling::Authorization
-            >::Proof
-        >;
+        // Listen for notes sent to our viewing keys, only if we are syncing
+        // (i.e. in a confirmed status)
+        let decrypted = match self.sync_status {
+            ContextSyncStatus::Confirmed => trial_decrypt(shielded, vk),
+            ContextSyncStatus::Speculative => vec![],
+        };
+        self.scan_decrypted_tx(indexed_tx, shielded, vk, decrypted)
+    }
 
+    /// Applies the given transaction to the supplied context, like
+    /// [`Self::scan_tx`], given the notes that `vk` already trial-decrypted
+    /// from the transaction's outputs.
+    fn scan_decrypted_tx(
+        &mut self,
+        indexed_tx: IndexedTx,
+        shielded: &[Transaction],
+        vk: &ViewingKey,
+        decrypted: Vec<DecryptedNote>,
+    ) -> Result<(), Error> {
         // For tracking the account changes caused by this Transaction
         let mut transaction_delta = TransactionDelta::new();
         if let ContextSyncStatus::Confirmed = self.sync_status {
-            let mut note_pos = self.tx_note_map[&indexed_tx];
-            // Listen for notes sent to our viewing keys, only if we are syncing
-            // (i.e. in a confirmed status)
-            for tx in shielded {
-                for so in
-                    tx.sapling_bundle().map_or(&vec![], |x| &x.shielded_outputs)
-                {
-                    // Let's try to see if this viewing key can decrypt latest
-                    // note
-                    let notes = self.pos_map.entry(*vk).or_default();
-                    let decres = try_sapling_note_decryption::<_, Proof>(
-                        &NETWORK,
-                        1.into(),
-                        &PreparedIncomingViewingKey::new(&vk.ivk()),
-                        so,
-                    );
-                    // So this current viewing key does decrypt this current
-                    // note...
-                    if let Some((note, pa, memo)) = decres {
-                        // Add this note to list of notes decrypted by this
-                        // viewing key
-                        notes.insert(note_pos);
-                        // Compute the nullifier now to quickly recognize when
-                        // spent
-                        let nf = note.nf(
-                            &vk.nk,
-                            note_pos.try_into().map_err(|_| {
-                                Error::Other(
-                                    "Can not get nullifier".to_string(),
-                                )
-                            })?,
-                        );
-                        self.note_map.insert(note_pos, note);
-                        self.memo_map.insert(note_pos, memo);
-                        // The payment address' diversifier is required to spend
-                        // note
-                        self.div_map.insert(note_pos, *pa.diversifier());
-                        self.nf_map.insert(nf, note_pos);
-                        // Note the account changes
-                        let balance = transaction_delta
-                            .entry(*vk)
-                            .or_insert_with(I128Sum::zero);
-                        *balance += I128Sum::from_nonnegative(
-                            note.asset_type,
-                            note.value as i128,
-                        )
-                        .map_err(|()| {
-                            Error::Other(
-                                "found note with invalid value or asset type"
-                                    .to_string(),
-                            )
-                        })?;
-                        self.vk_map.insert(note_pos, *vk);
-                    }
-                    note_pos += 1;
-                }
+            let first_note_pos = self.tx_note_map[&indexed_tx];
+            if shielded.iter().any(|tx| {
+                tx.sapling_bundle()
+                    .is_some_and(|x| !x.shielded_outputs.is_empty())
+            }) {
+                self.pos_map.entry(*vk).or_default();
+            }
+            for (output_index, note, pa, memo) in decrypted {
+                let note_pos = first_note_pos + output_index;
+                // Add this note to list of notes decrypted by this viewing key
+                self.pos_map.entry(*vk).or_default().insert(note_pos);
+                // Compute the nullifier now to quickly recognize when spent
+                let nf = note.nf(
+                    &vk.nk,
+                    note_pos.try_into().map_err(|_| {
+                        Error::Other("Can not get nullifier".to_string())
+                    })?,
+                );
+                self.note_map.insert(note_pos, note);
+                self.memo_map.insert(note_pos, memo);
+                // The payment address' diversifier is required to spend note
+                self.div_map.insert(note_pos, *pa.diversifier());
+                self.nf_map.insert(nf, note_pos);
+                // Note the account changes
+                let balance =
+                    transaction_delta.entry(*vk).or_insert_with(I128Sum::zero);
+                *balance += I128Sum::from_nonnegative(
+                    note.asset_type,
+                    note.value as i128,
+                )
+                .map_err(|()| {
+                    Error::Other(
+                        "found note with invalid value or asset type"
+                            .to_string(),
+                    )
+                })?;
+                self.vk_map.insert(note_pos, *vk);
             }
         }
 
@@ -1639,7 +1813,11 @@ impl<U: ShieldedUtils + MaybeSend + MaybeSync> ShieldedContext<U> {
             // Load the current shielded context given
             // the spending key we possess
             let mut shielded = context.shielded_mut().await;
-            let _ = shielded.load().await;
+            shielded.load_if_saved().await.map_err(|err| {
+                Error::Other(format!(
+                    "Failed to load the shielded context: {err}"
+                ))
+            })?;
         }
 
         let Some(MaspTxReorderedData {
@@ -3549,7 +3727,8 @@ pub mod fs {
 #[cfg(test)]
 mod test_shielded_sync {
     use core::str::FromStr;
-    use std::collections::BTreeSet;
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::num::NonZeroUsize;
 
     use borsh::BorshDeserialize;
     use masp_primitives::transaction::Transaction;
@@ -3680,6 +3859,24 @@ mod test_shielded_sync {
         .expect("Test failed")
     }
 
+    /// Test that a shielded context that was never saved loads as empty,
+    /// whereas a corrupt one fails to load
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_load_if_saved() {
+        let temp_dir = tempdir().unwrap();
+        let mut shielded_ctx =
+            FsShieldedUtils::new(temp_dir.path().to_path_buf());
+        shielded_ctx
+            .load_if_saved()
+            .await
+            .expect("A missing shielded context should load as empty");
+        assert!(shielded_ctx.vk_heights.is_empty());
+
+        std::fs::write(temp_dir.path().join("shielded.dat"), b"corrupt")
+            .unwrap();
+        assert!(shielded_ctx.load_if_saved().await.is_err());
+    }
+
     /// Test that if fetching fails before finishing,
     /// we re-establish the fetching process
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -3708,6 +3905,7 @@ mod test_shielded_sync {
                 RetryStrategy::Times(1),
                 &[],
                 &[vk],
+                NonZeroUsize::MIN,
             )
             .await
             .unwrap_err();
@@ -3752,6 +3950,7 @@ mod test_shielded_sync {
                 RetryStrategy::Times(2),
                 &[],
                 &[vk],
+                NonZeroUsize::MIN,
             )
             .await
             .expect("Test failed");
@@ -3869,6 +4068,7 @@ mod test_shielded_sync {
                 RetryStrategy::Times(1),
                 &[],
                 &[vk],
+                NonZeroUsize::MIN,
             )
             .await
             .unwrap_err();
@@ -3894,6 +4094,7 @@ mod test_shielded_sync {
                 RetryStrategy::Times(1),
                 &[],
                 &[vk],
+                NonZeroUsize::MIN,
             )
             .await
             .unwrap_err();
@@ -3910,6 +4111,7 @@ mod test_shielded_sync {
                 RetryStrategy::Times(1),
                 &[],
                 &[vk],
+                NonZeroUsize::MIN,
             )
             .await
             .unwrap_err();
@@ -3928,6 +4130,7 @@ mod test_shielded_sync {
                 RetryStrategy::Times(1),
                 &[],
                 &[vk],
+                NonZeroUsize::MIN,
             )
             .await
             .unwrap_err();
@@ -3964,6 +4167,7 @@ mod test_shielded_sync {
                 RetryStrategy::Times(1),
                 &[],
                 &[vk],
+                NonZeroUsize::MIN,
             )
             .await
             .expect("Test failed");
@@ -4017,6 +4221,7 @@ mod test_shielded_sync {
                 RetryStrategy::Times(2),
                 &[],
                 &[vk],
+                NonZeroUsize::MIN,
             )
             .await
             .expect("Test failed");
@@ -4076,6 +4281,7 @@ mod test_shielded_sync {
                 RetryStrategy::Forever,
                 &[],
                 &[vk],
+                NonZeroUsize::MIN,
                 shutdown_signal,
             )
             .await
@@ -4089,4 +4295,80 @@ mod test_shielded_sync {
         assert_eq!(entry, expected);
         assert!(shielded_ctx.unscanned.is_empty());
     }
+
+    /// Test that trial decryption yields the same notes, for the same txs
+    /// and keys, regardless of the number of scan jobs used
+    #[tokio::test]
+    async fn test_trial_decrypt_independent_of_scan_jobs() {
+        let batch: Vec<_> = (1..=3)
+            .map(|index| {
+                (
+                    IndexedTx {
+                        height: 1.into(),
+                        index: TxIndex(index),
+                    },
+                    vec![arbitrary_masp_tx()],
+                )
+            })
+            .collect();
+        let vk = ExtendedFullViewingKey::from(
+            ExtendedViewingKey::from_str(AA_VIEWING_KEY).expect("Test failed"),
+        )
+        .fvk
+        .vk;
+        // The key was already synced up to the first tx of the batch
+        let mut vk_heights = BTreeMap::from([(vk, Some(batch[0].0.clone()))]);
+        for seed in 1..=4u8 {
+            let spend_key =
+                masp_primitives::zip32::ExtendedSpendingKey::master(&[seed]);
+            vk_heights
+                .insert(ExtendedFullViewingKey::from(&spend_key).fvk.vk, None);
+        }
+
+        let mut results = vec![];
+        for jobs in [1, 2, 3, 8] {
+            let decryptor = super::TrialDecryptor::new(
+                NonZeroUsize::new(jobs).expect("Test failed"),
+            )
+            .expect("Test failed");
+            let (scanned, decrypted) =
+                decryptor.decrypt(batch.clone(), vk_heights.clone()).await;
+            let scanned: Vec<_> =
+                scanned.into_iter().map(|(tx, _)| tx).collect();
+            assert!(batch.iter().map(|(tx, _)| tx).eq(&scanned));
+            let decrypted: Vec<_> = decrypted
+                .into_iter()
+                .map(|notes| {
+                    notes
+                        .into_iter()
+                        .map(|(vk, notes)| {
+                            let notes: Vec<_> = notes
+                                .into_iter()
+                                .map(|(idx, note, pa, _memo)| {
+                                    (idx, note.value, pa)
+                                })
+                                .collect();
+                            (vk, notes)
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            results.push(decrypted);
+        }
+
+        let sequential = &results[0];
+        // The synced key skips the first tx, and decrypts the notes of the
+        // others, which the remaining keys can't decrypt
+        assert_eq!(sequential[0].len(), 4);
+        assert!(sequential[0].iter().all(|(_vk, notes)| notes.is_empty()));
+        for decrypted in &sequential[1..] {
+            assert_eq!(decrypted.len(), 5);
+            for (key, notes) in decrypted {
+                assert_eq!(*key == vk, !notes.is_empty());
+            }
+        }
+        for concurrent in &results[1..] {
+            assert_eq!(sequential, concurrent);
+        }
+    }
 }
@@ -10,6 +10,7 @@ use masp_primitives::sapling::Node;
 use namada_account::{Account, AccountPublicKeysMap};
 use namada_core::address::Address;
 use namada_core::arith::checked;
+use namada_core::chain::ChainId;
 use namada_core::dec::Dec;
 use namada_core::hash::Hash;
 use namada_core::hints;
@@ -69,6 +70,9 @@ router! {SHELL,
     // The address of the native token
     ( "native_token" ) -> Address = native_token,
 
+    // The ID of the chain
+    ( "chain_id" ) -> ChainId = chain_id,
+
     // Epoch of the input block height
     ( "epoch_at_height" / [height: BlockHeight]) -> Option<Epoch> = epoch_at_height,
 
@@ -78,6 +82,9 @@ router! {SHELL,
     // First block height of the current epoch
     ( "first_block_height_of_current_epoch" ) -> BlockHeight = first_block_height_of_current_epoch,
 
+    // First block height of the input epoch
+    ( "first_block_height_of_epoch" / [epoch: Epoch] ) -> Option<BlockHeight> = first_block_height_of_epoch,
+
     // Raw storage access - read value
     ( "value" / [storage_key: storage::Key] )
         -> Vec<u8> = (with_options storage_value),
@@ -385,6 +392,17 @@ where
     Ok(data)
 }
 
+fn chain_id<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<ChainId>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let data = ctx.state.in_mem().chain_id.clone();
+    Ok(data)
+}
+
 fn epoch_at_height<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     height: BlockHeight,
@@ -426,6 +444,22 @@ where
         .cloned()
 }
 
+fn first_block_height_of_epoch<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    epoch: Epoch,
+) -> namada_storage::Result<Option<BlockHeight>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    Ok(ctx
+        .state
+        .in_mem()
+        .block
+        .pred_epochs
+        .get_start_height_of_epoch(epoch))
+}
+
 /// Returns data with `vec![]` when the storage key is not found. For all
 /// borsh-encoded types, it is safe to check `data.is_empty()` to see if the
 /// value was found, except for unit - see `fn query_storage_value` in
@@ -663,10 +697,15 @@ where
 
 #[cfg(test)]
 mod test {
-    use namada_core::address;
+    use namada_core::storage::{BlockHeight, Epoch};
+    use namada_core::{address, token};
+    use namada_storage::StorageWrite;
     use namada_token::storage_key::balance_key;
 
+    use crate::error::{Error, QueryError};
     use crate::queries::RPC;
+    use crate::queries::testing::TestClient;
+    use crate::rpc;
 
     #[test]
     fn test_shell_queries_router_paths() {
@@ -688,4 +727,65 @@ mod test {
         let path = RPC.shell().storage_has_key_path(&key);
         assert_eq!(format!("/shell/has_key/{}", key), path);
     }
+
+    #[tokio::test]
+    async fn test_token_balance_at_past_epoch() {
+        let mut client = TestClient::new(RPC);
+        let token = address::testing::nam();
+        let owner = address::testing::established_address_1();
+        let key = balance_key(&token, &owner);
+        let past_balance = token::Amount::native_whole(100);
+        let current_balance = token::Amount::native_whole(200);
+
+        // Epoch 0 starts at height 1
+        client.state.in_mem_mut().block.height = BlockHeight(1);
+        client
+            .state
+            .in_mem_mut()
+            .block
+            .pred_epochs
+            .new_epoch(BlockHeight(1));
+        client.state.write(&key, past_balance).unwrap();
+        client.state.commit_block().unwrap();
+
+        // Epoch 1 starts at height 2
+        client.state.in_mem_mut().block.height = BlockHeight(2);
+        client.state.in_mem_mut().block.epoch = Epoch(1);
+        client
+            .state
+            .in_mem_mut()
+            .block
+            .pred_epochs
+            .new_epoch(BlockHeight(2));
+        client.state.write(&key, current_balance).unwrap();
+        client.state.commit_block().unwrap();
+
+        let height = rpc::query_last_block_height_of_epoch(&client, Epoch(0))
+            .await
+            .unwrap();
+        assert_eq!(height, BlockHeight(1));
+        let balance =
+            rpc::get_token_balance_at_height(&client, &token, &owner, height)
+                .await
+                .unwrap();
+        assert_eq!(balance, past_balance);
+
+        let height = rpc::query_last_block_height_of_epoch(&client, Epoch(1))
+            .await
+            .unwrap();
+        assert_eq!(height, BlockHeight(2));
+        let latest = rpc::get_token_balance(&client, &token, &owner)
+            .await
+            .unwrap();
+        assert_eq!(latest, current_balance);
+        assert_ne!(balance, latest);
+
+        let err = rpc::query_last_block_height_of_epoch(&client, Epoch(2))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Query(QueryError::FutureEpoch(Epoch(2), Epoch(1)))
+        ));
+    }
 }
@@ -23,7 +23,9 @@ use namada_ethereum_bridge::protocol::transactions::votes::{
     EpochedVotingPower, EpochedVotingPowerExt,
 };
 use namada_ethereum_bridge::storage::bridge_pool::get_key_from_hash;
-use namada_ethereum_bridge::storage::eth_bridge_queries::EthBridgeQueries;
+use namada_ethereum_bridge::storage::eth_bridge_queries::{
+    self, EthBridgeQueries,
+};
 use namada_ethereum_bridge::storage::parameters::UpgradeableContract;
 use namada_ethereum_bridge::storage::proof::{sort_sigs, EthereumProof};
 use namada_ethereum_bridge::storage::vote_tallies::{eth_msgs_prefix, Keys};
@@ -188,6 +190,13 @@ router! {ETH_BRIDGE,
     ( "pool" / "transfer_status" )
         -> TransferToEthereumStatus = (with_options pending_eth_transfer_status),
 
+    // Read the Ethereum events which have not yet been seen by
+    // more than 2/3 of the voting power, along with the voting
+    // power backing them and the validators that voted on them.
+    ( "eth_events" / "pending" )
+        -> Vec<(EthereumEvent, FractionalVotingPower, Vec<Address>)>
+        = read_pending_eth_msgs,
+
     // Request a proof of a validator set signed off for
     // the given epoch.
     //
@@ -682,6 +691,20 @@ where
     Ok(pending_events)
 }
 
+/// Read the Ethereum events which have not yet been seen by
+/// more than 2/3 of the voting power.
+fn read_pending_eth_msgs<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<
+    Vec<(EthereumEvent, FractionalVotingPower, Vec<Address>)>,
+>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    eth_bridge_queries::read_pending_eth_msgs(ctx.state)
+}
+
 /// Read a validator set update proof from storage.
 ///
 /// This method may fail if a complete proof (i.e. with more than
@@ -1492,6 +1515,68 @@ mod test_ethbridge_router {
         assert_eq!(expected, resp);
     }
 
+    /// Test that only the Ethereum events which have not been
+    /// seen yet are returned as pending.
+    #[tokio::test]
+    async fn test_read_pending_eth_msgs() {
+        let mut client = TestClient::new(RPC);
+        let (validator, dummy_validator_stake) =
+            test_utils::default_validator();
+        test_utils::init_default_storage(&mut client.state);
+
+        let pending_event = EthereumEvent::TransfersToNamada {
+            nonce: 0.into(),
+            transfers: vec![],
+        };
+        let seen_event = EthereumEvent::TransfersToNamada {
+            nonce: 1.into(),
+            transfers: vec![],
+        };
+        let voting_power = FractionalVotingPower::HALF;
+        for (event, seen, voting_power) in [
+            (&pending_event, false, voting_power),
+            (&seen_event, true, FractionalVotingPower::WHOLE),
+        ] {
+            let keys = vote_tallies::Keys::from(event);
+            client
+                .state
+                .write(&keys.body(), event)
+                .expect("Test failed");
+            client.state.write(&keys.seen(), seen).expect("Test failed");
+            client
+                .state
+                .write(
+                    &keys.seen_by(),
+                    BTreeMap::from([(validator.clone(), BlockHeight(1))]),
+                )
+                .expect("Test failed");
+            client
+                .state
+                .write(
+                    &keys.voting_power(),
+                    EpochedVotingPower::from([(
+                        0.into(),
+                        voting_power * dummy_validator_stake,
+                    )]),
+                )
+                .expect("Test failed");
+        }
+        // commit the changes and increase block height
+        client
+            .state
+            .commit_block_from_batch(MockDBWriteBatch)
+            .expect("Test failed");
+        client.state.in_mem_mut().block.height += 1;
+
+        let resp = RPC
+            .shell()
+            .eth_bridge()
+            .read_pending_eth_msgs(&client)
+            .await
+            .unwrap();
+        assert_eq!(resp, vec![(pending_event, voting_power, vec![validator])]);
+    }
+
     /// Test if the a transfer has been removed from the
     /// pool (either because it was transferred or timed out),
     /// a proof is not generated for it, even if it was
@@ -729,7 +729,7 @@ pub mod client_only_methods {
 }
 
 /// Calculate extra information from the bonds and unbonds details.
-fn enrich_bonds_and_unbonds(
+pub fn enrich_bonds_and_unbonds(
     current_epoch: Epoch,
     bonds_and_unbonds: BondsAndUnbondsDetails,
 ) -> Result<EnrichedBondsAndUnbondsDetails, arith::Error> {
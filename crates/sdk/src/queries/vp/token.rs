@@ -47,6 +47,7 @@ where
 pub mod client_only_methods {
     use borsh::BorshDeserialize;
     use namada_core::address::Address;
+    use namada_core::storage::BlockHeight;
     use namada_core::token;
     use namada_token::storage_key::{balance_key, masp_total_rewards};
 
@@ -79,6 +80,33 @@ pub mod client_only_methods {
             Ok(balance)
         }
 
+        /// Get the balance of the given `token` belonging to the given `owner`
+        /// as it was at the given block `height`.
+        pub async fn balance_at_height<CLIENT>(
+            &self,
+            client: &CLIENT,
+            token: &Address,
+            owner: &Address,
+            height: BlockHeight,
+        ) -> Result<token::Amount, <CLIENT as Client>::Error>
+        where
+            CLIENT: Client + Sync,
+        {
+            let balance_key = balance_key(token, owner);
+            let response = RPC
+                .shell()
+                .storage_value(client, None, Some(height), false, &balance_key)
+                .await?;
+
+            let balance = if response.data.is_empty() {
+                token::Amount::zero()
+            } else {
+                token::Amount::try_from_slice(&response.data)
+                    .unwrap_or_default()
+            };
+            Ok(balance)
+        }
+
         /// Get the total rewards minted by MASP.
         pub async fn masp_total_rewards<CLIENT>(
             &self,
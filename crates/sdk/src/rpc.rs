@@ -11,13 +11,14 @@ use masp_primitives::sapling::Node;
 use namada_account::Account;
 use namada_core::address::{Address, InternalAddress};
 use namada_core::arith::checked;
+use namada_core::chain::ChainId;
 use namada_core::collections::{HashMap, HashSet};
 use namada_core::hash::Hash;
 use namada_core::ibc::IbcTokenHash;
 use namada_core::key::common;
 use namada_core::masp::MaspEpoch;
 use namada_core::storage::{
-    BlockHeight, BlockResults, Epoch, Key, PrefixValue,
+    BlockHeight, BlockResults, Epoch, Header, Key, PrefixValue,
 };
 use namada_core::time::DurationSecs;
 use namada_core::token::{
@@ -42,7 +43,9 @@ use namada_proof_of_stake::types::{
     BondsAndUnbondsDetails, CommissionPair, ValidatorMetaData,
 };
 use namada_state::LastBlock;
-use namada_tx::data::{BatchedTxResult, DryRunResult, ResultCode, TxResult};
+use namada_tx::data::{
+    BatchedTxResult, DryRunResult, GasLimit, ResultCode, TxResult,
+};
 use namada_tx::event::{Batch as BatchAttr, Code as CodeAttr};
 use serde::Serialize;
 
@@ -162,6 +165,13 @@ pub async fn query_native_token<C: crate::queries::Client + Sync>(
     convert_response::<C, _>(RPC.shell().native_token(client).await)
 }
 
+/// Query the ID of the chain of the node.
+pub async fn query_chain_id<C: crate::queries::Client + Sync>(
+    client: &C,
+) -> Result<ChainId, error::Error> {
+    convert_response::<C, _>(RPC.shell().chain_id(client).await)
+}
+
 /// Query the epoch of the given block height, if it exists.
 /// Will return none if the input block height is greater than
 /// the latest committed block height.
@@ -181,6 +191,14 @@ pub async fn query_block<C: crate::queries::Client + Sync>(
     convert_response::<C, _>(RPC.shell().last_block(client).await)
 }
 
+/// Query the header of the block at the given height
+pub async fn query_block_header<C: crate::queries::Client + Sync>(
+    client: &C,
+    height: BlockHeight,
+) -> Result<Option<Header>, error::Error> {
+    convert_response::<C, _>(RPC.shell().block_header(client, &height).await)
+}
+
 /// A helper to unwrap client's response. Will shut down process on error.
 fn unwrap_client_response<C: crate::queries::Client, T>(
     response: Result<T, C::Error>,
@@ -206,6 +224,55 @@ pub async fn query_results<C: crate::queries::Client + Sync>(
     convert_response::<C, _>(RPC.shell().read_results(client).await)
 }
 
+/// Query the last block height of the given epoch, or of the last committed
+/// block if the epoch is the current one.
+pub async fn query_last_block_height_of_epoch<
+    C: crate::queries::Client + Sync,
+>(
+    client: &C,
+    epoch: Epoch,
+) -> Result<BlockHeight, error::Error> {
+    let current_epoch = query_epoch(client).await?;
+    if epoch > current_epoch {
+        return Err(Error::from(QueryError::FutureEpoch(epoch, current_epoch)));
+    }
+    if epoch == current_epoch {
+        return Ok(query_block(client)
+            .await?
+            .map(|block| block.height)
+            .unwrap_or_default());
+    }
+    let next_epoch_start = convert_response::<C, _>(
+        RPC.shell()
+            .first_block_height_of_epoch(client, &epoch.next())
+            .await,
+    )?
+    .ok_or_else(|| {
+        Error::from(QueryError::General(format!(
+            "Missing the first block height of epoch {}",
+            epoch.next()
+        )))
+    })?;
+    next_epoch_start.prev_height().ok_or_else(|| {
+        Error::from(QueryError::General(format!("Epoch {epoch} has no blocks")))
+    })
+}
+
+/// Query token amount of owner at the given block height.
+pub async fn get_token_balance_at_height<C: crate::queries::Client + Sync>(
+    client: &C,
+    token: &Address,
+    owner: &Address,
+    height: BlockHeight,
+) -> Result<token::Amount, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .token()
+            .balance_at_height(client, token, owner, height)
+            .await,
+    )
+}
+
 /// Query token amount of owner.
 pub async fn get_token_balance<C: crate::queries::Client + Sync>(
     client: &C,
@@ -545,10 +612,12 @@ pub async fn query_tx_events<C: crate::queries::Client + Sync>(
     }
 }
 
-/// Dry run a transaction
+/// Dry run a transaction, reporting the gas it consumed against the gas limit
+/// it would be submitted with
 pub async fn dry_run_tx<N: Namada>(
     context: &N,
     tx_bytes: Vec<u8>,
+    gas_limit: GasLimit,
 ) -> Result<DryRunResult, Error> {
     let (data, height, prove) = (Some(tx_bytes), None, false);
     let result = convert_response::<N::Client, _>(
@@ -557,7 +626,11 @@ pub async fn dry_run_tx<N: Namada>(
             .await,
     )?
     .data;
-    let result_str = format!("Transaction consumed {} gas", result.1);
+    let result_str = format!(
+        "Transaction consumed {} gas (gas limit: {})",
+        result.1,
+        u64::from(gas_limit)
+    );
 
     let mut cmt_result_str = String::new();
     for (inner_hash, cmt_result) in result.0.iter() {
@@ -911,6 +984,23 @@ pub async fn get_account_info<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Query the code hash of the validity predicate of an account, if any
+pub async fn get_vp_code_hash<C: crate::queries::Client + Sync>(
+    client: &C,
+    owner: &Address,
+) -> Result<Option<Hash>, error::Error> {
+    let key = storage::Key::validity_predicate(owner);
+    let (bytes, _proof) =
+        query_storage_value_bytes(client, &key, None, false).await?;
+    bytes
+        .map(|bytes| {
+            Hash::try_from_slice(&bytes).map_err(|err| {
+                Error::from(EncodingError::Decoding(err.to_string()))
+            })
+        })
+        .transpose()
+}
+
 /// Query if the public_key is revealed
 pub async fn is_public_key_revealed<C: crate::queries::Client + Sync>(
     client: &C,
@@ -1223,18 +1313,19 @@ pub async fn validate_amount<N: Namada>(
             }
         }
     }?;
-    if denom < input_amount.denom() && !force {
-        display_line!(
-            context.io(),
-            "The input amount contained a higher precision than allowed by \
-             {token}."
-        );
-        Err(Error::from(QueryError::General(format!(
-            "the input amount. It contained a higher precision than allowed \
-             by {token}"
-        ))))
-    } else {
-        input_amount.increase_precision(denom).map_err(|_err| {
+    input_amount.with_denomination(denom).map_err(|err| match err {
+        token::AmountParseError::PrecisionDecrease => {
+            display_line!(
+                context.io(),
+                "The input amount contained a higher precision than allowed \
+                 by {token}."
+            );
+            Error::from(QueryError::General(format!(
+                "the input amount. It contained a higher precision than \
+                 allowed by {token}"
+            )))
+        }
+        _ => {
             display_line!(
                 context.io(),
                 "The amount provided requires more the 256 bits to represent."
@@ -1244,8 +1335,8 @@ pub async fn validate_amount<N: Namada>(
                  represent"
                     .to_string(),
             ))
-        })
-    }
+        }
+    })
 }
 
 /// Wait for a first block and node to be synced.
@@ -1426,3 +1517,44 @@ pub async fn query_ibc_denom<N: Namada>(
 
     token.as_ref().to_string()
 }
+
+#[cfg(test)]
+mod test {
+    use assert_matches::assert_matches;
+    use namada_core::address::testing::{
+        established_address_1, established_address_2, established_address_3,
+    };
+    use namada_state::StorageWrite;
+
+    use super::*;
+    use crate::queries::testing::TestClient;
+
+    #[tokio::test]
+    async fn test_get_vp_code_hash() {
+        let mut client = TestClient::new(RPC);
+        let owner = established_address_1();
+        let vp_code_hash = Hash::sha256(b"vp_user.wasm");
+        client
+            .state
+            .write(&storage::Key::validity_predicate(&owner), vp_code_hash)
+            .unwrap();
+        // A value that can't be decoded as a hash
+        let invalid = established_address_2();
+        client
+            .state
+            .write_bytes(&storage::Key::validity_predicate(&invalid), [0; 3])
+            .unwrap();
+        client.state.commit_block().unwrap();
+
+        assert_eq!(
+            get_vp_code_hash(&client, &owner).await.unwrap(),
+            Some(vp_code_hash)
+        );
+        assert_matches!(
+            get_vp_code_hash(&client, &invalid).await,
+            Err(Error::Encode(EncodingError::Decoding(_)))
+        );
+        let unknown = established_address_3();
+        assert_eq!(get_vp_code_hash(&client, &unknown).await.unwrap(), None);
+    }
+}
@@ -1,6 +1,5 @@
 //! SDK functions to construct different types of transactions
 
-use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::fs::File;
 use std::path::{Path, PathBuf};
@@ -22,6 +21,7 @@ use masp_primitives::zip32::ExtendedFullViewingKey;
 use namada_account::{InitAccount, UpdateAccount};
 use namada_core::address::{Address, IBC, MASP};
 use namada_core::arith::checked;
+use namada_core::chain::ChainId;
 use namada_core::collections::HashSet;
 use namada_core::dec::Dec;
 use namada_core::hash::Hash;
@@ -43,6 +43,7 @@ use namada_core::masp::{
 use namada_core::storage;
 use namada_core::storage::Epoch;
 use namada_core::time::DateTimeUtc;
+use namada_gas::WholeGas;
 use namada_governance::cli::onchain::{
     DefaultProposal, OnChainProposal, PgfFundingProposal, PgfStewardProposal,
 };
@@ -64,7 +65,8 @@ use namada_token::DenominatedAmount;
 use namada_tx::data::pgf::UpdateStewardCommission;
 use namada_tx::data::pos::{BecomeValidator, ConsensusKeyChange};
 use namada_tx::data::{
-    compute_inner_tx_hash, pos, BatchedTxResult, DryRunResult, ResultCode,
+    compute_inner_tx_hash, pos, BatchedTxResult, DryRunResult, GasLimit,
+    ResultCode,
 };
 pub use namada_tx::{Authorization, *};
 use num_traits::Zero;
@@ -92,7 +94,7 @@ use crate::signing::{
 };
 use crate::tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use crate::tendermint_rpc::error::Error as RpcError;
-use crate::wallet::WalletIo;
+use crate::wallet::{InsertError, Wallet, WalletIo};
 use crate::{args, display_line, edisplay_line, Namada};
 
 /// Initialize account transaction WASM
@@ -148,6 +150,10 @@ pub const TX_REDELEGATE_WASM: &str = "tx_redelegate.wasm";
 /// and `/applied` ABCI query endpoints.
 const DEFAULT_NAMADA_EVENTS_MAX_WAIT_TIME_SECONDS: u64 = 60;
 
+/// The maximum length in bytes of a user given IBC packet memo. Longer memos
+/// are rejected by the transfer application of ibc-go counterparties.
+pub const MAX_IBC_MEMO_LENGTH: usize = 32768;
+
 /// Capture the result of running a transaction
 #[derive(Debug)]
 pub enum ProcessTxResponse {
@@ -188,28 +194,41 @@ impl ProcessTxResponse {
     }
 }
 
-/// Build and dump a transaction either to file or to screen
+/// Build and dump a transaction either to file or to screen. The tx is
+/// written to `args.dump_tx_path` if given, `-` standing for stdout, or else
+/// to a file named after its hash in `args.output_folder`.
 pub fn dump_tx<IO: Io>(io: &IO, args: &args::Tx, tx: Tx) {
-    let tx_id = tx.header_hash();
-    let serialized_tx = tx.serialize();
-    match args.output_folder.to_owned() {
-        Some(path) => {
-            let tx_filename = format!("{}.tx", tx_id);
-            let tx_path = path.join(tx_filename);
-            let out = File::create(&tx_path).unwrap();
-            serde_json::to_writer_pretty(out, &serialized_tx)
-                .expect("Should be able to write to file.");
-            display_line!(
-                io,
-                "Transaction serialized to {}.",
-                tx_path.to_string_lossy()
-            );
+    let tx_path = match (&args.dump_tx_path, &args.output_folder) {
+        (Some(path), _) if path.as_os_str() == "-" => {
+            write_dumped_tx(&mut std::io::stdout().lock(), &tx)
+                .expect("Should be able to write to stdout.");
+            return;
         }
-        None => {
+        (Some(path), _) => path.clone(),
+        (None, Some(folder)) => folder.join(format!("{}.tx", tx.header_hash())),
+        (None, None) => {
             display_line!(io, "Below the serialized transaction: \n");
-            display_line!(io, "{}", serialized_tx)
+            display_line!(io, "{}", tx.serialize());
+            return;
         }
-    }
+    };
+    let mut out = File::create(&tx_path).unwrap();
+    write_dumped_tx(&mut out, &tx).expect("Should be able to write to file.");
+    display_line!(
+        io,
+        "Transaction serialized to {}.",
+        tx_path.to_string_lossy()
+    );
+}
+
+/// Write a dumped transaction in the format read back by
+/// [`Tx::deserialize`]
+fn write_dumped_tx(
+    w: &mut impl std::io::Write,
+    tx: &Tx,
+) -> std::io::Result<()> {
+    serde_json::to_writer_pretty(&mut *w, &tx.serialize())?;
+    writeln!(w)
 }
 
 /// Prepare a transaction for signing and submission by adding a wrapper header
@@ -245,8 +264,36 @@ pub async fn process_tx(
     // println!("HTTP request body: {}", request_body);
 
     if args.dry_run || args.dry_run_wrapper {
-        expect_dry_broadcast(TxBroadcastData::DryRun(tx), context).await
+        let response = expect_dry_broadcast(
+            TxBroadcastData::DryRun(tx),
+            args.gas_limit,
+            context,
+        )
+        .await?;
+        if let ProcessTxResponse::DryRun(DryRunResult(_, gas_used)) = &response
+        {
+            if let Some(warning) = gas_limit_warning(*gas_used, args.gas_limit)
+            {
+                edisplay_line!(context.io(), "{warning}");
+            }
+        }
+        Ok(response)
     } else {
+        // NB: nodes that don't support the query can't be checked, but the tx
+        // may still be valid for them
+        match rpc::query_chain_id(context.client()).await {
+            Ok(node_chain_id) => check_chain_id(
+                &tx.header.chain_id,
+                &node_chain_id,
+                args.force,
+                context.io(),
+            )?,
+            Err(err) => edisplay_line!(
+                context.io(),
+                "Couldn't check the transaction's chain ID against the \
+                 node's: {err}"
+            ),
+        }
         // We use this to determine when the wrapper tx makes it on-chain
         let tx_hash = tx.header_hash().to_string();
         let cmts = tx.commitments().clone();
@@ -284,6 +331,33 @@ pub async fn process_tx(
     }
 }
 
+/// Check that a transaction targets the chain of the node it is about to be
+/// broadcast to, so that it doesn't get rejected only after broadcasting.
+/// Force only warns about a mismatch.
+fn check_chain_id(
+    tx_chain_id: &ChainId,
+    node_chain_id: &ChainId,
+    force: bool,
+    io: &impl Io,
+) -> Result<()> {
+    if tx_chain_id == node_chain_id {
+        return Ok(());
+    }
+    if force {
+        edisplay_line!(
+            io,
+            "The transaction's chain ID {tx_chain_id} doesn't match the chain \
+             ID {node_chain_id} of the node."
+        );
+        Ok(())
+    } else {
+        Err(Error::from(TxSubmitError::ChainIdMismatch(
+            tx_chain_id.clone(),
+            node_chain_id.clone(),
+        )))
+    }
+}
+
 /// Check if a reveal public key transaction is needed
 pub async fn is_reveal_pk_needed<C: crate::queries::Client + Sync>(
     client: &C,
@@ -462,13 +536,15 @@ pub fn display_batch_resp(context: &impl Namada, resp: &TxResponse) {
     );
 }
 
-/// Save accounts initialized from a tx into the wallet, if any.
+/// Save accounts initialized from a tx into the wallet, if any. Returns the
+/// aliases under which the accounts were saved.
 pub async fn save_initialized_accounts<N: Namada>(
     context: &N,
     args: &args::Tx,
     initialized_accounts: Vec<Address>,
-) {
+) -> Vec<String> {
     let len = initialized_accounts.len();
+    let mut saved_aliases = vec![];
     if len != 0 {
         // Store newly initialized account addresses in the wallet
         display_line!(
@@ -478,29 +554,36 @@ pub async fn save_initialized_accounts<N: Namada>(
             if len == 1 { "" } else { "s" }
         );
         // Store newly initialized account addresses in the wallet
-        for (ix, address) in initialized_accounts.iter().enumerate() {
-            let encoded = address.encode();
-            let alias: Cow<'_, str> = match &args.initialized_account_alias {
-                Some(initialized_account_alias) => {
-                    if len == 1 {
-                        // If there's only one account, use the
-                        // alias as is
-                        initialized_account_alias.into()
-                    } else {
-                        // If there're multiple accounts, use
-                        // the alias as prefix, followed by
-                        // index number
-                        format!("{}{}", initialized_account_alias, ix).into()
+        let added_aliases = match &args.initialized_account_alias {
+            Some(initialized_account_alias) => insert_initialized_accounts(
+                &mut *context.wallet_mut().await,
+                initialized_account_alias,
+                &initialized_accounts,
+                args.wallet_alias_force,
+            ),
+            None => {
+                let mut added_aliases: Vec<Option<String>> = vec![None; len];
+                for (added, address) in
+                    added_aliases.iter_mut().zip(&initialized_accounts)
+                {
+                    let alias = N::WalletUtils::read_alias(&address.encode());
+                    let mut wallet = context.wallet_mut().await;
+                    match wallet.store_mut().insert_address::<N::WalletUtils>(
+                        alias.into(),
+                        address.clone(),
+                        args.wallet_alias_force,
+                    ) {
+                        Ok(alias) => *added = alias.map(Into::into),
+                        Err(InsertError::InvalidAlias(_)) => {}
+                        // Leave the remaining accounts without an alias
+                        Err(InsertError::Aborted(_)) => break,
                     }
                 }
-                None => N::WalletUtils::read_alias(&encoded).into(),
-            };
-            let alias = alias.into_owned();
-            let added = context.wallet_mut().await.insert_address(
-                alias.clone(),
-                address.clone(),
-                args.wallet_alias_force,
-            );
+                added_aliases
+            }
+        };
+        for (address, added) in initialized_accounts.iter().zip(added_aliases) {
+            let encoded = address.encode();
             match added {
                 Some(new_alias) if new_alias != encoded => {
                     display_line!(
@@ -509,6 +592,7 @@ pub async fn save_initialized_accounts<N: Namada>(
                         new_alias,
                         encoded
                     );
+                    saved_aliases.push(new_alias);
                 }
                 _ => {
                     display_line!(
@@ -520,6 +604,54 @@ pub async fn save_initialized_accounts<N: Namada>(
             };
         }
     }
+    saved_aliases
+}
+
+/// Insert accounts initialized from a tx into the wallet. The first account
+/// is saved under the given alias and the following ones under the alias
+/// suffixed with their index, i.e. `alias`, `alias-1`, `alias-2`, etc.
+/// Returns the alias that each account was saved under, if any. If adding an
+/// account is aborted, none of the remaining accounts are added either.
+pub fn insert_initialized_accounts<U: WalletIo>(
+    wallet: &mut Wallet<U>,
+    alias: &str,
+    initialized_accounts: &[Address],
+    force_alias: bool,
+) -> Vec<Option<String>> {
+    let mut added_aliases = vec![None; initialized_accounts.len()];
+    for (ix, address) in initialized_accounts.iter().enumerate() {
+        let alias = if ix == 0 {
+            alias.to_string()
+        } else {
+            format!("{alias}-{ix}")
+        };
+        match wallet.store_mut().insert_address::<U>(
+            alias.into(),
+            address.clone(),
+            force_alias,
+        ) {
+            Ok(alias) => added_aliases[ix] = alias.map(Into::into),
+            Err(InsertError::InvalidAlias(_)) => {}
+            Err(InsertError::Aborted(_)) => break,
+        }
+    }
+    added_aliases
+}
+
+/// Check that a commission rate, or a maximum change of the commission rate
+/// per epoch, is within the allowed range of values between 0.0 and 1.0.
+pub fn is_valid_commission_rate(rate: &Dec) -> bool {
+    !rate.is_negative() && *rate <= Dec::one()
+}
+
+/// Check that changing the commission rate from `current_rate` to `new_rate`
+/// doesn't exceed the validator's maximum commission rate change per epoch.
+pub fn is_valid_commission_rate_change(
+    new_rate: &Dec,
+    current_rate: Dec,
+    max_commission_rate_change: Dec,
+) -> Result<bool> {
+    Ok(new_rate.abs_diff(current_rate)? <= max_commission_rate_change)
 }
 
 /// Submit validator commission rate change
@@ -610,11 +742,11 @@ pub async fn build_validator_commission_change(
 
     let validator = validator.clone();
     if rpc::is_validator(context.client(), &validator).await? {
-        if *rate < Dec::zero() || *rate > Dec::one() {
+        if !is_valid_commission_rate(rate) {
             edisplay_line!(
                 context.io(),
-                "Invalid new commission rate, received {}",
-                rate
+                "Invalid new commission rate {rate}, it must be between 0.0 \
+                 and 1.0."
             );
             return Err(Error::from(TxSubmitError::InvalidCommissionRate(
                 *rate,
@@ -637,26 +769,18 @@ pub async fn build_validator_commission_change(
 
         match (commission_rate, max_commission_change_per_epoch) {
             (Some(commission_rate), Some(max_commission_change_per_epoch)) => {
-                if rate.is_negative() || *rate > Dec::one() {
-                    edisplay_line!(
-                        context.io(),
-                        "New rate is outside of the allowed range of values \
-                         between 0.0 and 1.0."
-                    );
-                    if !tx_args.force {
-                        return Err(Error::from(
-                            TxSubmitError::InvalidCommissionRate(*rate),
-                        ));
-                    }
-                }
-                if rate.abs_diff(commission_rate)?
-                    > max_commission_change_per_epoch
-                {
+                if !is_valid_commission_rate_change(
+                    rate,
+                    commission_rate,
+                    max_commission_change_per_epoch,
+                )? {
                     edisplay_line!(
                         context.io(),
-                        "New rate is too large of a change with respect to \
-                         the predecessor epoch in which the rate will take \
-                         effect."
+                        "New rate {rate} is too large of a change with \
+                         respect to the rate {commission_rate} of the \
+                         predecessor epoch in which the rate will take \
+                         effect. The maximum change per epoch is \
+                         {max_commission_change_per_epoch}."
                     );
                     if !tx_args.force {
                         return Err(Error::from(
@@ -831,11 +955,11 @@ pub async fn build_validator_metadata_change(
 
     // If there's a new commission rate, it must be valid
     if let Some(rate) = commission_rate.as_ref() {
-        if *rate < Dec::zero() || *rate > Dec::one() {
+        if !is_valid_commission_rate(rate) {
             edisplay_line!(
                 context.io(),
-                "Invalid new commission rate, received {}",
-                rate
+                "Invalid new commission rate {rate}, it must be between 0.0 \
+                 and 1.0."
             );
             if !tx_args.force {
                 return Err(Error::from(TxSubmitError::InvalidCommissionRate(
@@ -859,26 +983,18 @@ pub async fn build_validator_metadata_change(
 
         match (commission_rate, max_commission_change_per_epoch) {
             (Some(commission_rate), Some(max_commission_change_per_epoch)) => {
-                if rate.is_negative() || *rate > Dec::one() {
+                if !is_valid_commission_rate_change(
+                    rate,
+                    commission_rate,
+                    max_commission_change_per_epoch,
+                )? {
                     edisplay_line!(
                         context.io(),
-                        "New rate is outside of the allowed range of values \
-                         between 0.0 and 1.0."
-                    );
-                    if !tx_args.force {
-                        return Err(Error::from(
-                            TxSubmitError::InvalidCommissionRate(*rate),
-                        ));
-                    }
-                }
-                if rate.abs_diff(commission_rate)?
-                    > max_commission_change_per_epoch
-                {
-                    edisplay_line!(
-                        context.io(),
-                        "New rate is too large of a change with respect to \
-                         the predecessor epoch in which the rate will take \
-                         effect."
+                        "New rate {rate} is too large of a change with \
+                         respect to the rate {commission_rate} of the \
+                         predecessor epoch in which the rate will take \
+                         effect. The maximum change per epoch is \
+                         {max_commission_change_per_epoch}."
                     );
                     if !tx_args.force {
                         return Err(Error::from(
@@ -2246,7 +2362,7 @@ pub async fn build_become_validator(
     }
 
     // Validate the commission rate data
-    if *commission_rate > Dec::one() || *commission_rate < Dec::zero() {
+    if !is_valid_commission_rate(commission_rate) {
         edisplay_line!(
             context.io(),
             "The validator commission rate must not exceed 1.0 or 100%, and \
@@ -2259,9 +2375,7 @@ pub async fn build_become_validator(
         }
     }
 
-    if *max_commission_rate_change > Dec::one()
-        || *max_commission_rate_change < Dec::zero()
-    {
+    if !is_valid_commission_rate(max_commission_rate_change) {
         edisplay_line!(
             context.io(),
             "The validator maximum change in commission rate per epoch must \
@@ -2468,6 +2582,9 @@ pub async fn build_ibc_transfer(
                 .to_string(),
         ));
     }
+    if let Some(memo) = &args.ibc_memo {
+        validate_ibc_memo(memo)?;
+    }
 
     let refund_target =
         get_refund_target(context, &args.source, &args.refund_target).await?;
@@ -2887,16 +3004,18 @@ pub async fn build_transparent_transfer<N: Namada>(
 
     // Evaluate signer and fees
     let (signing_data, fee_amount, updated_balance) = {
-        let source = if args.data.len() == 1 {
-            // If only one transfer take its source as the signer
-            args.data
-                .first()
-                .map(|transfer_data| transfer_data.source.clone())
-        } else {
-            // Otherwise the caller is required to pass the public keys in the
-            // argument
-            None
-        };
+        // If all the transfers share a source take it as the signer,
+        // otherwise the caller is required to pass the public keys in the
+        // argument
+        let source = args
+            .data
+            .first()
+            .map(|transfer_data| transfer_data.source.clone())
+            .filter(|source| {
+                args.data
+                    .iter()
+                    .all(|transfer_data| &transfer_data.source == source)
+            });
 
         let signing_data = signing::aux_signing_data(
             context,
@@ -3774,13 +3893,32 @@ async fn get_ibc_src_port_channel(
         })
 }
 
+/// Check the gas consumed by a dry run against the gas limit that the
+/// transaction would be submitted with. Returns a warning if the limit is too
+/// low for the transaction to be applied.
+fn gas_limit_warning(
+    gas_used: WholeGas,
+    gas_limit: GasLimit,
+) -> Option<String> {
+    let (gas_used, gas_limit) = (u64::from(gas_used), u64::from(gas_limit));
+    (gas_used > gas_limit).then(|| {
+        format!(
+            "Warning: the gas limit of {gas_limit} is below the {gas_used} \
+             gas consumed by the dry run. Submitting the transaction with \
+             this limit would fail, consider raising it with --gas-limit."
+        )
+    })
+}
+
 async fn expect_dry_broadcast(
     to_broadcast: TxBroadcastData,
+    gas_limit: GasLimit,
     context: &impl Namada,
 ) -> Result<ProcessTxResponse> {
     match to_broadcast {
         TxBroadcastData::DryRun(tx) => {
-            let result = rpc::dry_run_tx(context, tx.to_bytes()).await?;
+            let result =
+                rpc::dry_run_tx(context, tx.to_bytes(), gas_limit).await?;
             Ok(ProcessTxResponse::DryRun(result))
         }
         TxBroadcastData::Live { tx, tx_hash: _ } => {
@@ -4030,7 +4168,205 @@ where
     Ok(())
 }
 
+/// Check that a user given IBC packet memo is not too long to be relayed
+fn validate_ibc_memo(memo: &str) -> Result<()> {
+    if memo.len() > MAX_IBC_MEMO_LENGTH {
+        return Err(Error::Other(format!(
+            "The IBC memo is {} bytes long, the maximum is {} bytes",
+            memo.len(),
+            MAX_IBC_MEMO_LENGTH
+        )));
+    }
+    Ok(())
+}
+
 fn proposal_to_vec(proposal: OnChainProposal) -> Result<Vec<u8>> {
     borsh::to_vec(&proposal.content)
         .map_err(|e| Error::from(EncodingError::Conversion(e.to_string())))
 }
+
+#[cfg(test)]
+mod test {
+    use assert_matches::assert_matches;
+    use namada_core::address::testing::{
+        established_address_1, established_address_2, established_address_3,
+    };
+    use namada_core::key::testing::{keypair_1, keypair_2};
+
+    use super::*;
+    use crate::io::StdIo;
+    use crate::wallet::alias::Alias;
+    use crate::wallet::fs::FsWalletUtils;
+    use crate::wallet::{ConfirmationResponse, Store};
+
+    #[test]
+    fn test_insert_initialized_accounts() {
+        let mut wallet = FsWalletUtils::new(PathBuf::new());
+        let accounts = [established_address_1(), established_address_2()];
+
+        let aliases = insert_initialized_accounts(
+            &mut wallet,
+            "multisig",
+            &accounts,
+            false,
+        );
+        assert_eq!(
+            aliases,
+            vec![Some("multisig".to_string()), Some("multisig-1".to_string())]
+        );
+        assert_eq!(
+            wallet.find_address("multisig").unwrap().as_ref(),
+            &accounts[0]
+        );
+        assert_eq!(
+            wallet.find_address("multisig-1").unwrap().as_ref(),
+            &accounts[1]
+        );
+    }
+
+    thread_local! {
+        static CONFIRMATION_PROMPTS: std::cell::Cell<usize> =
+            const { std::cell::Cell::new(0) };
+    }
+
+    /// Wallet utils that abort on any alias conflict
+    #[derive(Clone)]
+    struct AbortWalletUtils;
+
+    impl WalletIo for AbortWalletUtils {
+        type Rng = rand_core::OsRng;
+
+        fn show_overwrite_confirmation(
+            _alias: &Alias,
+            _alias_for: &str,
+        ) -> ConfirmationResponse {
+            CONFIRMATION_PROMPTS.with(|prompts| prompts.set(prompts.get() + 1));
+            ConfirmationResponse::Abort
+        }
+    }
+
+    #[test]
+    fn test_insert_initialized_accounts_abort() {
+        let mut wallet = Wallet::new(AbortWalletUtils, Store::default());
+        let accounts = [
+            established_address_1(),
+            established_address_2(),
+            established_address_3(),
+        ];
+        // Make the aliases of the last two accounts conflict
+        wallet.insert_address(
+            "multisig-1",
+            Address::from(&keypair_1().ref_to()),
+            false,
+        );
+        wallet.insert_address(
+            "multisig-2",
+            Address::from(&keypair_2().ref_to()),
+            false,
+        );
+
+        let aliases = insert_initialized_accounts(
+            &mut wallet,
+            "multisig",
+            &accounts,
+            false,
+        );
+        // The insertions stop at the first conflict
+        assert_eq!(aliases, vec![Some("multisig".to_string()), None, None]);
+        assert_eq!(CONFIRMATION_PROMPTS.with(|prompts| prompts.get()), 1);
+        assert!(wallet.find_address("multisig").is_some());
+        assert!(wallet.find_address("multisig-2").is_some());
+        assert!(wallet.find_alias(&accounts[1]).is_none());
+        assert!(wallet.find_alias(&accounts[2]).is_none());
+    }
+
+    #[test]
+    fn test_check_chain_id() {
+        let chain_id = ChainId("namada-test.0123456789abcdef".to_string());
+        check_chain_id(&chain_id, &chain_id, false, &StdIo)
+            .expect("Matching chain IDs should pass");
+
+        let other_chain_id =
+            ChainId("namada-other.0123456789abcdef".to_string());
+        assert_matches!(
+            check_chain_id(&chain_id, &other_chain_id, false, &StdIo),
+            Err(Error::Tx(TxSubmitError::ChainIdMismatch(
+                tx_chain_id,
+                node_chain_id,
+            ))) if tx_chain_id == chain_id && node_chain_id == other_chain_id
+        );
+        check_chain_id(&chain_id, &other_chain_id, true, &StdIo)
+            .expect("Forcing should only warn about mismatching chain IDs");
+    }
+
+    #[test]
+    fn test_gas_limit_warning() {
+        let gas_used = WholeGas::from(25_000);
+        assert!(gas_limit_warning(gas_used, GasLimit::from(50_000)).is_none());
+        assert!(gas_limit_warning(gas_used, GasLimit::from(25_000)).is_none());
+
+        let warning = gas_limit_warning(gas_used, GasLimit::from(20_000))
+            .expect("The gas limit is too low");
+        assert!(warning.contains("gas limit of 20000"));
+        assert!(warning.contains("25000 gas consumed"));
+    }
+
+    #[test]
+    fn test_validate_ibc_memo() {
+        assert!(validate_ibc_memo("").is_ok());
+        assert!(validate_ibc_memo("deposit-id:1234").is_ok());
+        assert!(validate_ibc_memo(&"a".repeat(MAX_IBC_MEMO_LENGTH)).is_ok());
+
+        let err = validate_ibc_memo(&"a".repeat(MAX_IBC_MEMO_LENGTH + 1))
+            .unwrap_err();
+        assert!(err.to_string().contains("the maximum is 32768 bytes"));
+        // The length is counted in bytes, not characters
+        assert!(
+            validate_ibc_memo(&"é".repeat(MAX_IBC_MEMO_LENGTH / 2 + 1))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_write_dumped_tx() {
+        let mut tx = Tx::new(ChainId::default(), None);
+        tx.add_serialized_data(vec![1, 2, 3]);
+
+        let mut out = vec![];
+        write_dumped_tx(&mut out, &tx).unwrap();
+        // Only the tx is written, without any header
+        assert_eq!(
+            String::from_utf8(out.clone()).unwrap(),
+            format!("\"{}\"\n", tx.serialize())
+        );
+        let dumped = Tx::deserialize(&out).unwrap();
+        assert_eq!(dumped.to_bytes(), tx.to_bytes());
+    }
+
+    #[test]
+    fn test_commission_rate_bounds() {
+        assert!(is_valid_commission_rate(&Dec::zero()));
+        assert!(is_valid_commission_rate(&Dec::new(5, 2).unwrap()));
+        assert!(is_valid_commission_rate(&Dec::one()));
+        // A negative rate
+        assert!(!is_valid_commission_rate(&Dec::new(-1, 2).unwrap()));
+        // A rate above 1
+        assert!(!is_valid_commission_rate(&Dec::new(101, 2).unwrap()));
+    }
+
+    #[test]
+    fn test_commission_rate_change() {
+        let current = Dec::new(5, 2).unwrap();
+        let max_change = Dec::new(1, 2).unwrap();
+        let is_valid = |new_rate: Dec| {
+            is_valid_commission_rate_change(&new_rate, current, max_change)
+                .unwrap()
+        };
+        assert!(is_valid(current));
+        assert!(is_valid(Dec::new(4, 2).unwrap()));
+        assert!(is_valid(Dec::new(6, 2).unwrap()));
+        // A change exceeding the max in either direction
+        assert!(!is_valid(Dec::new(3, 2).unwrap()));
+        assert!(!is_valid(Dec::new(7, 2).unwrap()));
+    }
+}
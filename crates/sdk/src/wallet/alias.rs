@@ -20,6 +20,18 @@ use serde::{Deserialize, Serialize};
 pub struct Alias(String);
 
 impl Alias {
+    /// Create an alias from the given string, if it is valid
+    pub fn try_new(raw: impl AsRef<str>) -> Option<Self> {
+        let alias = Self::from(raw);
+        alias.is_valid().then_some(alias)
+    }
+
+    /// Check that the alias is neither empty nor only made of whitespace,
+    /// and that it doesn't contain any non-printable characters
+    pub fn is_valid(&self) -> bool {
+        !self.0.trim().is_empty() && !self.0.chars().any(char::is_control)
+    }
+
     /// Normalize an alias to lower-case
     pub fn normalize(&self) -> String {
         self.0.to_lowercase()
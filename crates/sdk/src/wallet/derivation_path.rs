@@ -1,7 +1,10 @@
 use core::fmt;
 use std::str::FromStr;
 
-use derivation_path::{ChildIndex, DerivationPath as DerivationPathInner};
+use derivation_path::{
+    ChildIndex, ChildIndexError, ChildIndexParseError,
+    DerivationPath as DerivationPathInner, DerivationPathParseError,
+};
 use masp_primitives::zip32;
 use namada_core::key::SchemeType;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -21,8 +24,108 @@ const NAMADA_COIN_TYPE: u32 = 877;
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
 pub enum DerivationPathError {
-    #[error("invalid derivation path: {0}")]
-    InvalidDerivationPath(String),
+    #[error(
+        "invalid derivation path: the path is empty, expected a path such as \
+         m/44'/877'/0'/0'/0'"
+    )]
+    Empty,
+    #[error(
+        "invalid derivation path: the path must start with `m`, found `{0}`"
+    )]
+    InvalidPrefix(String),
+    #[error(
+        "invalid derivation path: `{segment}` at position {position} is not a \
+         valid child index, expected a number optionally followed by `'` for \
+         a hardened index"
+    )]
+    InvalidChildIndex { position: usize, segment: String },
+    #[error(
+        "invalid derivation path: child index {index} at position {position} \
+         is too large, the maximum is 2147483647"
+    )]
+    ChildIndexTooLarge { position: usize, index: u32 },
+    #[error("invalid derivation path: {path} is not compliant: {reason}")]
+    NonCompliant { path: String, reason: Compatibility },
+}
+
+impl DerivationPathError {
+    /// Convert an error from parsing the given path into a user-facing
+    /// error that points at the offending part of the path
+    fn from_parse_error(path: &str, err: DerivationPathParseError) -> Self {
+        match err {
+            DerivationPathParseError::Empty => Self::Empty,
+            DerivationPathParseError::InvalidPrefix(prefix) => {
+                Self::InvalidPrefix(prefix)
+            }
+            DerivationPathParseError::InvalidChildIndex(err) => {
+                // The 1-based position of the first index that fails to parse
+                let (position, segment) = path
+                    .split('/')
+                    .skip(1)
+                    .enumerate()
+                    .find(|(_, segment)| ChildIndex::from_str(segment).is_err())
+                    .map(|(ix, segment)| (ix + 1, segment.to_string()))
+                    .unwrap_or_default();
+                match err {
+                    ChildIndexParseError::ParseIntError(_) => {
+                        Self::InvalidChildIndex { position, segment }
+                    }
+                    ChildIndexParseError::ChildIndexError(
+                        ChildIndexError::NumberTooLarge(index),
+                    ) => Self::ChildIndexTooLarge { position, index },
+                }
+            }
+        }
+    }
+}
+
+/// The compatibility of a derivation path with one of Namada's key schemes,
+/// giving the first reason why it is not compliant, if any
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compatibility {
+    /// The path is compliant
+    Ok,
+    /// The path does not have the expected number of levels
+    WrongDepth,
+    /// The purpose level does not match the scheme
+    WrongPurpose,
+    /// The coin type level does not match the scheme
+    WrongCoinType,
+    /// Some levels are hardened (or not) against the scheme's rules
+    WrongHardening,
+    /// The scheme has no derivation path rules
+    UnsupportedScheme,
+}
+
+impl Compatibility {
+    /// Check if the path is compliant
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Self::Ok)
+    }
+}
+
+impl fmt::Display for Compatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ok => write!(f, "the path is compliant"),
+            Self::WrongDepth => {
+                write!(f, "the path has an unexpected number of levels")
+            }
+            Self::WrongPurpose => {
+                write!(f, "the purpose does not match the key scheme")
+            }
+            Self::WrongCoinType => {
+                write!(f, "the coin type does not match the key scheme")
+            }
+            Self::WrongHardening => write!(
+                f,
+                "the hardened levels do not match the rules of the key scheme"
+            ),
+            Self::UnsupportedScheme => {
+                write!(f, "the key scheme does not support derivation paths")
+            }
+        }
+    }
 }
 
 /// A key derivation path
@@ -151,6 +254,90 @@ impl DerivationPath {
         self.is_zip32_conform() && self.has_shielded_compatible_coin_type()
     }
 
+    /// Check the compatibility of the path with Namada's transparent scheme
+    /// and give the reason if it is not compliant
+    pub fn compatibility(&self, scheme: SchemeType) -> Compatibility {
+        let path = self.0.as_ref();
+        let hardening_ok = match scheme {
+            // all indices must be hardened
+            SchemeType::Ed25519 => path.iter().all(|idx| idx.is_hardened()),
+            // m / purpose' / coin_type' / account' / change / address_index
+            SchemeType::Secp256k1 => path.iter().enumerate().all(|(i, idx)| {
+                if i < 3 {
+                    idx.is_hardened()
+                } else {
+                    idx.is_normal()
+                }
+            }),
+            SchemeType::Common => return Compatibility::UnsupportedScheme,
+        };
+        if path.len() != 5 {
+            Compatibility::WrongDepth
+        } else if path[0].to_u32() != BIP44_PURPOSE {
+            Compatibility::WrongPurpose
+        } else if !self.has_transparent_compatible_coin_type(scheme) {
+            Compatibility::WrongCoinType
+        } else if !hardening_ok {
+            Compatibility::WrongHardening
+        } else {
+            Compatibility::Ok
+        }
+    }
+
+    /// Check the compatibility of the path with Namada's shielded scheme and
+    /// give the reason if it is not compliant
+    pub fn shielded_compatibility(&self) -> Compatibility {
+        let path = self.0.as_ref();
+        // m / purpose' / coin_type' / account' [ / address_index ]
+        let hardening_ok = path.iter().enumerate().all(|(i, idx)| {
+            if i < 3 {
+                idx.is_hardened()
+            } else {
+                idx.is_normal()
+            }
+        });
+        if path.len() != 3 && path.len() != 4 {
+            Compatibility::WrongDepth
+        } else if path[0].to_u32() != ZIP32_PURPOSE {
+            Compatibility::WrongPurpose
+        } else if !self.has_shielded_compatible_coin_type() {
+            Compatibility::WrongCoinType
+        } else if !hardening_ok {
+            Compatibility::WrongHardening
+        } else {
+            Compatibility::Ok
+        }
+    }
+
+    /// Check that the path is compliant with Namada's transparent scheme,
+    /// failing with the reason if it is not
+    pub fn validate_for_scheme(
+        &self,
+        scheme: SchemeType,
+    ) -> Result<(), DerivationPathError> {
+        self.validate(self.compatibility(scheme))
+    }
+
+    /// Check that the path is compliant with Namada's shielded scheme,
+    /// failing with the reason if it is not
+    pub fn validate_for_shielded(&self) -> Result<(), DerivationPathError> {
+        self.validate(self.shielded_compatibility())
+    }
+
+    fn validate(
+        &self,
+        reason: Compatibility,
+    ) -> Result<(), DerivationPathError> {
+        if reason.is_ok() {
+            Ok(())
+        } else {
+            Err(DerivationPathError::NonCompliant {
+                path: self.to_string(),
+                reason,
+            })
+        }
+    }
+
     fn bip44_base_indexes_for_scheme(scheme: SchemeType) -> Vec<ChildIndex> {
         vec![
             ChildIndex::Hardened(BIP44_PURPOSE),
@@ -214,9 +401,8 @@ impl DerivationPath {
 
     /// Try to parse a derivation path from a string
     pub fn from_path_string(path: &str) -> Result<Self, DerivationPathError> {
-        let inner = DerivationPathInner::from_str(path).map_err(|err| {
-            DerivationPathError::InvalidDerivationPath(err.to_string())
-        })?;
+        let inner = DerivationPathInner::from_str(path)
+            .map_err(|err| DerivationPathError::from_parse_error(path, err))?;
         Ok(Self(inner))
     }
 
@@ -232,6 +418,35 @@ impl DerivationPath {
     pub fn path(&self) -> &[ChildIndex] {
         self.0.path()
     }
+
+    /// Get the same path with its last index replaced by `index`. The new
+    /// index is hardened if and only if the replaced one was.
+    pub fn with_last_index(
+        &self,
+        index: u32,
+    ) -> Result<Self, DerivationPathError> {
+        let mut path = self.path().to_vec();
+        let position = path.len();
+        let last = path.last_mut().ok_or(DerivationPathError::Empty)?;
+        *last = if last.is_hardened() {
+            ChildIndex::hardened(index)
+        } else {
+            ChildIndex::normal(index)
+        }
+        .map_err(|_| DerivationPathError::ChildIndexTooLarge {
+            position,
+            index,
+        })?;
+        Ok(Self::new(path))
+    }
+
+    /// Get the path that follows this one, e.g. to derive the next address
+    /// from the same mnemonic. The last index is incremented.
+    pub fn next(&self) -> Result<Self, DerivationPathError> {
+        let last = self.path().last().ok_or(DerivationPathError::Empty)?;
+        // A child index is at most 2^31 - 1, so this cannot overflow
+        self.with_last_index(last.to_u32().saturating_add(1))
+    }
 }
 
 impl fmt::Display for DerivationPath {
@@ -244,9 +459,7 @@ impl FromStr for DerivationPath {
     type Err = DerivationPathError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        DerivationPathInner::from_str(s).map(Self).map_err(|err| {
-            DerivationPathError::InvalidDerivationPath(err.to_string())
-        })
+        Self::from_path_string(s)
     }
 }
 
@@ -285,7 +498,7 @@ impl From<DerivationPath> for Vec<zip32::ChildIndex> {
 mod tests {
     use namada_core::key::SchemeType;
 
-    use super::DerivationPath;
+    use super::{Compatibility, DerivationPath, DerivationPathError};
 
     #[test]
     fn path_conformity() {
@@ -397,4 +610,236 @@ mod tests {
         );
         assert!(path_z_2.is_namada_shielded_compliant());
     }
+
+    #[test]
+    fn path_parse_errors() {
+        let err = DerivationPath::from_path_string("").unwrap_err();
+        assert!(matches!(err, DerivationPathError::Empty));
+        let empty_msg = err.to_string();
+
+        let err = DerivationPath::from_path_string("n/44'/877'").unwrap_err();
+        assert!(
+            matches!(&err, DerivationPathError::InvalidPrefix(p) if p == "n")
+        );
+        let prefix_msg = err.to_string();
+        assert!(prefix_msg.contains("`n`"));
+
+        let err =
+            DerivationPath::from_path_string("m/44'/877'/0x/0'").unwrap_err();
+        assert!(matches!(
+            &err,
+            DerivationPathError::InvalidChildIndex { position: 3, segment }
+                if segment == "0x"
+        ));
+        let index_msg = err.to_string();
+        assert!(index_msg.contains("`0x` at position 3"));
+
+        // A typographic apostrophe is not a hardened index marker
+        let err =
+            DerivationPath::from_path_string("m/44\u{2019}/877'").unwrap_err();
+        assert!(matches!(
+            &err,
+            DerivationPathError::InvalidChildIndex { position: 1, .. }
+        ));
+        let apostrophe_msg = err.to_string();
+
+        let err = DerivationPath::from_path_string_for_transparent_scheme(
+            SchemeType::Ed25519,
+            "m/44'/877'/2147483648'",
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            DerivationPathError::ChildIndexTooLarge {
+                position: 3,
+                index: 2147483648
+            }
+        ));
+        let too_large_msg = err.to_string();
+
+        let msgs = [
+            &empty_msg,
+            &prefix_msg,
+            &index_msg,
+            &apostrophe_msg,
+            &too_large_msg,
+        ];
+        for (i, msg) in msgs.iter().enumerate() {
+            assert!(msg.starts_with("invalid derivation path: "));
+            for other in &msgs[i + 1..] {
+                assert_ne!(msg, other);
+            }
+        }
+    }
+
+    #[test]
+    fn path_compatibility() {
+        let cases = [
+            (
+                SchemeType::Ed25519,
+                "m/44'/877'/0'/0'/0'",
+                Compatibility::Ok,
+            ),
+            (
+                SchemeType::Ed25519,
+                "m/44'/877'/0'/0'",
+                Compatibility::WrongDepth,
+            ),
+            (
+                SchemeType::Ed25519,
+                "m/44'/877'/0'/0'/0'/0'",
+                Compatibility::WrongDepth,
+            ),
+            (
+                SchemeType::Ed25519,
+                "m/45'/877'/0'/0'/0'",
+                Compatibility::WrongPurpose,
+            ),
+            (
+                SchemeType::Ed25519,
+                "m/44'/60'/0'/0'/0'",
+                Compatibility::WrongCoinType,
+            ),
+            (
+                SchemeType::Ed25519,
+                "m/44'/877'/0'/0/0",
+                Compatibility::WrongHardening,
+            ),
+            (SchemeType::Secp256k1, "m/44'/60'/0'/0/0", Compatibility::Ok),
+            (
+                SchemeType::Secp256k1,
+                "m/44'/60'/0'",
+                Compatibility::WrongDepth,
+            ),
+            (
+                SchemeType::Secp256k1,
+                "m/44'/877'/0'/0/0",
+                Compatibility::WrongCoinType,
+            ),
+            (
+                SchemeType::Secp256k1,
+                "m/44'/60'/0'/0'/0'",
+                Compatibility::WrongHardening,
+            ),
+            (
+                SchemeType::Secp256k1,
+                "m/44'/60'/0/0/0",
+                Compatibility::WrongHardening,
+            ),
+            (
+                SchemeType::Common,
+                "m/44'/877'/0'/0'/0'",
+                Compatibility::UnsupportedScheme,
+            ),
+        ];
+        for (scheme, path, expected) in cases {
+            let path = DerivationPath::from_path_string(path).unwrap();
+            let compatibility = path.compatibility(scheme);
+            assert_eq!(compatibility, expected, "path {path}");
+            assert_eq!(
+                compatibility.is_ok(),
+                path.is_namada_transparent_compliant(scheme)
+            );
+        }
+
+        let cases = [
+            ("m/32'/877'/0'", Compatibility::Ok),
+            ("m/32'/877'/0'/0", Compatibility::Ok),
+            ("m/32'/877'", Compatibility::WrongDepth),
+            ("m/32'/877'/0'/0/0", Compatibility::WrongDepth),
+            ("m/44'/877'/0'", Compatibility::WrongPurpose),
+            ("m/32'/60'/0'", Compatibility::WrongCoinType),
+            ("m/32'/877'/0", Compatibility::WrongHardening),
+            ("m/32'/877'/0'/0'", Compatibility::WrongHardening),
+        ];
+        for (path, expected) in cases {
+            let path = DerivationPath::from_path_string(path).unwrap();
+            let compatibility = path.shielded_compatibility();
+            assert_eq!(compatibility, expected, "path {path}");
+            assert_eq!(
+                compatibility.is_ok(),
+                path.is_namada_shielded_compliant()
+            );
+        }
+    }
+
+    #[test]
+    fn path_next_index() {
+        // ed25519 paths are hardened at every level
+        let path =
+            DerivationPath::default_for_transparent_scheme(SchemeType::Ed25519);
+        assert_eq!(path.to_string(), "m/44'/877'/0'/0'/0'");
+        let next = path.next().unwrap();
+        assert_eq!(next.to_string(), "m/44'/877'/0'/0'/1'");
+        assert_eq!(next.next().unwrap().to_string(), "m/44'/877'/0'/0'/2'");
+        assert_eq!(
+            path.with_last_index(42).unwrap().to_string(),
+            "m/44'/877'/0'/0'/42'"
+        );
+        assert!(next.is_namada_transparent_compliant(SchemeType::Ed25519));
+
+        // secp256k1 paths keep their last index non-hardened
+        let path = DerivationPath::default_for_transparent_scheme(
+            SchemeType::Secp256k1,
+        );
+        assert_eq!(path.to_string(), "m/44'/60'/0'/0/0");
+        let next = path.next().unwrap();
+        assert_eq!(next.to_string(), "m/44'/60'/0'/0/1");
+        assert_eq!(
+            path.with_last_index(42).unwrap().to_string(),
+            "m/44'/60'/0'/0/42"
+        );
+        assert!(next.is_namada_transparent_compliant(SchemeType::Secp256k1));
+
+        // The last index cannot go past the maximum child index
+        let last = path.with_last_index(2147483647).unwrap();
+        assert!(matches!(
+            last.next(),
+            Err(DerivationPathError::ChildIndexTooLarge {
+                position: 5,
+                index: 2147483648,
+            })
+        ));
+        assert!(matches!(
+            DerivationPath::from_path_string("m").unwrap().next(),
+            Err(DerivationPathError::Empty)
+        ));
+    }
+
+    #[test]
+    fn path_validation() {
+        let path =
+            DerivationPath::from_path_string("m/44'/877'/0'/0'/0'").unwrap();
+        assert!(path.validate_for_scheme(SchemeType::Ed25519).is_ok());
+
+        let err = path.validate_for_scheme(SchemeType::Secp256k1).unwrap_err();
+        assert!(matches!(
+            err,
+            DerivationPathError::NonCompliant {
+                reason: Compatibility::WrongCoinType,
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "invalid derivation path: {path} is not compliant: {}",
+                Compatibility::WrongCoinType
+            )
+        );
+
+        let err = path.validate_for_shielded().unwrap_err();
+        assert!(matches!(
+            err,
+            DerivationPathError::NonCompliant {
+                reason: Compatibility::WrongDepth,
+                ..
+            }
+        ));
+        assert!(
+            DerivationPath::default_for_shielded()
+                .validate_for_shielded()
+                .is_ok()
+        );
+    }
 }
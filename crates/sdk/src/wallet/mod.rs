@@ -30,13 +30,21 @@ pub use store::{AddressVpType, Store};
 use thiserror::Error;
 use zeroize::Zeroizing;
 
-pub use self::derivation_path::{DerivationPath, DerivationPathError};
+pub use self::derivation_path::{
+    Compatibility, DerivationPath, DerivationPathError,
+};
 pub use self::keys::{DecryptionError, StoredKeypair};
-pub use self::store::{ConfirmationResponse, ValidatorData, ValidatorKeys};
+pub use self::store::{
+    ConfirmationResponse, InsertError, RenameResult, StoreInconsistency,
+    StoredMnemonic, ValidatorData, ValidatorKeys,
+};
 use crate::wallet::store::{derive_hd_secret_key, derive_hd_spending_key};
 
 const DISPOSABLE_KEY_LIFETIME_IN_SECONDS: i64 = 7 * 24 * 60 * 60; // 1 week
 
+/// The default number of words of a generated BIP39 mnemonic code
+pub const DEFAULT_MNEMONIC_WORDS: usize = 24;
+
 /// Captures the interactive parts of the wallet's functioning
 pub trait WalletIo: Sized + Clone {
     /// Secure random number generator
@@ -239,6 +247,23 @@ fn gen_spending_key(
         .into()
 }
 
+/// The error that is produced when a mnemonic code with an invalid number of
+/// words is requested
+#[derive(Error, Debug)]
+#[error(
+    "Invalid number of mnemonic words {0}, expected one of 12, 15, 18, 21 or \
+     24"
+)]
+pub struct InvalidMnemonicWords(pub usize);
+
+/// Get the BIP39 mnemonic type of a mnemonic code with the given number of
+/// words.
+pub fn mnemonic_type_for_word_count(
+    words: usize,
+) -> Result<MnemonicType, InvalidMnemonicWords> {
+    MnemonicType::for_word_count(words).map_err(|_| InvalidMnemonicWords(words))
+}
+
 /// The error that is produced when a given key cannot be obtained
 #[derive(Error, Debug)]
 pub enum FindKeyError {
@@ -248,6 +273,17 @@ pub enum FindKeyError {
     /// Could not decrypt a given key in the wallet
     #[error("{0}")]
     KeyDecryptionError(keys::DecryptionError),
+    /// Could not get the derivation path of a key to derive
+    #[error("{0}")]
+    DerivationPathError(DerivationPathError),
+    /// A key cannot be of the given scheme in its role
+    #[error("The {role} key cannot use the {scheme:?} scheme")]
+    UnsupportedScheme {
+        /// The role of the key
+        role: &'static str,
+        /// The unsupported scheme
+        scheme: SchemeType,
+    },
 }
 
 /// Represents a collection of keys and addresses while caching key decryptions
@@ -599,6 +635,34 @@ impl<U: WalletIo> Wallet<U> {
         prompt_bip39_passphrase: bool,
         password: Option<Zeroizing<String>>,
     ) -> Option<(String, common::SecretKey)> {
+        let sk = Self::derive_key_from_mnemonic_code(
+            scheme,
+            derivation_path.clone(),
+            mnemonic_passphrase,
+            prompt_bip39_passphrase,
+        )?;
+
+        self.insert_keypair(
+            alias.unwrap_or_default(),
+            alias_force,
+            sk.clone(),
+            password,
+            None,
+            Some(derivation_path),
+        )
+        .map(|alias| (alias, sk))
+    }
+
+    /// Derive a keypair from the user mnemonic code (read from stdin unless
+    /// provided) using a given BIP44 derivation path, without inserting it
+    /// into the store. Useful to check which key and implicit address a
+    /// mnemonic code corresponds to before committing to it.
+    pub fn derive_key_from_mnemonic_code(
+        scheme: SchemeType,
+        derivation_path: DerivationPath,
+        mnemonic_passphrase: Option<(Mnemonic, Zeroizing<String>)>,
+        prompt_bip39_passphrase: bool,
+    ) -> Option<common::SecretKey> {
         let (mnemonic, passphrase) =
             if let Some(mnemonic_passphrase) = mnemonic_passphrase {
                 mnemonic_passphrase
@@ -612,21 +676,11 @@ impl<U: WalletIo> Wallet<U> {
                 (mnemonic, passphrase)
             };
         let seed = Seed::new(&mnemonic, &passphrase);
-        let sk = derive_hd_secret_key(
+        Some(derive_hd_secret_key(
             scheme,
             seed.as_bytes(),
-            derivation_path.clone(),
-        );
-
-        self.insert_keypair(
-            alias.unwrap_or_default(),
-            alias_force,
-            sk.clone(),
-            password,
-            None,
-            Some(derivation_path),
-        )
-        .map(|alias| (alias, sk))
+            derivation_path,
+        ))
     }
 
     /// Generate a spending key similarly to how it's done for keypairs
@@ -671,19 +725,19 @@ impl<U: WalletIo> Wallet<U> {
         .map(|alias| (alias, sk))
     }
 
-    /// Generate a BIP39 mnemonic code, and derive HD wallet seed from it using
-    /// the given passphrase. If no passphrase is provided, optionally prompt
-    /// for a passphrase.
+    /// Generate a BIP39 mnemonic code of the given type, and derive HD wallet
+    /// seed from it using the given passphrase. If no passphrase is provided,
+    /// optionally prompt for a passphrase.
     pub fn gen_hd_seed(
+        mnemonic_type: MnemonicType,
         passphrase: Option<Zeroizing<String>>,
         rng: &mut U::Rng,
         prompt_bip39_passphrase: bool,
     ) -> (Mnemonic, Seed) {
-        const MNEMONIC_TYPE: MnemonicType = MnemonicType::Words24;
-        let mnemonic = U::generate_mnemonic_code(MNEMONIC_TYPE, rng);
+        let mnemonic = U::generate_mnemonic_code(mnemonic_type, rng);
         println!(
             "Safely store your {} words mnemonic.",
-            MNEMONIC_TYPE.word_count()
+            mnemonic_type.word_count()
         );
         println!("{}", mnemonic.clone().into_phrase());
 
@@ -760,6 +814,123 @@ impl<U: WalletIo> Wallet<U> {
         .map(|alias| (alias, spend_key))
     }
 
+    /// Store the mnemonic code that the key with the given alias was derived
+    /// from along the given path, so that further keys can be derived from it
+    /// with [`Self::derive_next_from_stored_mnemonic`]. If no encryption
+    /// password is provided, the mnemonic code will be stored raw without
+    /// encryption.
+    pub fn store_mnemonic(
+        &mut self,
+        alias: impl AsRef<str>,
+        mnemonic: &Mnemonic,
+        passphrase: &str,
+        derivation_path: &DerivationPath,
+        password: Option<Zeroizing<String>>,
+    ) {
+        let mnemonic = StoredMnemonic {
+            phrase: Zeroizing::new(mnemonic.phrase().to_string()),
+            passphrase: Zeroizing::new(passphrase.to_string()),
+            last_derivation_path: derivation_path.to_string(),
+        };
+        self.store.insert_mnemonic(
+            alias.into(),
+            StoredKeypair::new(mnemonic, password).0,
+        );
+    }
+
+    /// Derive the key that follows the last one derived from the mnemonic
+    /// code stored for the given alias, and insert it into the store under
+    /// that alias suffixed with the last index of its derivation path, e.g.
+    /// `alias-1`. The new key is of the same kind as the one that the mnemonic
+    /// code was stored for, and it is encrypted with the password of the
+    /// mnemonic code, if any. If the mnemonic code is encrypted and no
+    /// password is provided, will prompt for password from stdin.
+    /// Returns the alias and the derivation path of the new key, or nothing
+    /// if it was not inserted.
+    pub fn derive_next_from_stored_mnemonic(
+        &mut self,
+        alias_prefix: impl AsRef<str>,
+        password: Option<Zeroizing<String>>,
+    ) -> Result<Option<(String, DerivationPath)>, FindKeyError> {
+        let alias_prefix: Alias = alias_prefix.into();
+        let stored =
+            self.store.find_mnemonic(&alias_prefix).ok_or_else(|| {
+                FindKeyError::KeyNotFound(format!("mnemonic of {alias_prefix}"))
+            })?;
+        let password = if stored.is_encrypted() {
+            Some(password.unwrap_or_else(|| U::read_password(false)))
+        } else {
+            None
+        };
+        let mut stored_mnemonic = stored
+            .get::<U>(true, password.clone())
+            .map_err(FindKeyError::KeyDecryptionError)?;
+        let mnemonic = Mnemonic::from_phrase(
+            stored_mnemonic.phrase.as_str(),
+            Language::English,
+        )
+        .map_err(|_| {
+            FindKeyError::KeyDecryptionError(
+                DecryptionError::DeserializingError,
+            )
+        })?;
+        let seed = Seed::new(&mnemonic, &stored_mnemonic.passphrase);
+        let derivation_path = DerivationPath::from_path_string(
+            &stored_mnemonic.last_derivation_path,
+        )
+        .and_then(|path| path.next())
+        .map_err(FindKeyError::DerivationPathError)?;
+        let index = derivation_path
+            .path()
+            .last()
+            .map(|index| index.to_u32())
+            .unwrap_or_default();
+        let alias = format!("{alias_prefix}-{index}");
+
+        let inserted = if self.store.find_spending_key(&alias_prefix).is_some()
+        {
+            let spend_key = derive_hd_spending_key(
+                seed.as_bytes(),
+                derivation_path.clone(),
+            );
+            self.insert_spending_key(
+                alias,
+                false,
+                spend_key,
+                password.clone(),
+                Some(derivation_path.clone()),
+            )
+        } else {
+            let scheme = match self.find_public_key(&alias_prefix)? {
+                common::PublicKey::Ed25519(_) => SchemeType::Ed25519,
+                common::PublicKey::Secp256k1(_) => SchemeType::Secp256k1,
+            };
+            let sk = derive_hd_secret_key(
+                scheme,
+                seed.as_bytes(),
+                derivation_path.clone(),
+            );
+            self.insert_keypair(
+                alias,
+                false,
+                sk,
+                password.clone(),
+                None,
+                Some(derivation_path.clone()),
+            )
+        };
+        let Some(alias) = inserted else {
+            return Ok(None);
+        };
+        // Keep track of the last derived key to derive the next one later
+        stored_mnemonic.last_derivation_path = derivation_path.to_string();
+        self.store.insert_mnemonic(
+            alias_prefix,
+            StoredKeypair::new(stored_mnemonic, password).0,
+        );
+        Ok(Some((alias, derivation_path)))
+    }
+
     /// Generate a disposable signing key for fee payment and store it under the
     /// precomputed alias in the wallet. This is simply a wrapper around
     /// `gen_key` to manage the alias
@@ -847,6 +1018,29 @@ impl<U: WalletIo> Wallet<U> {
         )
     }
 
+    /// Decrypt all the known secret keys with the given password, without
+    /// prompting for it. Any keys that are decrypted are stored in the cache.
+    /// Returns the outcome of decrypting each of the keys, by their alias.
+    pub fn decrypt_secret_keys(
+        &mut self,
+        password: Zeroizing<String>,
+    ) -> HashMap<String, Result<common::SecretKey, FindKeyError>> {
+        let mut keys = HashMap::new();
+        for (alias, (stored_key, _pkh)) in self.store.get_secret_keys() {
+            let key = match self.decrypted_key_cache.get(&alias) {
+                Some(cached_key) => Ok(cached_key.clone()),
+                None => Self::decrypt_stored_key(
+                    &mut self.decrypted_key_cache,
+                    stored_key,
+                    alias.clone(),
+                    Some(password.clone()),
+                ),
+            };
+            keys.insert(alias.into(), key);
+        }
+        keys
+    }
+
     /// Find a public key in the wallet from the given implicit address.
     pub fn find_public_key_from_implicit_addr(
         &self,
@@ -879,6 +1073,50 @@ impl<U: WalletIo> Wallet<U> {
             })
     }
 
+    /// Export the keys of the validator that was added to this wallet from a
+    /// pre-genesis validator wallet with the given alias, along with the
+    /// supplied Ethereum cold key, which is never added to this wallet. If the
+    /// keys are encrypted but a password is not supplied, then it will be
+    /// interactively prompted.
+    pub fn export_pre_genesis_validator(
+        &self,
+        validator_alias: impl AsRef<str>,
+        eth_cold_key: StoredKeypair<common::SecretKey>,
+        password: Option<Zeroizing<String>>,
+    ) -> Result<pre_genesis::ValidatorWallet, FindKeyError> {
+        let store = self
+            .store
+            .export_pre_genesis_validator(
+                &Alias::from(validator_alias.as_ref()),
+                eth_cold_key,
+            )
+            .ok_or_else(|| {
+                FindKeyError::KeyNotFound(validator_alias.as_ref().to_string())
+            })?;
+        // The keys of a pre-genesis validator wallet share the same password
+        let password = password.or_else(|| {
+            store
+                .consensus_key
+                .is_encrypted()
+                .then(|| U::read_password(false))
+        });
+        let decrypt = |key: &StoredKeypair<common::SecretKey>| {
+            key.get::<U>(true, password.clone())
+                .map_err(FindKeyError::KeyDecryptionError)
+        };
+        let consensus_key = decrypt(&store.consensus_key)?;
+        let eth_cold_key = decrypt(&store.eth_cold_key)?;
+        let tendermint_node_key = decrypt(&store.tendermint_node_key)?;
+        let eth_hot_key = store.validator_keys.eth_bridge_keypair.clone();
+        Ok(pre_genesis::ValidatorWallet {
+            store,
+            consensus_key,
+            eth_cold_key,
+            eth_hot_key,
+            tendermint_node_key,
+        })
+    }
+
     /// Find the spending key with the given alias in the wallet and return it.
     /// If the spending key is encrypted but a password is not supplied, then it
     /// will be interactively prompted.
@@ -1014,7 +1252,8 @@ impl<U: WalletIo> Wallet<U> {
     /// will ask whether the existing alias should be replaced, a different
     /// alias is desired, or the alias creation should be cancelled. Return
     /// the chosen alias if the address has been added, otherwise return
-    /// nothing.
+    /// nothing. Aborted insertions and invalid aliases are treated like a
+    /// cancelled insertion, see [`Store::insert_address`] to tell them apart.
     pub fn insert_address(
         &mut self,
         alias: impl AsRef<str>,
@@ -1023,6 +1262,8 @@ impl<U: WalletIo> Wallet<U> {
     ) -> Option<String> {
         self.store
             .insert_address::<U>(alias.into(), address, force_alias)
+            .ok()
+            .flatten()
             .map(Into::into)
     }
 
@@ -1049,6 +1290,8 @@ impl<U: WalletIo> Wallet<U> {
                 path,
                 alias_force,
             )
+            .ok()
+            .flatten()
             .map(|alias| {
                 // Cache the newly added key
                 self.decrypted_key_cache.insert(alias.clone(), sk);
@@ -1074,6 +1317,8 @@ impl<U: WalletIo> Wallet<U> {
                 path,
                 force_alias,
             )
+            .ok()
+            .flatten()
             .map(Into::into)
     }
 
@@ -1086,6 +1331,8 @@ impl<U: WalletIo> Wallet<U> {
     ) -> Option<String> {
         self.store
             .insert_viewing_key::<U>(alias.into(), view_key, force_alias)
+            .ok()
+            .flatten()
             .map(Into::into)
     }
 
@@ -1106,6 +1353,8 @@ impl<U: WalletIo> Wallet<U> {
                 path,
                 force_alias,
             )
+            .ok()
+            .flatten()
             .map(|alias| {
                 // Cache the newly added key
                 self.decrypted_spendkey_cache
@@ -1124,6 +1373,8 @@ impl<U: WalletIo> Wallet<U> {
     ) -> Option<String> {
         self.store
             .insert_payment_addr::<U>(alias.into(), payment_addr, force_alias)
+            .ok()
+            .flatten()
             .map(Into::into)
     }
 
@@ -1137,6 +1388,35 @@ impl<U: WalletIo> Wallet<U> {
     pub fn remove_all_by_alias(&mut self, alias: String) {
         self.store.remove_alias(&alias.into())
     }
+
+    /// Move the keys and addresses associated with the `old` alias to the
+    /// `new` one. If the new alias is already used, will ask whether it should
+    /// be replaced, a different alias is desired, or the renaming should be
+    /// cancelled.
+    pub fn rename_alias(
+        &mut self,
+        old: impl AsRef<str>,
+        new: impl AsRef<str>,
+        force_alias: bool,
+    ) -> RenameResult {
+        let old: Alias = old.into();
+        let result =
+            self.store.rename_alias::<U>(&old, new.into(), force_alias);
+        if let RenameResult::Renamed(new) = &result {
+            if *new != old {
+                // Drop the decrypted keys of a replaced alias, if any
+                self.decrypted_key_cache.remove(new);
+                self.decrypted_spendkey_cache.remove(new);
+                if let Some(key) = self.decrypted_key_cache.remove(&old) {
+                    self.decrypted_key_cache.insert(new.clone(), key);
+                }
+                if let Some(key) = self.decrypted_spendkey_cache.remove(&old) {
+                    self.decrypted_spendkey_cache.insert(new.clone(), key);
+                }
+            }
+        }
+        result
+    }
 }
 
 #[inline]
@@ -1170,6 +1450,8 @@ fn check_if_disposable_key_and<F: FnOnce(&PublicKeyHash, i64) -> bool>(
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
+    use namada_core::address::testing::established_address_1;
     use namada_core::key::testing::{keypair_1, keypair_2, keypair_3};
     use rand_core::OsRng;
 
@@ -1182,6 +1464,306 @@ mod tests {
         type Rng = OsRng;
     }
 
+    /// Wallet utils that decline overwriting existing aliases
+    #[derive(Clone)]
+    struct SkipWalletUtils;
+
+    impl WalletIo for SkipWalletUtils {
+        type Rng = OsRng;
+
+        fn show_overwrite_confirmation(
+            _alias: &Alias,
+            _alias_for: &str,
+        ) -> ConfirmationResponse {
+            ConfirmationResponse::Skip
+        }
+    }
+
+    fn new_wallet<U: WalletIo>(utils: U) -> Wallet<U> {
+        Wallet {
+            utils,
+            store: Default::default(),
+            decrypted_key_cache: Default::default(),
+            decrypted_spendkey_cache: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_rename_key() {
+        let mut wallet = new_wallet(TestWalletUtils);
+        let pk = keypair_1().ref_to();
+        let pkh = PublicKeyHash::from(&pk);
+        let address = Address::Implicit(ImplicitAddress(pkh.clone()));
+        wallet.insert_keypair(
+            "alice".to_string(),
+            false,
+            keypair_1(),
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(
+            wallet.rename_alias("alice", "bob", false),
+            RenameResult::Renamed("bob".into())
+        );
+        assert!(wallet.find_secret_key("alice", None).is_err());
+        assert_eq!(wallet.find_secret_key("bob", None).unwrap().ref_to(), pk);
+        assert_eq!(wallet.find_public_key("bob").unwrap(), pk);
+        assert_eq!(wallet.find_alias(&address), Some(&"bob".into()));
+        assert_eq!(wallet.store.find_alias_by_pkh(&pkh), Some("bob".into()));
+    }
+
+    #[test]
+    fn test_decrypt_secret_keys() {
+        let mut wallet = new_wallet(TestWalletUtils);
+        let password = Zeroizing::new("password".to_string());
+        for (alias, sk, password) in [
+            ("alice", keypair_1(), Some(password.clone())),
+            ("bob", keypair_2(), Some(password.clone())),
+            ("carol", keypair_3(), None),
+        ] {
+            wallet.insert_keypair(
+                alias.to_string(),
+                false,
+                sk,
+                password,
+                None,
+                None,
+            );
+        }
+        // Start from an empty cache, to force the keys to be decrypted
+        wallet.decrypted_key_cache.clear();
+
+        let keys = wallet.decrypt_secret_keys(password);
+
+        assert_eq!(keys.len(), 3);
+        for (alias, sk) in [
+            ("alice", keypair_1()),
+            ("bob", keypair_2()),
+            ("carol", keypair_3()),
+        ] {
+            assert_eq!(keys[alias].as_ref().unwrap(), &sk);
+        }
+        assert!(wallet.decrypted_key_cache.contains_key(&"alice".into()));
+        assert!(wallet.decrypted_key_cache.contains_key(&"bob".into()));
+    }
+
+    #[test]
+    fn test_rename_onto_existing_alias() {
+        let mut wallet = new_wallet(SkipWalletUtils);
+        for (alias, sk) in [("alice", keypair_1()), ("bob", keypair_2())] {
+            wallet.insert_keypair(
+                alias.to_string(),
+                false,
+                sk,
+                None,
+                None,
+                None,
+            );
+        }
+
+        // Without force, overwriting is declined
+        assert_eq!(
+            wallet.rename_alias("alice", "bob", false),
+            RenameResult::Skipped
+        );
+        assert_eq!(
+            wallet.find_public_key("alice").unwrap(),
+            keypair_1().ref_to()
+        );
+        assert_eq!(
+            wallet.find_public_key("bob").unwrap(),
+            keypair_2().ref_to()
+        );
+
+        // With force, the entries of the existing alias are replaced
+        assert_eq!(
+            wallet.rename_alias("alice", "bob", true),
+            RenameResult::Renamed("bob".into())
+        );
+        assert!(wallet.find_public_key("alice").is_err());
+        assert_eq!(
+            wallet.find_public_key("bob").unwrap(),
+            keypair_1().ref_to()
+        );
+        let pkh_2 = PublicKeyHash::from(&keypair_2().ref_to());
+        assert!(wallet.store.find_alias_by_pkh(&pkh_2).is_none());
+    }
+
+    #[test]
+    fn test_invalid_aliases_are_rejected() {
+        for alias in [" ", "\t\n", "bob\u{0}", "al\u{7f}ice"] {
+            assert!(!Alias::from(alias).is_valid());
+            assert!(Alias::try_new(alias).is_none());
+        }
+        for alias in ["bob", "Alice-1", "my key", "validator_0"] {
+            assert!(Alias::from(alias).is_valid());
+            assert_eq!(Alias::try_new(alias), Some(Alias::from(alias)));
+        }
+
+        let mut wallet = new_wallet(TestWalletUtils);
+        let address = Address::Implicit(ImplicitAddress(PublicKeyHash::from(
+            &keypair_1().ref_to(),
+        )));
+        assert!(
+            wallet
+                .insert_address(" \t", address.clone(), false)
+                .is_none()
+        );
+        assert!(
+            wallet
+                .insert_keypair(
+                    "bob\n".to_string(),
+                    false,
+                    keypair_1(),
+                    None,
+                    None,
+                    None
+                )
+                .is_none()
+        );
+        // The store tells invalid aliases apart from other failures
+        assert_eq!(
+            wallet.store.insert_address::<TestWalletUtils>(
+                " \t".into(),
+                address.clone(),
+                false
+            ),
+            Err(InsertError::InvalidAlias(" \t".into()))
+        );
+        assert!(wallet.store.get_addresses().is_empty());
+        assert!(wallet.store.get_public_keys().is_empty());
+
+        // Valid aliases are still inserted and empty ones are still defaulted
+        assert_eq!(
+            wallet.insert_address("Bob", address.clone(), false),
+            Some("bob".to_string())
+        );
+        assert_eq!(
+            wallet.insert_address("", established_address_1(), false),
+            Some(established_address_1().encode())
+        );
+        assert_eq!(
+            wallet.rename_alias("bob", "\u{1b}[31m", false),
+            RenameResult::InvalidAlias("\u{1b}[31m".into())
+        );
+        assert_eq!(wallet.find_address("bob").as_deref(), Some(&address));
+    }
+
+    #[test]
+    fn test_derive_next_from_stored_mnemonic() {
+        const MNEMONIC_CODE: &str = "cruise ball fame lucky fabric govern \
+                                     length fruit permit tonight fame pear \
+                                     horse park key chimney furnace lobster \
+                                     foot example shoot dry fuel lawn";
+        let password = || Some(Zeroizing::new("password".to_string()));
+        let mnemonic =
+            Mnemonic::from_phrase(MNEMONIC_CODE, Language::English).unwrap();
+        let seed = || Seed::new(&mnemonic, "passphrase");
+        let mut wallet = new_wallet(TestWalletUtils);
+
+        // Generate a transparent and a shielded key and store their mnemonic
+        let path =
+            DerivationPath::default_for_transparent_scheme(SchemeType::Ed25519);
+        let (alias, _) = wallet
+            .derive_store_hd_secret_key(
+                SchemeType::Ed25519,
+                Some("alice".to_string()),
+                false,
+                seed(),
+                path.clone(),
+                password(),
+            )
+            .unwrap();
+        wallet.store_mnemonic(
+            &alias,
+            &mnemonic,
+            "passphrase",
+            &path,
+            password(),
+        );
+        let shielded_path = DerivationPath::default_for_shielded();
+        let (shielded_alias, _) = wallet
+            .derive_store_hd_spendind_key(
+                "bob".to_string(),
+                false,
+                seed(),
+                shielded_path.clone(),
+                None,
+            )
+            .unwrap();
+        wallet.store_mnemonic(
+            &shielded_alias,
+            &mnemonic,
+            "passphrase",
+            &shielded_path,
+            None,
+        );
+
+        // Derive siblings of the transparent key
+        for index in 1..=2 {
+            let (alias, sibling_path) = wallet
+                .derive_next_from_stored_mnemonic("alice", password())
+                .unwrap()
+                .unwrap();
+            assert_eq!(alias, format!("alice-{index}"));
+            assert_eq!(
+                sibling_path.to_string(),
+                format!("m/44'/877'/0'/0'/{index}'")
+            );
+            let expected_sk = derive_hd_secret_key(
+                SchemeType::Ed25519,
+                seed().as_bytes(),
+                sibling_path,
+            );
+            assert_eq!(
+                wallet.find_public_key(&alias).unwrap(),
+                expected_sk.ref_to()
+            );
+            assert!(
+                wallet.store.find_secret_key(&alias).unwrap().is_encrypted()
+            );
+        }
+
+        // Derive a sibling of the shielded key
+        let (alias, sibling_path) = wallet
+            .derive_next_from_stored_mnemonic("bob", None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(alias, "bob-1");
+        let expected_key =
+            derive_hd_spending_key(seed().as_bytes(), sibling_path);
+        assert_eq!(
+            wallet.find_spending_key(&alias, None).unwrap(),
+            expected_key
+        );
+
+        // The mnemonic code can't be used with a wrong password or without
+        // having been stored
+        assert_matches!(
+            wallet.derive_next_from_stored_mnemonic(
+                "alice",
+                Some(Zeroizing::new("wrong".to_string()))
+            ),
+            Err(FindKeyError::KeyDecryptionError(_))
+        );
+        assert_matches!(
+            wallet.derive_next_from_stored_mnemonic("alice-1", password()),
+            Err(FindKeyError::KeyNotFound(_))
+        );
+    }
+
+    #[test]
+    fn test_rename_nonexistent_alias() {
+        let mut wallet = new_wallet(TestWalletUtils);
+        assert_eq!(
+            wallet.rename_alias("alice", "bob", false),
+            RenameResult::NotFound
+        );
+        assert!(!wallet.store.contains_alias(&"bob".into()));
+    }
+
     #[test]
     fn test_disposable_key_alias_invalid() {
         assert!(!check_if_disposable_key_and(
@@ -1258,4 +1840,190 @@ mod tests {
                 .any(|pk| *pk == new_key_pk)
         );
     }
+
+    #[test]
+    fn test_derive_key_from_mnemonic_code_does_not_store() {
+        const PHRASE: &str = "abandon abandon abandon abandon abandon abandon \
+                              abandon abandon abandon abandon abandon about";
+        const OTHER_PHRASE: &str = "legal winner thank year wave sausage \
+                                    worth useful legal winner thank yellow";
+        const EXPECTED_ADDRESS: &str =
+            "tnam1qqq33azfjnv53vlgp2xxuaake5pepqyjfgj9wmgx";
+        let mnemonic_passphrase_for = |phrase: &str, passphrase: &str| {
+            let mnemonic = Mnemonic::from_phrase(phrase, Language::English)
+                .expect("Valid mnemonic phrase");
+            Some((mnemonic, Zeroizing::new(passphrase.to_string())))
+        };
+        let mnemonic_passphrase =
+            || mnemonic_passphrase_for(PHRASE, "passphrase");
+        let derivation_path =
+            DerivationPath::default_for_transparent_scheme(SchemeType::Ed25519);
+        let mut wallet = Wallet {
+            utils: TestWalletUtils,
+            store: Default::default(),
+            decrypted_key_cache: Default::default(),
+            decrypted_spendkey_cache: Default::default(),
+        };
+        let store_before = wallet.store.encode();
+
+        let derive = || {
+            Wallet::<TestWalletUtils>::derive_key_from_mnemonic_code(
+                SchemeType::Ed25519,
+                derivation_path.clone(),
+                mnemonic_passphrase(),
+                false,
+            )
+            .expect("Key derivation should succeed")
+        };
+        let sk = derive();
+        let address = Address::Implicit((&sk.to_public()).into());
+        assert_eq!(address.encode(), EXPECTED_ADDRESS);
+
+        // a wrong passphrase or a different mnemonic yield another address
+        for (phrase, passphrase) in [
+            (PHRASE, "wrong"),
+            (PHRASE, ""),
+            (OTHER_PHRASE, "passphrase"),
+        ] {
+            let other_sk =
+                Wallet::<TestWalletUtils>::derive_key_from_mnemonic_code(
+                    SchemeType::Ed25519,
+                    derivation_path.clone(),
+                    mnemonic_passphrase_for(phrase, passphrase),
+                    false,
+                )
+                .expect("Key derivation should succeed");
+            let other_address =
+                Address::Implicit((&other_sk.to_public()).into());
+            assert_ne!(other_address, address);
+        }
+
+        // the same mnemonic and path always yield the same address
+        let sk_again = derive();
+        assert_eq!(sk.to_public(), sk_again.to_public());
+        assert_eq!(address, Address::Implicit((&sk_again.to_public()).into()));
+        // nothing was written to the wallet
+        assert_eq!(wallet.store.encode(), store_before);
+        assert!(wallet.store.get_public_keys().is_empty());
+
+        // storing the key from the same mnemonic yields the same key
+        let (_alias, stored_sk) = wallet
+            .derive_store_key_from_mnemonic_code(
+                SchemeType::Ed25519,
+                Some("test".to_string()),
+                false,
+                derivation_path.clone(),
+                mnemonic_passphrase(),
+                false,
+                None,
+            )
+            .expect("Key derivation should succeed");
+        assert_eq!(sk.to_public(), stored_sk.to_public());
+        assert_eq!(
+            wallet.find_address("test").map(|addr| addr.into_owned()),
+            Some(address)
+        );
+    }
+
+    #[test]
+    fn test_mnemonic_type_for_word_count() {
+        for words in [12, 15, 18, 21, 24] {
+            let mnemonic_type = mnemonic_type_for_word_count(words)
+                .expect("Valid number of mnemonic words");
+            let mnemonic = TestWalletUtils::generate_mnemonic_code(
+                mnemonic_type,
+                &mut OsRng,
+            );
+            assert_eq!(mnemonic.phrase().split_whitespace().count(), words);
+        }
+        for words in [0, 11, 13, 23, 25, 48] {
+            assert!(mnemonic_type_for_word_count(words).is_err());
+        }
+    }
+
+    #[test]
+    fn test_export_pre_genesis_validator() {
+        let password = || Some(Zeroizing::new("password".to_string()));
+        let (consensus_key, consensus_sk) =
+            gen_key_to_store(SchemeType::Ed25519, password(), &mut OsRng);
+        let (eth_cold_key, eth_cold_sk) =
+            gen_key_to_store(SchemeType::Secp256k1, password(), &mut OsRng);
+        let (tendermint_node_key, tendermint_node_sk) =
+            gen_key_to_store(SchemeType::Ed25519, password(), &mut OsRng);
+        let validator_keys = ValidatorKeys {
+            protocol_keypair: keypair_1(),
+            eth_bridge_keypair: gen_secret_key(
+                SchemeType::Secp256k1,
+                &mut OsRng,
+            ),
+        };
+        let validator_wallet = pre_genesis::ValidatorWallet {
+            eth_hot_key: validator_keys.eth_bridge_keypair.clone(),
+            store: pre_genesis::ValidatorStore {
+                consensus_key,
+                eth_cold_key,
+                tendermint_node_key,
+                validator_keys,
+            },
+            consensus_key: consensus_sk,
+            eth_cold_key: eth_cold_sk,
+            tendermint_node_key: tendermint_node_sk,
+        };
+        let encoded_store = validator_wallet.store.encode();
+        let stored_eth_cold_key = validator_wallet.store.eth_cold_key.clone();
+        let eth_cold_pkh =
+            PublicKeyHash::from(&validator_wallet.eth_cold_key.ref_to());
+        let public_keys = [
+            validator_wallet.consensus_key.ref_to(),
+            validator_wallet.eth_cold_key.ref_to(),
+            validator_wallet.eth_hot_key.ref_to(),
+            validator_wallet.tendermint_node_key.ref_to(),
+        ];
+
+        let mut wallet = new_wallet(TestWalletUtils);
+        wallet.extend_from_pre_genesis_validator(
+            established_address_1(),
+            "validator".into(),
+            validator_wallet,
+        );
+        // The cold key stays out of the wallet of the chain
+        assert_matches!(
+            wallet.find_public_key_by_pkh(&eth_cold_pkh),
+            Err(FindKeyError::KeyNotFound(_))
+        );
+        let exported = wallet
+            .export_pre_genesis_validator(
+                "validator",
+                stored_eth_cold_key.clone(),
+                password(),
+            )
+            .unwrap();
+        assert_eq!(exported.store.encode(), encoded_store);
+        assert_eq!(
+            [
+                exported.consensus_key.ref_to(),
+                exported.eth_cold_key.ref_to(),
+                exported.eth_hot_key.ref_to(),
+                exported.tendermint_node_key.ref_to(),
+            ],
+            public_keys
+        );
+
+        assert_matches!(
+            wallet.export_pre_genesis_validator(
+                "other",
+                stored_eth_cold_key.clone(),
+                password()
+            ),
+            Err(FindKeyError::KeyNotFound(_))
+        );
+        assert_matches!(
+            wallet.export_pre_genesis_validator(
+                "validator",
+                stored_eth_cold_key,
+                Some(Zeroizing::new("wrong".to_string()))
+            ),
+            Err(FindKeyError::KeyDecryptionError(_))
+        );
+    }
 }
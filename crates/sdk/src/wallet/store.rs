@@ -7,6 +7,9 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 use bimap::BiBTreeMap;
+use borsh::{BorshDeserialize, BorshSerialize};
+use borsh_ext::BorshSerializeExt;
+use data_encoding::HEXLOWER;
 use itertools::Itertools;
 use masp_primitives::zip32;
 use namada_core::address::{Address, ImplicitAddress};
@@ -16,6 +19,7 @@ use namada_core::masp::{
     ExtendedSpendingKey, ExtendedViewingKey, PaymentAddress,
 };
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use zeroize::Zeroizing;
 
 use super::alias::{self, Alias};
@@ -31,6 +35,37 @@ pub enum ConfirmationResponse {
     Reselect(Alias),
     /// Skip assigning the given entity an alias
     Skip,
+    /// Abort the whole operation, including any remaining insertions
+    Abort,
+}
+
+/// Error returned when an entry can't be inserted into the store
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum InsertError {
+    /// The insertion was aborted while resolving an alias conflict
+    #[error("Aborted adding an entry with the alias {0}")]
+    Aborted(Alias),
+    /// The alias is blank or contains non-printable characters
+    #[error(
+        "The alias {:?} is invalid. Aliases must not be blank nor contain \
+         non-printable characters.",
+        .0.normalize()
+    )]
+    InvalidAlias(Alias),
+}
+
+/// Outcome of renaming an alias
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RenameResult {
+    /// The entries of the old alias were moved to the given alias
+    Renamed(Alias),
+    /// The old alias does not exist in the wallet
+    NotFound,
+    /// The renaming was cancelled, either because the new alias is reserved
+    /// or because overwriting an existing alias was declined
+    Skipped,
+    /// The new alias is blank or contains non-printable characters
+    InvalidAlias(Alias),
 }
 
 /// Special keys for a validator
@@ -58,6 +93,102 @@ pub struct ValidatorData {
     pub keys: ValidatorKeys,
 }
 
+/// A dangling reference between the maps of a wallet store
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum StoreInconsistency {
+    /// A public key hash refers to an alias without a matching public key
+    #[error(
+        "The public key hash {pkh} refers to alias {alias}, which has no \
+         matching public key"
+    )]
+    DanglingPublicKeyHash {
+        /// The public key hash
+        pkh: PublicKeyHash,
+        /// The alias it refers to
+        alias: Alias,
+    },
+    /// A public key cannot be looked up by its hash
+    #[error("The public key of alias {0} is not indexed by its hash")]
+    UnindexedPublicKey(Alias),
+    /// A secret key has no public key under the same alias
+    #[error("The secret key of alias {0} has no public key")]
+    SecretKeyWithoutPublicKey(Alias),
+    /// An unencrypted secret key does not match the public key under the
+    /// same alias
+    #[error("The secret key of alias {0} does not match its public key")]
+    MismatchedPublicKey(Alias),
+    /// An implicit address does not match the public key under the same
+    /// alias
+    #[error("The address of alias {0} does not match its public key")]
+    MismatchedImplicitAddress(Alias),
+    /// A spending key has no viewing key under the same alias
+    #[error("The spending key of alias {0} has no viewing key")]
+    SpendingKeyWithoutViewingKey(Alias),
+    /// An unencrypted spending key does not match the viewing key under the
+    /// same alias
+    #[error("The viewing key of alias {0} does not match its spending key")]
+    MismatchedViewingKey(Alias),
+    /// A derivation path refers to an alias without any key
+    #[error("The derivation path of alias {0} does not belong to any key")]
+    DanglingDerivationPath(Alias),
+}
+
+/// A BIP39 mnemonic code kept in the store, so that further HD keys can be
+/// derived from it
+#[derive(Clone, Debug)]
+pub struct StoredMnemonic {
+    /// The mnemonic code phrase
+    pub phrase: Zeroizing<String>,
+    /// The BIP39 passphrase that the seed is generated with
+    pub passphrase: Zeroizing<String>,
+    /// The derivation path of the last key derived from the mnemonic code
+    pub last_derivation_path: String,
+}
+
+impl BorshSerialize for StoredMnemonic {
+    fn serialize<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        BorshSerialize::serialize(self.phrase.as_str(), writer)?;
+        BorshSerialize::serialize(self.passphrase.as_str(), writer)?;
+        BorshSerialize::serialize(&self.last_derivation_path, writer)
+    }
+}
+
+impl BorshDeserialize for StoredMnemonic {
+    fn deserialize_reader<R: std::io::Read>(
+        reader: &mut R,
+    ) -> std::io::Result<Self> {
+        let phrase: String = BorshDeserialize::deserialize_reader(reader)?;
+        let passphrase: String = BorshDeserialize::deserialize_reader(reader)?;
+        let last_derivation_path: String =
+            BorshDeserialize::deserialize_reader(reader)?;
+        Ok(Self {
+            phrase: Zeroizing::new(phrase),
+            passphrase: Zeroizing::new(passphrase),
+            last_derivation_path,
+        })
+    }
+}
+
+impl Display for StoredMnemonic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", HEXLOWER.encode(&self.serialize_to_vec()))
+    }
+}
+
+impl FromStr for StoredMnemonic {
+    type Err = std::io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = HEXLOWER.decode(s.as_bytes()).map_err(|err| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+        })?;
+        Self::try_from_slice(&bytes)
+    }
+}
+
 /// A Storage area for keys and addresses
 #[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Store {
@@ -82,6 +213,10 @@ pub struct Store {
     pub(crate) validator_data: Option<ValidatorData>,
     /// Namada address vp type
     address_vp_types: BTreeMap<AddressVpType, HashSet<Address>>,
+    /// Mnemonic codes stored to derive further HD keys, by the alias of the
+    /// first key derived from them
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    mnemonics: BTreeMap<Alias, StoredKeypair<StoredMnemonic>>,
 }
 
 /// Grouping of addresses by validity predicate.
@@ -137,6 +272,24 @@ impl Store {
         self.spend_keys.get(&alias.into())
     }
 
+    /// Find the mnemonic code stored for the key with the given alias
+    pub fn find_mnemonic(
+        &self,
+        alias: impl AsRef<str>,
+    ) -> Option<&StoredKeypair<StoredMnemonic>> {
+        self.mnemonics.get(&alias.into())
+    }
+
+    /// Store a mnemonic code for the key with the given alias, replacing any
+    /// mnemonic code previously stored for it
+    pub fn insert_mnemonic(
+        &mut self,
+        alias: Alias,
+        mnemonic: StoredKeypair<StoredMnemonic>,
+    ) {
+        self.mnemonics.insert(alias, mnemonic);
+    }
+
     /// Find the viewing key with the given alias and return it
     pub fn find_viewing_key(
         &self,
@@ -295,7 +448,8 @@ impl Store {
     /// Insert a new secret key with the given alias. If the alias is already
     /// used, will prompt for overwrite/reselection confirmation. If declined,
     /// then keypair is not inserted and nothing is returned, otherwise selected
-    /// alias is returned.
+    /// alias is returned. If the whole operation is aborted instead, an error
+    /// is returned so that the caller can stop any remaining insertions.
     pub fn insert_keypair<U: WalletIo>(
         &mut self,
         mut alias: Alias,
@@ -304,7 +458,7 @@ impl Store {
         address: Option<Address>,
         path: Option<DerivationPath>,
         force: bool,
-    ) -> Option<Alias> {
+    ) -> Result<Option<Alias>, InsertError> {
         // abort if the key already exists
         let pubkey = keypair.ref_to();
         let pkh = PublicKeyHash::from(&pubkey);
@@ -314,43 +468,42 @@ impl Store {
             if self.pkhs.contains_key(&pkh) {
                 let alias = self.pkhs.get(&pkh).unwrap();
                 println!("The key already exists with alias {}", alias);
-                return None;
+                return Ok(None);
             } else if let Some(alias) = self.addresses.get_by_right(&address) {
                 println!(
                     "Address {} already exists in the wallet with alias {}",
                     address.encode(),
                     alias,
                 );
-                return None;
+                return Ok(None);
             }
         }
 
         // abort if the alias is reserved
         if Alias::is_reserved(&alias).is_some() {
             println!("The alias {} is reserved.", alias);
-            return None;
+            return Ok(None);
         }
 
         if alias.is_empty() {
             alias = pkh.to_string().into();
             println!("Empty alias given, defaulting to {}.", alias);
         }
-        if self.contains_alias(&alias) && !force {
-            match U::show_overwrite_confirmation(&alias, "a key") {
-                ConfirmationResponse::Replace => {}
-                ConfirmationResponse::Reselect(new_alias) => {
-                    return self.insert_keypair::<U>(
-                        new_alias,
-                        keypair,
-                        password,
-                        Some(address),
-                        path,
-                        false,
-                    );
-                }
-                ConfirmationResponse::Skip => {
-                    return None;
-                }
+        match self.resolve_alias::<U>(&alias, "a key", force)? {
+            ConfirmationResponse::Replace => {}
+            ConfirmationResponse::Reselect(new_alias) => {
+                return self.insert_keypair::<U>(
+                    new_alias,
+                    keypair,
+                    password,
+                    Some(address),
+                    path,
+                    false,
+                );
+            }
+            ConfirmationResponse::Skip => return Ok(None),
+            ConfirmationResponse::Abort => {
+                return Err(InsertError::Aborted(alias));
             }
         }
         self.remove_alias(&alias);
@@ -360,7 +513,7 @@ impl Store {
         self.pkhs.insert(pkh, alias.clone());
         self.addresses.insert(alias.clone(), address);
         path.map(|x| self.derivation_paths.insert(alias.clone(), x));
-        Some(alias)
+        Ok(Some(alias))
     }
 
     /// Insert spending keys similarly to how it's done for keypairs
@@ -371,27 +524,28 @@ impl Store {
         password: Option<Zeroizing<String>>,
         path: Option<DerivationPath>,
         force: bool,
-    ) -> Option<Alias> {
+    ) -> Result<Option<Alias>, InsertError> {
         // abort if the alias is reserved
         if Alias::is_reserved(&alias).is_some() {
             println!("The alias {} is reserved.", alias);
-            return None;
+            return Ok(None);
         }
         // abort if the alias is empty
         if alias.is_empty() {
             eprintln!("Empty alias given.");
-            return None;
+            return Ok(None);
         }
 
-        if self.contains_alias(&alias) && !force {
-            match U::show_overwrite_confirmation(&alias, "a spending key") {
-                ConfirmationResponse::Replace => {}
-                ConfirmationResponse::Reselect(new_alias) => {
-                    return self.insert_spending_key::<U>(
-                        new_alias, spendkey, password, path, false,
-                    );
-                }
-                ConfirmationResponse::Skip => return None,
+        match self.resolve_alias::<U>(&alias, "a spending key", force)? {
+            ConfirmationResponse::Replace => {}
+            ConfirmationResponse::Reselect(new_alias) => {
+                return self.insert_spending_key::<U>(
+                    new_alias, spendkey, password, path, false,
+                );
+            }
+            ConfirmationResponse::Skip => return Ok(None),
+            ConfirmationResponse::Abort => {
+                return Err(InsertError::Aborted(alias));
             }
         }
         self.remove_alias(&alias);
@@ -403,7 +557,7 @@ impl Store {
             zip32::ExtendedFullViewingKey::from(&spendkey.into()).into();
         self.view_keys.insert(alias.clone(), viewkey);
         path.map(|p| self.derivation_paths.insert(alias.clone(), p));
-        Some(alias)
+        Ok(Some(alias))
     }
 
     /// Insert viewing keys similarly to how it's done for keypairs
@@ -412,30 +566,30 @@ impl Store {
         alias: Alias,
         viewkey: ExtendedViewingKey,
         force: bool,
-    ) -> Option<Alias> {
+    ) -> Result<Option<Alias>, InsertError> {
         // abort if the alias is reserved
         if Alias::is_reserved(&alias).is_some() {
             println!("The alias {} is reserved.", alias);
-            return None;
+            return Ok(None);
         }
 
         if alias.is_empty() {
             eprintln!("Empty alias given.");
-            return None;
+            return Ok(None);
         }
-        if self.contains_alias(&alias) && !force {
-            match U::show_overwrite_confirmation(&alias, "a viewing key") {
-                ConfirmationResponse::Replace => {}
-                ConfirmationResponse::Reselect(new_alias) => {
-                    return self
-                        .insert_viewing_key::<U>(new_alias, viewkey, false);
-                }
-                ConfirmationResponse::Skip => return None,
+        match self.resolve_alias::<U>(&alias, "a viewing key", force)? {
+            ConfirmationResponse::Replace => {}
+            ConfirmationResponse::Reselect(new_alias) => {
+                return self.insert_viewing_key::<U>(new_alias, viewkey, false);
+            }
+            ConfirmationResponse::Skip => return Ok(None),
+            ConfirmationResponse::Abort => {
+                return Err(InsertError::Aborted(alias));
             }
         }
         self.remove_alias(&alias);
         self.view_keys.insert(alias.clone(), viewkey);
-        Some(alias)
+        Ok(Some(alias))
     }
 
     /// Insert public keys
@@ -446,7 +600,7 @@ impl Store {
         address: Option<Address>,
         path: Option<DerivationPath>,
         force: bool,
-    ) -> Option<Alias> {
+    ) -> Result<Option<Alias>, InsertError> {
         let pkh = PublicKeyHash::from(&pubkey);
         let address = address
             .unwrap_or_else(|| Address::Implicit(ImplicitAddress(pkh.clone())));
@@ -454,33 +608,34 @@ impl Store {
             if self.pkhs.contains_key(&pkh) {
                 let alias = self.pkhs.get(&pkh).unwrap();
                 println!("The key already exists with alias {}", alias);
-                return None;
+                return Ok(None);
             } else if let Some(alias) = self.addresses.get_by_right(&address) {
                 println!(
                     "Address {} already exists in the wallet with alias {}",
                     address.encode(),
                     alias,
                 );
-                return None;
+                return Ok(None);
             }
         }
         if alias.is_empty() {
             alias = pkh.to_string().into();
             println!("Empty alias given, defaulting to {}.", alias);
         }
-        if self.contains_alias(&alias) && !force {
-            match U::show_overwrite_confirmation(&alias, "a public key") {
-                ConfirmationResponse::Replace => {}
-                ConfirmationResponse::Reselect(new_alias) => {
-                    return self.insert_public_key::<U>(
-                        new_alias,
-                        pubkey,
-                        Some(address),
-                        path,
-                        false,
-                    );
-                }
-                ConfirmationResponse::Skip => return None,
+        match self.resolve_alias::<U>(&alias, "a public key", force)? {
+            ConfirmationResponse::Replace => {}
+            ConfirmationResponse::Reselect(new_alias) => {
+                return self.insert_public_key::<U>(
+                    new_alias,
+                    pubkey,
+                    Some(address),
+                    path,
+                    false,
+                );
+            }
+            ConfirmationResponse::Skip => return Ok(None),
+            ConfirmationResponse::Abort => {
+                return Err(InsertError::Aborted(alias));
             }
         }
         self.remove_alias(&alias);
@@ -488,7 +643,7 @@ impl Store {
         path.map(|x| self.derivation_paths.insert(alias.clone(), x));
         self.pkhs.insert(pkh, alias.clone());
         self.addresses.insert(alias.clone(), address);
-        Some(alias)
+        Ok(Some(alias))
     }
 
     /// Insert payment addresses similarly to how it's done for keypairs
@@ -497,45 +652,46 @@ impl Store {
         alias: Alias,
         payment_addr: PaymentAddress,
         force: bool,
-    ) -> Option<Alias> {
+    ) -> Result<Option<Alias>, InsertError> {
         // abort if the alias is reserved
         if Alias::is_reserved(&alias).is_some() {
             println!("The alias {} is reserved.", alias);
-            return None;
+            return Ok(None);
         }
 
         if alias.is_empty() {
             eprintln!("Empty alias given.");
-            return None;
+            return Ok(None);
         }
-        if self.contains_alias(&alias) && !force {
-            match U::show_overwrite_confirmation(&alias, "a payment address") {
-                ConfirmationResponse::Replace => {}
-                ConfirmationResponse::Reselect(new_alias) => {
-                    return self.insert_payment_addr::<U>(
-                        new_alias,
-                        payment_addr,
-                        false,
-                    );
-                }
-                ConfirmationResponse::Skip => return None,
+        match self.resolve_alias::<U>(&alias, "a payment address", force)? {
+            ConfirmationResponse::Replace => {}
+            ConfirmationResponse::Reselect(new_alias) => {
+                return self.insert_payment_addr::<U>(
+                    new_alias,
+                    payment_addr,
+                    false,
+                );
+            }
+            ConfirmationResponse::Skip => return Ok(None),
+            ConfirmationResponse::Abort => {
+                return Err(InsertError::Aborted(alias));
             }
         }
         self.remove_alias(&alias);
         self.payment_addrs.insert(alias.clone(), payment_addr);
-        Some(alias)
+        Ok(Some(alias))
     }
 
     /// Insert a new address with the given alias. If the alias is already used,
     /// will prompt for overwrite/reselection confirmation, which when declined,
     /// the address won't be added. Return the selected alias if the address has
-    /// been added.
+    /// been added, or an error if the operation was aborted.
     pub fn insert_address<U: WalletIo>(
         &mut self,
         mut alias: Alias,
         address: Address,
         force: bool,
-    ) -> Option<Alias> {
+    ) -> Result<Option<Alias>, InsertError> {
         // abort if the address already exists in the wallet
         if self.addresses.contains_right(&address) && !force {
             println!(
@@ -543,27 +699,45 @@ impl Store {
                 address.encode(),
                 self.addresses.get_by_right(&address).unwrap()
             );
-            return None;
+            return Ok(None);
         }
 
         if alias.is_empty() {
             alias = address.encode().into();
             println!("Empty alias given, defaulting to {}.", alias);
         }
-        if self.contains_alias(&alias) && !force {
-            match U::show_overwrite_confirmation(&alias, "an address") {
-                ConfirmationResponse::Replace => {}
-                ConfirmationResponse::Reselect(new_alias) => {
-                    return self.insert_address::<U>(new_alias, address, false);
-                }
-                ConfirmationResponse::Skip => {
-                    return None;
-                }
+        match self.resolve_alias::<U>(&alias, "an address", force)? {
+            ConfirmationResponse::Replace => {}
+            ConfirmationResponse::Reselect(new_alias) => {
+                return self.insert_address::<U>(new_alias, address, false);
+            }
+            ConfirmationResponse::Skip => return Ok(None),
+            ConfirmationResponse::Abort => {
+                return Err(InsertError::Aborted(alias));
             }
         }
         self.remove_alias(&alias);
         self.addresses.insert(alias.clone(), address);
-        Some(alias)
+        Ok(Some(alias))
+    }
+
+    /// Check that the alias an entry is about to be inserted under is valid,
+    /// and ask how to resolve a conflict with the entries already under it,
+    /// if there are any and the insertion isn't forced
+    fn resolve_alias<U: WalletIo>(
+        &self,
+        alias: &Alias,
+        entry: &str,
+        force: bool,
+    ) -> Result<ConfirmationResponse, InsertError> {
+        if !alias.is_valid() {
+            return Err(InsertError::InvalidAlias(alias.clone()));
+        }
+        Ok(if self.contains_alias(alias) && !force {
+            U::show_overwrite_confirmation(alias, entry)
+        } else {
+            ConfirmationResponse::Replace
+        })
     }
 
     /// Check if any map of the wallet contains the given alias
@@ -588,6 +762,162 @@ impl Store {
         self.pkhs.retain(|_key, val| val != alias);
         self.public_keys.remove(alias);
         self.derivation_paths.remove(alias);
+        self.mnemonics.remove(alias);
+    }
+
+    /// Move all the entries of the `old` alias to the `new` one. If the new
+    /// alias is already used, will prompt for overwrite/reselection
+    /// confirmation, and the entries it held are removed if replaced.
+    pub fn rename_alias<U: WalletIo>(
+        &mut self,
+        old: &Alias,
+        new: Alias,
+        force: bool,
+    ) -> RenameResult {
+        if !self.contains_alias(old) {
+            return RenameResult::NotFound;
+        }
+        // abort if the alias is reserved
+        if Alias::is_reserved(&new).is_some() {
+            println!("The alias {} is reserved.", new);
+            return RenameResult::Skipped;
+        }
+        if new == *old {
+            return RenameResult::Renamed(new);
+        }
+        match self.resolve_alias::<U>(&new, "another entry", force) {
+            Ok(ConfirmationResponse::Replace) => {}
+            Ok(ConfirmationResponse::Reselect(new_alias)) => {
+                return self.rename_alias::<U>(old, new_alias, false);
+            }
+            Ok(ConfirmationResponse::Skip | ConfirmationResponse::Abort) => {
+                return RenameResult::Skipped;
+            }
+            Err(_) => return RenameResult::InvalidAlias(new),
+        }
+        self.remove_alias(&new);
+        if let Some((_, payment_addr)) = self.payment_addrs.remove_by_left(old)
+        {
+            self.payment_addrs.insert(new.clone(), payment_addr);
+        }
+        if let Some(view_key) = self.view_keys.remove(old) {
+            self.view_keys.insert(new.clone(), view_key);
+        }
+        if let Some(spend_key) = self.spend_keys.remove(old) {
+            self.spend_keys.insert(new.clone(), spend_key);
+        }
+        if let Some(secret_key) = self.secret_keys.remove(old) {
+            self.secret_keys.insert(new.clone(), secret_key);
+        }
+        if let Some((_, address)) = self.addresses.remove_by_left(old) {
+            self.addresses.insert(new.clone(), address);
+        }
+        for alias in self.pkhs.values_mut() {
+            if *alias == *old {
+                *alias = new.clone();
+            }
+        }
+        if let Some(public_key) = self.public_keys.remove(old) {
+            self.public_keys.insert(new.clone(), public_key);
+        }
+        if let Some(path) = self.derivation_paths.remove(old) {
+            self.derivation_paths.insert(new.clone(), path);
+        }
+        if let Some(mnemonic) = self.mnemonics.remove(old) {
+            self.mnemonics.insert(new.clone(), mnemonic);
+        }
+        RenameResult::Renamed(new)
+    }
+
+    /// Look for dangling references between the keys, addresses, viewing
+    /// keys and spending keys of the store. Encrypted keys are only checked
+    /// for the presence of their counterparts, as they can't be decrypted
+    /// here.
+    pub fn check_integrity(&self) -> Vec<StoreInconsistency> {
+        let mut inconsistencies = vec![];
+        for (pkh, alias) in &self.pkhs {
+            let pk_matches = self
+                .public_keys
+                .get(alias)
+                .is_some_and(|pk| PublicKeyHash::from(pk) == *pkh);
+            if !pk_matches {
+                inconsistencies.push(
+                    StoreInconsistency::DanglingPublicKeyHash {
+                        pkh: pkh.clone(),
+                        alias: alias.clone(),
+                    },
+                );
+            }
+        }
+        for (alias, pk) in &self.public_keys {
+            let pkh = PublicKeyHash::from(pk);
+            if self.pkhs.get(&pkh) != Some(alias) {
+                inconsistencies.push(StoreInconsistency::UnindexedPublicKey(
+                    alias.clone(),
+                ));
+            }
+            if let Some(Address::Implicit(ImplicitAddress(addr_pkh))) =
+                self.addresses.get_by_left(alias)
+            {
+                if *addr_pkh != pkh {
+                    inconsistencies.push(
+                        StoreInconsistency::MismatchedImplicitAddress(
+                            alias.clone(),
+                        ),
+                    );
+                }
+            }
+        }
+        for (alias, sk) in &self.secret_keys {
+            match (self.public_keys.get(alias), sk) {
+                (None, _) => inconsistencies.push(
+                    StoreInconsistency::SecretKeyWithoutPublicKey(
+                        alias.clone(),
+                    ),
+                ),
+                (Some(pk), StoredKeypair::Raw(sk)) if sk.ref_to() != *pk => {
+                    inconsistencies.push(
+                        StoreInconsistency::MismatchedPublicKey(alias.clone()),
+                    )
+                }
+                _ => {}
+            }
+        }
+        for (alias, spendkey) in &self.spend_keys {
+            match (self.view_keys.get(alias), spendkey) {
+                (None, _) => inconsistencies.push(
+                    StoreInconsistency::SpendingKeyWithoutViewingKey(
+                        alias.clone(),
+                    ),
+                ),
+                (Some(viewkey), StoredKeypair::Raw(spendkey)) => {
+                    let derived: ExtendedViewingKey =
+                        zip32::ExtendedFullViewingKey::from(
+                            &(*spendkey).into(),
+                        )
+                        .into();
+                    if derived != *viewkey {
+                        inconsistencies.push(
+                            StoreInconsistency::MismatchedViewingKey(
+                                alias.clone(),
+                            ),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+        for alias in self.derivation_paths.keys() {
+            if !self.secret_keys.contains_key(alias)
+                && !self.public_keys.contains_key(alias)
+                && !self.spend_keys.contains_key(alias)
+            {
+                inconsistencies.push(
+                    StoreInconsistency::DanglingDerivationPath(alias.clone()),
+                );
+            }
+        }
+        inconsistencies
     }
 
     /// Extend this store from another store (typically pre-genesis).
@@ -604,6 +934,7 @@ impl Store {
             pkhs,
             validator_data: _,
             address_vp_types,
+            mnemonics,
         } = self;
         view_keys.extend(store.view_keys);
         spend_keys.extend(store.spend_keys);
@@ -614,6 +945,7 @@ impl Store {
         addresses.extend(store.addresses);
         pkhs.extend(store.pkhs);
         address_vp_types.extend(store.address_vp_types);
+        mnemonics.extend(store.mnemonics);
     }
 
     /// Extend this store from pre-genesis validator wallet.
@@ -671,6 +1003,36 @@ impl Store {
         });
     }
 
+    /// Export the keys of the validator that was added to this store from a
+    /// pre-genesis validator wallet with the given alias. The Ethereum cold
+    /// key is not added to the store, so it must be supplied. Returns nothing
+    /// if the store doesn't hold all the other keys of this validator.
+    pub fn export_pre_genesis_validator(
+        &self,
+        validator_alias: &Alias,
+        eth_cold_key: StoredKeypair<common::SecretKey>,
+    ) -> Option<pre_genesis::ValidatorStore> {
+        let validator_data = self.validator_data.as_ref()?;
+        let address = self
+            .addresses
+            .get_by_left(&alias::validator_address(validator_alias))?;
+        if *address != validator_data.address {
+            return None;
+        }
+        let find_key =
+            |key_alias: Alias| self.secret_keys.get(&key_alias).cloned();
+        Some(pre_genesis::ValidatorStore {
+            consensus_key: find_key(alias::validator_consensus_key(
+                validator_alias,
+            ))?,
+            eth_cold_key,
+            tendermint_node_key: find_key(
+                alias::validator_tendermint_node_key(validator_alias),
+            )?,
+            validator_keys: validator_data.keys.clone(),
+        })
+    }
+
     /// get an address with the vp type
     pub fn get_addresses_with_vp_type(
         &self,
@@ -959,4 +1321,78 @@ mod test_wallet {
             do_test_gen_sk_from_seed_and_derivation_path(SCHEME, SEED, "m/0'/2147483647'/1'/2147483646'/2'", "551d333177df541ad876a60ea71f00447931c0a9da16f227c11ea080d7391b8d");
         }
     }
+
+    #[derive(Clone)]
+    struct TestWalletUtils;
+
+    impl WalletIo for TestWalletUtils {
+        type Rng = rand_core::OsRng;
+    }
+
+    #[test]
+    fn test_check_integrity() {
+        use namada_core::key::testing::{keypair_1, keypair_2};
+
+        let mut store = Store::default();
+        let spendkey: ExtendedSpendingKey =
+            zip32::ExtendedSpendingKey::master(&[0; 32]).into();
+        store
+            .insert_keypair::<TestWalletUtils>(
+                "alice".into(),
+                keypair_1(),
+                None,
+                None,
+                None,
+                false,
+            )
+            .unwrap();
+        store
+            .insert_spending_key::<TestWalletUtils>(
+                "bob".into(),
+                spendkey,
+                None,
+                None,
+                false,
+            )
+            .unwrap();
+        assert_eq!(store.check_integrity(), vec![]);
+
+        // Make the viewing key of bob dangle by pointing it to another key
+        let other_viewkey: ExtendedViewingKey =
+            zip32::ExtendedFullViewingKey::from(
+                &zip32::ExtendedSpendingKey::master(&[1; 32]),
+            )
+            .into();
+        store.view_keys.insert("bob".into(), other_viewkey);
+        // Drop the public key of alice, leaving its hash dangling
+        store.public_keys.remove(&"alice".into());
+        // Add a viewing key without a spending key, which is allowed
+        store.view_keys.insert("carol".into(), other_viewkey);
+        // Add a derivation path that doesn't belong to any key
+        store
+            .derivation_paths
+            .insert("dave".into(), DerivationPath::default_for_shielded());
+        // Add a public key that can't be looked up by its hash
+        store.public_keys.insert("eve".into(), keypair_2().ref_to());
+
+        let pkh = PublicKeyHash::from(&keypair_1().ref_to());
+        let inconsistencies = store.check_integrity();
+        assert_eq!(
+            inconsistencies,
+            vec![
+                StoreInconsistency::DanglingPublicKeyHash {
+                    pkh,
+                    alias: "alice".into(),
+                },
+                StoreInconsistency::UnindexedPublicKey("eve".into()),
+                StoreInconsistency::SecretKeyWithoutPublicKey("alice".into()),
+                StoreInconsistency::MismatchedViewingKey("bob".into()),
+                StoreInconsistency::DanglingDerivationPath("dave".into()),
+            ]
+        );
+        assert_eq!(
+            inconsistencies[3].to_string(),
+            "The viewing key of alias bob does not match its spending key"
+        );
+    }
 }
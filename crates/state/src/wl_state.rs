@@ -122,9 +122,26 @@ where
         native_token: Address,
         storage_read_past_height_limit: Option<u64>,
         diff_key_filter: fn(&storage::Key) -> bool,
+    ) -> Self {
+        Self::from_db(
+            D::open(db_path, cache),
+            chain_id,
+            native_token,
+            storage_read_past_height_limit,
+            diff_key_filter,
+        )
+    }
+
+    /// Instantiate a full-access state from an already opened DB handle.
+    /// Loads the last state from the DB, if any.
+    pub fn from_db(
+        db: D,
+        chain_id: ChainId,
+        native_token: Address,
+        storage_read_past_height_limit: Option<u64>,
+        diff_key_filter: fn(&storage::Key) -> bool,
     ) -> Self {
         let write_log = WriteLog::default();
-        let db = D::open(db_path, cache);
         let in_mem = InMemory::new(
             chain_id,
             native_token,
@@ -1794,6 +1794,57 @@ fn enforce_fee_payment() -> Result<()> {
     Ok(())
 }
 
+/// Test that the memos of an IBC transfer end up in the built tx: the tx memo
+/// in its own section and the IBC memo in the packet data of the message.
+#[test]
+fn ibc_transfer_memos() -> Result<()> {
+    // This address doesn't matter for tests. But an argument is required.
+    let validator_one_rpc = "http://127.0.0.1:26567";
+    let (node, _services) = setup::setup()?;
+
+    let tempdir = tempfile::tempdir().unwrap();
+    let tx_path = tempdir.path().join("ibc-transfer.tx");
+    run(
+        &node,
+        Bin::Client,
+        vec![
+            "ibc-transfer",
+            "--source",
+            ALBERT_KEY,
+            "--receiver",
+            "cosmos1receiver",
+            "--token",
+            NAM,
+            "--amount",
+            "10",
+            "--channel-id",
+            "channel-0",
+            "--memo",
+            "tx memo",
+            "--ibc-memo",
+            "ibc memo",
+            "--dump-tx-path",
+            tx_path.to_str().unwrap(),
+            "--dump-tx",
+            "--ledger-address",
+            validator_one_rpc,
+        ],
+    )?;
+
+    let bytes = std::fs::read(&tx_path).unwrap();
+    let tx = namada_sdk::tx::Tx::deserialize(&bytes).unwrap();
+    let cmt = tx.first_commitments().unwrap();
+    assert_eq!(tx.memo(cmt), Some(b"tx memo".to_vec()));
+    let data = tx.data(cmt).unwrap();
+    let msg = namada_sdk::ibc::MsgTransfer::try_from_slice(&data).unwrap();
+    assert_eq!(msg.message.packet_data.memo.to_string(), "ibc memo");
+    assert_eq!(
+        msg.message.packet_data.receiver.to_string(),
+        "cosmos1receiver"
+    );
+    Ok(())
+}
+
 /// Test that a scheduled migration actually makes changes
 /// to storage at the scheduled height.
 #[test]
@@ -103,6 +103,7 @@ pub fn initialize_genesis(
             consensus_timeout_commit: Timeout::from_str("30s").unwrap(),
             archive_dir: None,
             genesis_time,
+            deterministic: false,
         },
     );
 
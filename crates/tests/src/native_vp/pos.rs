@@ -95,11 +95,18 @@
 //! - add slashes
 //! - add rewards
 
+use std::cell::RefCell;
+
+use namada_sdk::address;
+use namada_sdk::gas::VpGasMeter;
 use namada_sdk::proof_of_stake::parameters::{OwnedPosParams, PosParams};
 use namada_sdk::proof_of_stake::test_utils::test_init_genesis as init_genesis;
 use namada_sdk::proof_of_stake::types::GenesisValidator;
+use namada_sdk::proof_of_stake::vp::Error as PosVpError;
 use namada_sdk::storage::Epoch;
+use namada_sdk::validation::PosVp;
 
+use crate::native_vp::TestNativeVpEnv;
 use crate::tx::tx_host_env;
 
 /// initialize proof-of-stake genesis with the given list of validators and
@@ -144,17 +151,37 @@ pub fn init_pos(
     })
 }
 
+/// Validate the changes of the current transaction in the `tx_host_env` with
+/// the PoS validity predicate, without going through the protocol.
+///
+/// The keys changed and the verifiers are collected from the tx env the same
+/// way the protocol would. The tx env is put back before returning, so the
+/// caller can keep inspecting or modifying its state.
+pub fn validate_pos_changes() -> Result<(), PosVpError> {
+    let tx_env = tx_host_env::take();
+    let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+        &tx_env.gas_meter.borrow(),
+    ));
+    let vp_env = TestNativeVpEnv::from_tx_env(tx_env, address::POS);
+    let vp = vp_env.init_vp(&gas_meter, PosVp::new);
+    let result = vp_env.validate_tx(&vp);
+
+    // Put the tx_env back before returning the result
+    tx_host_env::set(vp_env.tx_env);
+    result
+}
+
 #[cfg(test)]
 mod tests {
 
-    use std::cell::RefCell;
-
-    use namada_sdk::gas::VpGasMeter;
+    use namada_sdk::dec::Dec;
     use namada_sdk::key::common::PublicKey;
-    use namada_sdk::validation::PosVp;
-    use namada_sdk::{address, token};
+    use namada_sdk::key::RefTo;
+    use namada_sdk::{key, token};
+    use namada_tx_prelude::action::{Action, PosAction, Write};
     use namada_tx_prelude::proof_of_stake::parameters::testing::arb_pos_params;
-    use namada_tx_prelude::Address;
+    use namada_tx_prelude::transaction::pos::Bond;
+    use namada_tx_prelude::{Address, StorageRead};
     use proptest::prelude::*;
     use proptest::test_runner::Config;
     use proptest_state_machine::{
@@ -167,7 +194,6 @@ mod tests {
         ValidPosAction,
     };
     use super::*;
-    use crate::native_vp::TestNativeVpEnv;
 
     prop_state_machine! {
         #![proptest_config(Config {
@@ -188,6 +214,87 @@ mod tests {
         fn pos_vp_state_machine_test(sequential 1..100 => ConcretePosState);
     }
 
+    /// Test that a self-bond authorized by the validator is accepted by the
+    /// PoS VP.
+    #[test]
+    fn test_validate_pos_changes_accepts_bond() {
+        let validator = init_pos_with_validator();
+        let amount = token::Amount::native_whole(100);
+        tx_host_env::with(|tx_env| {
+            let native_token = tx_env.state.in_mem().native_token.clone();
+            tx_env.credit_tokens(&validator, &native_token, amount);
+        });
+
+        tx_host_env::ctx()
+            .bond_tokens(None, &validator, amount)
+            .unwrap();
+
+        assert!(validate_pos_changes().is_ok());
+    }
+
+    /// Test that a delegation whose source is not a verifier of the tx is
+    /// rejected by the PoS VP.
+    #[test]
+    fn test_validate_pos_changes_rejects_unauthorized_bond() {
+        let validator = init_pos_with_validator();
+        let delegator = address::testing::established_address_2();
+        let amount = token::Amount::native_whole(100);
+        tx_host_env::with(|tx_env| {
+            tx_env.spawn_accounts([&delegator]);
+            let native_token = tx_env.state.in_mem().native_token.clone();
+            tx_env.credit_tokens(&delegator, &native_token, amount);
+        });
+
+        // Apply the delegation without inserting the delegator into the
+        // verifiers of the tx
+        let ctx = tx_host_env::ctx();
+        ctx.push_action(Action::Pos(PosAction::Bond(Bond {
+            validator: validator.clone(),
+            amount,
+            source: Some(delegator.clone()),
+        })))
+        .unwrap();
+        let current_epoch = ctx.get_block_epoch().unwrap();
+        namada_tx_prelude::proof_of_stake::bond_tokens(
+            ctx,
+            Some(&delegator),
+            &validator,
+            amount,
+            current_epoch,
+            None,
+        )
+        .unwrap();
+
+        let result = validate_pos_changes();
+        assert!(
+            matches!(
+                &result,
+                Err(PosVpError::Unauthorized("Bond", source))
+                    if *source == delegator
+            ),
+            "Unexpected result {result:?}"
+        );
+    }
+
+    /// Initialize PoS with the default parameters and a single genesis
+    /// validator, whose address is returned.
+    fn init_pos_with_validator() -> Address {
+        let validator = address::testing::established_address_1();
+        let genesis_validators = [GenesisValidator {
+            address: validator.clone(),
+            tokens: token::Amount::native_whole(1_000),
+            consensus_key: key::testing::keypair_1().ref_to(),
+            protocol_key: key::testing::keypair_2().ref_to(),
+            eth_cold_key: key::testing::keypair_3().ref_to(),
+            eth_hot_key: key::testing::keypair_4().ref_to(),
+            commission_rate: Dec::new(5, 2).expect("Cannot fail"),
+            max_commission_rate_change: Dec::new(1, 2).expect("Cannot fail"),
+            metadata: Default::default(),
+        }];
+        init_pos(&genesis_validators, &OwnedPosParams::default(), Epoch(0));
+        validator
+    }
+
     /// Abstract representation of a state of PoS system
     #[derive(Clone, Debug)]
     struct AbstractPosState {
@@ -433,17 +540,7 @@ mod tests {
     impl ConcretePosState {
         fn validate_transitions(&self) {
             // Use the tx_env to run PoS VP
-            let tx_env = tx_host_env::take();
-
-            let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
-                &tx_env.gas_meter.borrow(),
-            ));
-            let vp_env = TestNativeVpEnv::from_tx_env(tx_env, address::POS);
-            let vp = vp_env.init_vp(&gas_meter, PosVp::new);
-            let result = vp_env.validate_tx(&vp);
-
-            // Put the tx_env back before checking the result
-            tx_host_env::set(vp_env.tx_env);
+            let result = validate_pos_changes();
 
             // The expected result depends on the current state
             match (self.is_current_tx_valid, result) {
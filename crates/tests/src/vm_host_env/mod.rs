@@ -382,6 +382,54 @@ mod tests {
         assert_eq!(Some(value), read_post_value);
     }
 
+    /// An example how to unit-test the logic of a VP natively
+    #[test]
+    fn test_run_vp_in_process() {
+        use namada_vp_prelude::{Ctx, VpResult};
+
+        /// A VP that only accepts txs that change its `counter` key
+        fn vp_counter_changed(
+            _ctx: &Ctx,
+            _tx: BatchedTx,
+            addr: Address,
+            keys_changed: BTreeSet<Key>,
+            _verifiers: BTreeSet<Address>,
+        ) -> VpResult {
+            let counter_key = storage::Key::from(addr.to_db_key())
+                .join(&Key::parse("counter").unwrap());
+            if keys_changed.contains(&counter_key) {
+                namada_vp_prelude::accept()
+            } else {
+                namada_vp_prelude::reject()
+            }
+        }
+
+        let addr = address::testing::established_address_1();
+        let addr_key = storage::Key::from(addr.to_db_key());
+        let counter_key = addr_key.join(&Key::parse("counter").unwrap());
+        let other_key = addr_key.join(&Key::parse("other").unwrap());
+
+        // A tx that changes the counter is accepted
+        vp_host_env::init_from_tx(addr.clone(), TestTxEnv::default(), |_addr| {
+            tx::ctx().write(&counter_key, 1_u64).unwrap();
+        });
+        let batched_tx = vp_host_env::with(|env| env.batched_tx.clone());
+        assert!(
+            vp_host_env::run_vp_in_process(vp_counter_changed, batched_tx)
+                .is_ok()
+        );
+
+        // A tx that only changes another key is rejected
+        vp_host_env::init_from_tx(addr, TestTxEnv::default(), |_addr| {
+            tx::ctx().write(&other_key, 1_u64).unwrap();
+        });
+        let batched_tx = vp_host_env::with(|env| env.batched_tx.clone());
+        assert!(
+            vp_host_env::run_vp_in_process(vp_counter_changed, batched_tx)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_vp_read_and_has_key() {
         let mut tx_env = TestTxEnv::default();
@@ -12,7 +12,7 @@ use namada_sdk::tx::Tx;
 use namada_tx_prelude::BatchedTx;
 use namada_vm::wasm::{self, VpCache};
 use namada_vm::WasmCacheRwAccess;
-use namada_vp_prelude::Ctx;
+use namada_vp_prelude::{Ctx, VpResult};
 use tempfile::TempDir;
 
 use crate::tx::{tx_host_env, TestTxEnv};
@@ -215,6 +215,32 @@ mod native_vp_host_env {
         set(vp_env);
     }
 
+    /// Run a validity predicate natively, as a plain Rust function, against
+    /// the VP environment in [`ENV`]. Like the `validity_predicate` macro does
+    /// in wasm, the VP is called with the VP context, the given tx and the
+    /// address, changed keys and verifiers of the environment. The tx is also
+    /// set in [`ENV`], so that host functions reading it see the same tx.
+    pub fn run_vp_in_process(
+        vp_fn: impl FnOnce(
+            &Ctx,
+            BatchedTx,
+            Address,
+            BTreeSet<Key>,
+            BTreeSet<Address>,
+        ) -> VpResult,
+        batched_tx: BatchedTx,
+    ) -> VpResult {
+        let (addr, keys_changed, verifiers) = with(|env| {
+            env.batched_tx = batched_tx.clone();
+            (
+                env.addr.clone(),
+                env.keys_changed.clone(),
+                env.verifiers.clone(),
+            )
+        });
+        vp_fn(ctx(), batched_tx, addr, keys_changed, verifiers)
+    }
+
     /// A helper macro to create implementations of the host environment
     /// functions exported to wasm, which uses the environment from the
     /// `ENV` variable.
@@ -1816,6 +1816,7 @@ mod test {
 
     use borsh::schema::BorshSchema;
     use data_encoding::HEXLOWER;
+    use namada_core::key::testing::{keypair_1, keypair_2};
 
     use super::*;
 
@@ -1841,4 +1842,57 @@ mod test {
             Tx::try_from(tmp.as_ref()).unwrap();
         }
     }
+
+    /// Test that the signatures made separately by the signers of a multisig
+    /// account can be collected into the tx to meet the account's threshold
+    #[test]
+    fn test_collect_multisig_signatures() {
+        let owner = namada_core::address::testing::established_address_1();
+        let sk_1 = keypair_1();
+        let sk_2 = keypair_2();
+        let public_keys_map =
+            AccountPublicKeysMap::from_iter([sk_1.ref_to(), sk_2.ref_to()]);
+        let mut tx = Tx::new(ChainId::default(), None);
+        tx.add_code(vec![], None).add_serialized_data(vec![]);
+
+        // Each signer signs the tx on their own, e.g. offline
+        let mut signatures = tx.compute_section_signature(
+            &[sk_1],
+            &public_keys_map,
+            Some(owner.clone()),
+        );
+        signatures.extend(tx.compute_section_signature(
+            &[sk_2],
+            &public_keys_map,
+            Some(owner.clone()),
+        ));
+        assert_eq!(signatures.len(), 2);
+
+        let hashes = [tx.raw_header_hash()];
+        let mut partially_signed = tx.clone();
+        partially_signed.add_signatures(signatures[..1].to_vec());
+        assert!(
+            partially_signed
+                .verify_signatures(
+                    &hashes,
+                    public_keys_map.clone(),
+                    &Some(owner.clone()),
+                    2,
+                    || Ok(()),
+                )
+                .is_err()
+        );
+
+        tx.add_signatures(signatures);
+        assert!(
+            tx.verify_signatures(
+                &hashes,
+                public_keys_map,
+                &Some(owner),
+                2,
+                || Ok(()),
+            )
+            .is_ok()
+        );
+    }
 }